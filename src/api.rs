@@ -1,22 +1,38 @@
 pub mod auth;
+pub mod aws;
 pub mod database;
+pub mod identity;
+pub mod kmip;
 pub mod kv1;
 pub mod kv2;
 pub mod pki;
 pub mod ssh;
 pub mod sys;
 pub mod token;
+pub mod totp;
+pub mod transform;
 pub mod transit;
 
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use rustify::client::{Client as _, HTTP_SUCCESS_CODES};
 use rustify::endpoint::{Endpoint, MiddleWare};
+use rustify::enums::RequestMethod;
 use rustify::errors::ClientError as RestClientError;
-use serde::{de::DeserializeOwned, Deserialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::sys::wrapping;
+use crate::client::{Consistency, RequestEvent, RequestHook, VaultClientSettings};
+use crate::sys::{wrapping, Feature, ServerVersion};
 use crate::{client::Client, error::ClientError};
 
 use self::sys::responses::WrappingLookupResponse;
@@ -45,8 +61,68 @@ impl<T: DeserializeOwned + Send + Sync> rustify::endpoint::Wrapper for EndpointR
     type Value = T;
 }
 
-/// The information stored in the optional `wrap_info` field of API responses
+/// A dynamic secret along with its lease, which can be renewed or revoked with
+/// [sys::leases][crate::sys::leases], as returned by [exec_with_leased_secret]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LeasedSecret<T> {
+    pub lease_id: String,
+    pub lease_duration: u32,
+    pub renewable: bool,
+    pub data: T,
+}
+
+/// The raw body of a response which isn't JSON encoded, as returned by
+/// [exec_with_raw]
+#[derive(Clone, Debug)]
+pub struct RawResponse {
+    pub content_type: Option<String>,
+    pub body: Bytes,
+}
+
+impl RawResponse {
+    /// Returns the body as a string, failing with a
+    /// [ClientError::Utf8DecodeError] if it isn't valid UTF-8.
+    pub fn text(self) -> Result<String, ClientError> {
+        String::from_utf8(self.body.to_vec())
+            .map_err(|source| ClientError::Utf8DecodeError { source })
+    }
+}
+
+/// The `keys` field shared by the results of all `LIST` endpoints
 #[derive(Deserialize, Debug)]
+struct ListKeys {
+    keys: Vec<String>,
+}
+
+/// A filter applied to the keys returned by a `LIST` endpoint.
+///
+/// Vault doesn't support filtering lists, so the filter is applied on the
+/// client after all keys have been retrieved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListFilter {
+    /// Keeps the keys starting with the given value
+    Prefix(String),
+    /// Keeps the keys containing the given value
+    Contains(String),
+}
+
+impl ListFilter {
+    /// Returns whether the given key passes this filter.
+    pub fn matches(&self, key: &str) -> bool {
+        match self {
+            ListFilter::Prefix(p) => key.starts_with(p.as_str()),
+            ListFilter::Contains(c) => key.contains(c.as_str()),
+        }
+    }
+
+    /// Returns the given keys which pass this filter, preserving their order.
+    pub fn apply(&self, keys: Vec<String>) -> Vec<String> {
+        keys.into_iter().filter(|k| self.matches(k)).collect()
+    }
+}
+
+/// The information stored in the optional `wrap_info` field of API responses
+#[derive(Deserialize)]
 pub struct WrapInfo {
     pub token: String,
     pub accessor: String,
@@ -55,8 +131,20 @@ pub struct WrapInfo {
     pub creation_path: String,
 }
 
+impl fmt::Debug for WrapInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WrapInfo")
+            .field("token", &"[redacted]")
+            .field("accessor", &self.accessor)
+            .field("ttl", &self.ttl)
+            .field("creation_time", &self.creation_time)
+            .field("creation_path", &self.creation_path)
+            .finish()
+    }
+}
+
 /// The information stored in the optional `auth` field of API responses
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize)]
 pub struct AuthInfo {
     pub client_token: String,
     pub accessor: String,
@@ -70,6 +158,23 @@ pub struct AuthInfo {
     pub orphan: bool,
 }
 
+impl fmt::Debug for AuthInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthInfo")
+            .field("client_token", &"[redacted]")
+            .field("accessor", &self.accessor)
+            .field("policies", &self.policies)
+            .field("token_policies", &self.token_policies)
+            .field("metadata", &self.metadata)
+            .field("lease_duration", &self.lease_duration)
+            .field("renewable", &self.renewable)
+            .field("entity_id", &self.entity_id)
+            .field("token_type", &self.token_type)
+            .field("orphan", &self.orphan)
+            .finish()
+    }
+}
+
 /// Represents an API response that has been wrapped by a unique token.
 ///
 /// See [response wrapping][1] for details on how this works. This struct stores
@@ -132,17 +237,62 @@ pub struct EndpointError {
 /// information to all requests and adding a Vault token to the header of all
 /// requests. This is automatically passed by the API functions when an endpoint
 /// is executed.
-#[derive(Debug, Clone)]
+///
+/// Any additional `headers` are added last and replace headers of the same name
+/// set by the middleware.
+///
+/// With [Consistency::ReadYourWrites] the `X-Vault-Index` header returned by
+/// writes is stored in `index` and sent along with subsequent reads. The index
+/// is shared by all clones of the middleware, like the version of the server
+/// cached in `server_version`. If the version couldn't be read,
+/// `server_version_unknown` is set so that it isn't read again before each
+/// request to a gated endpoint.
+#[derive(Clone)]
 pub struct EndpointMiddleware {
     pub token: String,
     pub version: String,
     pub wrap: Option<String>,
     pub namespace: Option<String>,
+    pub headers: http::HeaderMap,
+    pub consistency: Consistency,
+    pub index: Arc<Mutex<Option<String>>>,
+    pub on_request: Option<RequestHook>,
+    pub server_version: Arc<Mutex<Option<ServerVersion>>>,
+    pub server_version_unknown: Arc<AtomicBool>,
+}
+
+impl fmt::Debug for EndpointMiddleware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EndpointMiddleware")
+            .field("token", &"[redacted]")
+            .field("version", &self.version)
+            .field("wrap", &self.wrap)
+            .field("namespace", &self.namespace)
+            .field("headers", &self.headers)
+            .field("consistency", &self.consistency)
+            .field("index", &self.index.lock().unwrap())
+            .field("on_request", &self.on_request.is_some())
+            .field("server_version", &self.server_version.lock().unwrap())
+            .field(
+                "server_version_unknown",
+                &self.server_version_unknown.load(Ordering::SeqCst),
+            )
+            .finish()
+    }
+}
+
+/// Returns whether requests with the given method only read data.
+fn is_read(method: &RequestMethod) -> bool {
+    matches!(
+        method,
+        RequestMethod::GET | RequestMethod::HEAD | RequestMethod::LIST
+    )
 }
+
 impl MiddleWare for EndpointMiddleware {
     fn request<E: Endpoint>(
         &self,
-        _: &E,
+        endpoint: &E,
         req: &mut http::Request<Vec<u8>>,
     ) -> Result<(), rustify::errors::ClientError> {
         // Prepend API version to all requests
@@ -176,8 +326,17 @@ impl MiddleWare for EndpointMiddleware {
             );
         }
 
-        // Optionally wrap response
-        if let Some(namespace) = &self.namespace {
+        // Vault only accepts JSON merge patches for PATCH requests
+        if req.method() == http::Method::PATCH {
+            debug!("Middleware: setting merge patch content type");
+            req.headers_mut().insert(
+                http::header::CONTENT_TYPE,
+                http::HeaderValue::from_static("application/merge-patch+json"),
+            );
+        }
+
+        // Optionally scope the request to a namespace
+        if let Some(namespace) = self.namespace.as_ref().filter(|n| !n.is_empty()) {
             info!("Middleware: adding namespace header {}", namespace);
             req.headers_mut().append(
                 "X-Vault-Namespace",
@@ -185,18 +344,241 @@ impl MiddleWare for EndpointMiddleware {
             );
         }
 
+        // Mark the request as an API request, which Vault Agent may require
+        req.headers_mut()
+            .insert("X-Vault-Request", http::HeaderValue::from_static("true"));
+
+        // Optionally make reads observe the last write
+        if self.consistency == Consistency::ReadYourWrites && is_read(&endpoint.method()) {
+            if let Some(index) = self.index.lock().unwrap().as_deref() {
+                debug!("Middleware: adding index header");
+                req.headers_mut()
+                    .insert("X-Vault-Index", http::HeaderValue::from_str(index).unwrap());
+            }
+        }
+
+        // Add any additional headers, replacing the ones set above
+        if !self.headers.is_empty() {
+            debug!("Middleware: adding {} extra headers", self.headers.len());
+            for name in self.headers.keys() {
+                req.headers_mut().remove(name);
+            }
+            for (name, value) in &self.headers {
+                req.headers_mut().append(name, value.clone());
+            }
+        }
+
         Ok(())
     }
 
     fn response<E: Endpoint>(
         &self,
-        _: &E,
-        _: &mut http::Response<Vec<u8>>,
+        endpoint: &E,
+        resp: &mut http::Response<Vec<u8>>,
     ) -> Result<(), rustify::errors::ClientError> {
+        // Remember the index of writes for subsequent reads
+        if self.consistency == Consistency::ReadYourWrites && !is_read(&endpoint.method()) {
+            if let Some(index) = resp
+                .headers()
+                .get("X-Vault-Index")
+                .and_then(|v| v.to_str().ok())
+            {
+                debug!("Middleware: storing index of write");
+                *self.index.lock().unwrap() = Some(index.to_string());
+            }
+        }
         Ok(())
     }
 }
 
+/// Sends a single request attempt.
+///
+/// With the `tracing` feature enabled, each attempt is wrapped in a
+/// `vault_request` span recording the method, path, status and duration.
+/// Request and response bodies are never recorded.
+#[cfg(not(feature = "tracing"))]
+async fn send(
+    client: &impl Client,
+    req: http::Request<Vec<u8>>,
+) -> Result<http::Response<Vec<u8>>, RestClientError> {
+    client.http().send(req).await
+}
+
+#[cfg(feature = "tracing")]
+async fn send(
+    client: &impl Client,
+    req: http::Request<Vec<u8>>,
+) -> Result<http::Response<Vec<u8>>, RestClientError> {
+    use tracing::{field, Instrument};
+
+    let span = info_span!(
+        "vault_request",
+        method = %req.method(),
+        path = %req.uri().path(),
+        status = field::Empty,
+        duration_ms = field::Empty,
+    );
+    let start = std::time::Instant::now();
+    let result = client.http().send(req).instrument(span.clone()).await;
+    if let Ok(resp) = &result {
+        span.record("status", resp.status().as_u16());
+    }
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+    span.in_scope(|| match &result {
+        Ok(_) => info!("Vault request finished"),
+        Err(e) => warn!("Vault request failed: {}", e),
+    });
+    result
+}
+
+/// Executes an [Endpoint] using the given [EndpointMiddleware].
+///
+/// Requests which fail with one of the configured status codes or with a
+/// connection error are retried with an exponential backoff according to the
+/// settings of the client (see
+/// [VaultClientSettings][crate::client::VaultClientSettings]). A `Retry-After`
+/// header returned by the server takes precedence over the backoff, unless it
+/// asks for a longer delay than the maximum one, in which case the response is
+/// returned without retrying. Only
+/// requests which read data are retried unless retrying writes is enabled,
+/// while requests which timed out after being sent are only ever retried if
+/// they read data. Timeouts are returned as [ClientError::Timeout].
+///
+/// Responses with one of the `accepted` status codes are neither retried nor
+/// treated as errors.
+async fn execute<E>(
+    client: &impl Client,
+    endpoint: E,
+    middle: &EndpointMiddleware,
+    accepted: &[u16],
+) -> Result<rustify::endpoint::EndpointResult<E::Response>, ClientError>
+where
+    E: Endpoint,
+{
+    let settings = client.settings();
+    let endpoint = endpoint.with_middleware(middle);
+    let idempotent = is_read(&endpoint.method());
+    let retryable = settings.retry_writes || idempotent;
+
+    let mut attempt = 0;
+    loop {
+        let req = endpoint.request(client.http().base())?;
+        let (method, path) = (req.method().clone(), req.uri().path().to_string());
+        let start = std::time::Instant::now();
+        let result = send(client, req).await;
+        if let Some(hook) = &middle.on_request {
+            hook(RequestEvent {
+                method,
+                path,
+                status: result.as_ref().ok().map(|r| r.status().as_u16()),
+                duration: start.elapsed(),
+                error: result.as_ref().err().map(|e| e.to_string()),
+            });
+        }
+
+        let delay = match &result {
+            Ok(resp) => {
+                let code = resp.status().as_u16();
+                if settings.retry_status_codes.contains(&code) && !accepted.contains(&code) {
+                    match retry_after(resp) {
+                        Some(d) => (d <= settings.retry_max_delay).then_some(d),
+                        None => Some(backoff(settings, attempt)),
+                    }
+                } else {
+                    None
+                }
+            }
+            // The server may have already processed a request which timed out
+            Err(e) if is_timeout(e) && !is_connect(e) => {
+                (settings.retry_connection_errors && idempotent).then(|| backoff(settings, attempt))
+            }
+            Err(RestClientError::RequestError { .. }) if settings.retry_connection_errors => {
+                Some(backoff(settings, attempt))
+            }
+            _ => None,
+        };
+        if let Some(delay) = delay.filter(|_| retryable && attempt < settings.max_retries) {
+            attempt += 1;
+            warn!(
+                "Request to {} failed, retrying in {:?} ({}/{})",
+                endpoint.path(),
+                delay,
+                attempt,
+                settings.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            continue;
+        }
+
+        let mut resp = match result {
+            Err(e) if is_timeout(&e) => return Err(ClientError::Timeout { source: e }),
+            result => result?,
+        };
+        let code = resp.status().as_u16();
+        if code == 429 && !accepted.contains(&code) {
+            return Err(ClientError::RateLimited {
+                retry_after: retry_after(&resp),
+            });
+        }
+        if !HTTP_SUCCESS_CODES.contains(&code) && !accepted.contains(&code) {
+            return Err(RestClientError::ServerResponseError {
+                code,
+                content: String::from_utf8(resp.body().to_vec()).ok(),
+            }
+            .into());
+        }
+        middle.response(&endpoint, &mut resp)?;
+        return Ok(rustify::endpoint::EndpointResult::new(
+            resp,
+            E::RESPONSE_BODY_TYPE,
+        ));
+    }
+}
+
+/// Returns the [reqwest::Error] which caused sending a request or reading its
+/// response to fail.
+fn reqwest_error(e: &RestClientError) -> Option<&reqwest::Error> {
+    match e {
+        RestClientError::RequestError { source, .. }
+        | RestClientError::ResponseError { source } => source.downcast_ref(),
+        _ => None,
+    }
+}
+
+/// Returns whether a request failed because it timed out.
+fn is_timeout(e: &RestClientError) -> bool {
+    reqwest_error(e).is_some_and(|e| e.is_timeout())
+}
+
+/// Returns whether a request failed because it couldn't connect to the server.
+fn is_connect(e: &RestClientError) -> bool {
+    reqwest_error(e).is_some_and(|e| e.is_connect())
+}
+
+/// Returns the delay requested by the `Retry-After` header of a response, if
+/// it is given in seconds.
+fn retry_after(resp: &http::Response<Vec<u8>>) -> Option<Duration> {
+    resp.headers()
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Returns the exponential backoff delay for the given attempt, capped at the
+/// maximum delay, with a random jitter of up to half of the delay.
+fn backoff(settings: &VaultClientSettings, attempt: u32) -> Duration {
+    let delay = settings
+        .retry_base_delay
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(settings.retry_max_delay);
+    let jitter = RandomState::new().build_hasher().finish() % 1000;
+    delay / 2 + delay / 2 * jitter as u32 / 1000
+}
+
 /// Executes an [Endpoint] which is expected to return an empty HTTP response.
 ///
 /// Any errors which occur in execution are wrapped in a
@@ -206,9 +588,7 @@ where
     E: Endpoint,
 {
     info!("Executing {} and expecting no response", endpoint.path());
-    endpoint
-        .with_middleware(client.middle())
-        .exec(client.http())
+    execute(client, endpoint, client.middle(), &[])
         .await
         .map_err(parse_err)
         .map(|_| ())
@@ -222,14 +602,12 @@ pub async fn exec_with_empty_result<E>(client: &impl Client, endpoint: E) -> Res
 where
     E: Endpoint,
 {
-    info!("Executing {} and expecting empty API data", endpoint.path());
-    endpoint
-        .with_middleware(client.middle())
-        .exec(client.http())
-        .await
-        .map_err(ClientError::from)?
+    let path = endpoint.path();
+    info!("Executing {} and expecting empty API data", path);
+    execute(client, endpoint, client.middle(), &[])
+        .await?
         .wrap::<EndpointResult<_>>()
-        .map_err(parse_err)
+        .map_err(|e| parse_response_err(&path, e))
         .map(strip)
         .map(|_| ())
 }
@@ -245,17 +623,60 @@ pub async fn exec_with_no_result<E>(
 where
     E: Endpoint,
 {
-    info!(
-        "Executing {} and expecting an unwrapped response",
-        endpoint.path()
-    );
-    endpoint
-        .with_middleware(client.middle())
-        .exec(client.http())
+    let path = endpoint.path();
+    info!("Executing {} and expecting an unwrapped response", path);
+    execute(client, endpoint, client.middle(), &[])
+        .await
+        .map_err(parse_err)?
+        .parse()
+        .map_err(|e| parse_response_err(&path, e))
+}
+
+/// Executes an [Endpoint] which is expected to return a raw response body,
+/// like a DER encoded certificate, and returns it without parsing it.
+///
+/// Any errors which occur in execution are wrapped in a
+/// [ClientError::RestClientError] and propagated.
+pub async fn exec_with_raw<E>(client: &impl Client, endpoint: E) -> Result<RawResponse, ClientError>
+where
+    E: Endpoint,
+{
+    info!("Executing {} and expecting a raw response", endpoint.path());
+    let res = execute(client, endpoint, client.middle(), &[])
+        .await
+        .map_err(parse_err)?;
+    Ok(RawResponse {
+        content_type: res
+            .response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from),
+        body: Bytes::from(res.response.into_body()),
+    })
+}
+
+/// Executes an [Endpoint] which is expected to return an unwrapped response,
+/// treating responses with any of the given status codes as successful.
+///
+/// This is used for endpoints which signal their result through the status
+/// code while still returning a regular response body, like
+/// [ReadHealthRequest][crate::api::sys::requests::ReadHealthRequest].
+pub async fn exec_with_status_codes<E>(
+    client: &impl Client,
+    endpoint: E,
+    codes: &[u16],
+) -> Result<E::Response, ClientError>
+where
+    E: Endpoint,
+{
+    let path = endpoint.path();
+    info!("Executing {} and expecting an unwrapped response", path);
+    execute(client, endpoint, client.middle(), codes)
         .await
         .map_err(parse_err)?
         .parse()
-        .map_err(ClientError::from)
+        .map_err(|e| parse_response_err(&path, e))
 }
 
 /// Executes an [Endpoint] and returns the result.
@@ -264,6 +685,9 @@ where
 ///
 /// * Any potential API error responses from the execution are searched for and,
 ///   if found, converted to a [ClientError::APIError]
+/// * A `429` response from a rate limit quota is converted to a
+///   [ClientError::RateLimited], carrying the delay requested by its
+///   `Retry-After` header
 /// * All other errors are mapped from [rustify::errors::ClientError] to
 ///   [ClientError::RestClientError]
 /// * An empty content body from the execution is rejected and a
@@ -281,18 +705,165 @@ pub async fn exec_with_result<E>(
 where
     E: Endpoint,
 {
-    info!("Executing {} and expecting a response", endpoint.path());
-    endpoint
-        .with_middleware(client.middle())
-        .exec(client.http())
+    let path = endpoint.path();
+    info!("Executing {} and expecting a response", path);
+    execute(client, endpoint, client.middle(), &[])
         .await
         .map_err(parse_err)?
         .wrap::<EndpointResult<_>>()
-        .map_err(ClientError::from)
+        .map_err(|e| parse_response_err(&path, e))
         .map(strip)?
         .ok_or(ClientError::ResponseDataEmptyError)
 }
 
+/// Executes an [Endpoint] and returns the enclosing [EndpointResult].
+///
+/// This behaves like [exec_with_result], except that the [EndpointResult] is
+/// not stripped off the response. This gives access to the lease information
+/// of dynamic secrets and to any warnings, which are returned alongside the
+/// `data` field.
+pub async fn exec_with_lease<E>(
+    client: &impl Client,
+    endpoint: E,
+) -> Result<EndpointResult<E::Response>, ClientError>
+where
+    E: Endpoint,
+{
+    let path = endpoint.path();
+    info!("Executing {} and expecting a leased response", path);
+    let res: EndpointResult<E::Response> = execute(client, endpoint, client.middle(), &[])
+        .await
+        .map_err(parse_err)?
+        .wrap::<EndpointResult<_>>()
+        .map_err(|e| parse_response_err(&path, e))?;
+    if let Some(w) = &res.warnings {
+        if !w.is_empty() {
+            warn!("Detected warnings in API response: {:#?}", w);
+        }
+    }
+    if res.data.is_none() {
+        return Err(ClientError::ResponseDataEmptyError);
+    }
+    Ok(res)
+}
+
+/// Executes an [Endpoint] which returns a dynamic secret and returns it along
+/// with its lease as a [LeasedSecret].
+pub async fn exec_with_leased_secret<E>(
+    client: &impl Client,
+    endpoint: E,
+) -> Result<LeasedSecret<E::Response>, ClientError>
+where
+    E: Endpoint,
+{
+    let res = exec_with_lease(client, endpoint).await?;
+    Ok(LeasedSecret {
+        lease_id: res.lease_id,
+        lease_duration: res.lease_duration,
+        renewable: res.renewable,
+        data: res.data.ok_or(ClientError::ResponseDataEmptyError)?,
+    })
+}
+
+/// Executes a `LIST` [Endpoint] and returns the keys found in the `keys`
+/// field of its result.
+///
+/// Vault responds with a `404` when there is nothing to list at the given
+/// path, which is returned as an empty list instead of an error. A `404` which
+/// carries error messages, like for a path without an engine mounted, is
+/// returned as an error (see [optional]).
+pub async fn exec_with_list<E>(
+    client: &impl Client,
+    endpoint: E,
+) -> Result<Vec<String>, ClientError>
+where
+    E: Endpoint,
+{
+    let path = endpoint.path();
+    info!("Executing {} and expecting a list of keys", path);
+    let res = execute(client, endpoint, client.middle(), &[]).await;
+    let res = match optional(res.map_err(parse_err))? {
+        Some(r) => r,
+        None => return Ok(Vec::new()),
+    };
+    let res: EndpointResult<ListKeys> =
+        serde_json::from_slice(res.raw().as_slice()).map_err(|e| {
+            deserialization_err(&path, e.to_string(), String::from_utf8(res.raw()).ok())
+        })?;
+    Ok(strip(res).map(|d| d.keys).unwrap_or_default())
+}
+
+/// Executes a `LIST` [Endpoint] like [exec_with_list] and returns the keys
+/// which pass the given [ListFilter].
+pub async fn exec_with_filtered_list<E>(
+    client: &impl Client,
+    endpoint: E,
+    filter: &ListFilter,
+) -> Result<Vec<String>, ClientError>
+where
+    E: Endpoint,
+{
+    exec_with_list(client, endpoint)
+        .await
+        .map(|keys| filter.apply(keys))
+}
+
+/// Executes a `LIST` [Endpoint] and returns its result.
+///
+/// This behaves like [exec_with_result], except that the `404` Vault responds
+/// with when there is nothing to list is returned as an empty result, the same
+/// way [exec_with_list] returns it as an empty list.
+pub async fn exec_with_list_result<E>(
+    client: &impl Client,
+    endpoint: E,
+) -> Result<E::Response, ClientError>
+where
+    E: Endpoint,
+    E::Response: Default,
+{
+    optional(exec_with_result(client, endpoint).await).map(Option::unwrap_or_default)
+}
+
+/// Executes a batch [Endpoint] and returns the result.
+///
+/// This behaves like [exec_with_result], except that a `400` response which
+/// carries a result is parsed instead of being returned as an error. Vault
+/// responds this way to batch requests in which some of the items failed, and
+/// the errors of the individual items are contained within the result.
+pub async fn exec_with_batch_result<E>(
+    client: &impl Client,
+    endpoint: E,
+) -> Result<E::Response, ClientError>
+where
+    E: Endpoint,
+{
+    let path = endpoint.path();
+    info!("Executing {} and expecting a batch response", path);
+    let res = execute(client, endpoint, client.middle(), &[]).await;
+    let res = match res {
+        Ok(r) => r
+            .wrap::<EndpointResult<_>>()
+            .map_err(|e| parse_response_err(&path, e))?,
+        Err(ClientError::RestClientError {
+            source:
+                RestClientError::ServerResponseError {
+                    code: 400,
+                    content: Some(c),
+                },
+        }) => match serde_json::from_str::<EndpointResult<E::Response>>(c.as_str()) {
+            Ok(r) => r,
+            Err(_) => {
+                return Err(parse_err(RestClientError::ServerResponseError {
+                    code: 400,
+                    content: Some(c),
+                }))
+            }
+        },
+        Err(e) => return Err(parse_err(e)),
+    };
+    strip(res).ok_or(ClientError::ResponseDataEmptyError)
+}
+
 /// Executes the given endpoint but requests that the Vault server to return a
 /// token wrapped response.
 ///
@@ -302,20 +873,31 @@ pub async fn wrap<E>(client: &impl Client, endpoint: E) -> Result<WrappedRespons
 where
     E: Endpoint,
 {
-    info!(
-        "Executing {} and returning a wrapped response",
-        endpoint.path()
-    );
+    wrap_with_ttl(client, endpoint, "10m").await
+}
+
+/// Executes the given endpoint but requests that the Vault server to return a
+/// token wrapped response which is valid for the given TTL.
+///
+/// See [wrap] for details.
+pub async fn wrap_with_ttl<E>(
+    client: &impl Client,
+    endpoint: E,
+    ttl: &str,
+) -> Result<WrappedResponse<E>, ClientError>
+where
+    E: Endpoint,
+{
+    let path = endpoint.path();
+    info!("Executing {} and returning a wrapped response", path);
     let mut m = client.middle().clone();
-    m.wrap = Some("10m".to_string());
-    let resp = endpoint
-        .with_middleware(&m)
-        .exec(client.http())
+    m.wrap = Some(ttl.to_string());
+    let resp = execute(client, endpoint, &m, &[])
         .await
         .map_err(parse_err)?;
     let info = resp
         .wrap::<EndpointResult<_>>()
-        .map_err(ClientError::from)
+        .map_err(|e| parse_response_err(&path, e))
         .map(strip_wrap)??;
     Ok(WrappedResponse {
         info,
@@ -323,32 +905,129 @@ where
     })
 }
 
+/// Executes an [Endpoint] which is expected to return wrapping information,
+/// returning it as a [WrapInfo].
+pub async fn exec_with_wrap_info<E>(
+    client: &impl Client,
+    endpoint: E,
+) -> Result<WrapInfo, ClientError>
+where
+    E: Endpoint,
+{
+    let path = endpoint.path();
+    info!("Executing {} and expecting wrapping information", path);
+    execute(client, endpoint, client.middle(), &[])
+        .await
+        .map_err(parse_err)?
+        .wrap::<EndpointResult<_>>()
+        .map_err(|e| parse_response_err(&path, e))
+        .map(strip_wrap)?
+}
+
 pub async fn auth<E>(client: &impl Client, endpoint: E) -> Result<AuthInfo, ClientError>
 where
     E: Endpoint<Response = ()>,
 {
-    info!(
-        "Executing {} and returning authentication info",
-        endpoint.path()
-    );
-    let r: EndpointResult<()> = endpoint
-        .with_middleware(client.middle())
-        .exec(client.http())
+    let path = endpoint.path();
+    info!("Executing {} and returning authentication info", path);
+    let r: EndpointResult<()> = execute(client, endpoint, client.middle(), &[])
         .await
         .map_err(parse_err)?
         .wrap::<EndpointResult<_>>()
-        .map_err(ClientError::from)?;
+        .map_err(|e| parse_response_err(&path, e))?;
     r.auth.ok_or(ClientError::ResponseEmptyError)
 }
 
+/// Converts the result of reading a path which doesn't exist into `None`.
+///
+/// Vault responds with a `404` and an empty list of errors when nothing is
+/// found at a path. Any other error, including a `404` which carries error
+/// messages like for a path without an engine mounted, is returned as is.
+pub fn optional<T>(res: Result<T, ClientError>) -> Result<Option<T>, ClientError> {
+    match res {
+        Ok(r) => Ok(Some(r)),
+        Err(ClientError::APIError { code: 404, errors }) if errors.is_empty() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Replaces the `unsupported path` error returned by Vault OSS for endpoints of
+/// Vault Enterprise with a [ClientError::EnterpriseOnlyError].
+///
+/// A `404` for a route without a handler is returned as is, since Enterprise
+/// servers respond the same way to requests for a mount which isn't enabled.
+pub(crate) fn enterprise_only(e: ClientError) -> ClientError {
+    match e {
+        ClientError::APIError { code: 404, errors }
+            if errors.iter().any(|e| e.contains("unsupported path")) =>
+        {
+            ClientError::EnterpriseOnlyError
+        }
+        e => e,
+    }
+}
+
+/// Returns an error if the server doesn't support the feature.
+///
+/// Features which are only available in Vault Enterprise fail with a
+/// [ClientError::EnterpriseOnlyError] against other servers, like requests
+/// which Vault OSS rejects with `unsupported path` (see [enterprise_only]).
+/// Servers which are too old for the feature fail with a
+/// [ClientError::UnsupportedFeature].
+///
+/// The version of the server is read with
+/// [server_version][crate::sys::server_version] on first use and cached by the
+/// client. If it can't be read, for example because the health endpoint isn't
+/// reachable, the feature is assumed to be supported and the version isn't
+/// read again until it's refreshed with
+/// [refresh_server_version][crate::sys::refresh_server_version].
+pub(crate) async fn require(client: &impl Client, feature: Feature) -> Result<(), ClientError> {
+    let middle = client.middle();
+    if middle.server_version.lock().unwrap().is_none()
+        && middle.server_version_unknown.load(Ordering::SeqCst)
+    {
+        return Ok(());
+    }
+    match crate::sys::server_version(client).await {
+        Ok(version) if !version.enterprise && feature.requires_enterprise() => {
+            Err(ClientError::EnterpriseOnlyError)
+        }
+        Ok(version) if !version.supports(feature) => Err(ClientError::UnsupportedFeature {
+            feature,
+            server_version: version.raw,
+        }),
+        Ok(_) => Ok(()),
+        Err(e) => {
+            warn!("Assuming {:?} is supported by the server: {}", feature, e);
+            middle.server_version_unknown.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+}
+
+/// Decodes the base64 encoded string stored in the `key` field of a secret,
+/// returning a [ClientError::DecodeError] if it's missing or invalid.
+pub(crate) fn decode_field(data: &serde_json::Value, key: &str) -> Result<Vec<u8>, ClientError> {
+    let value = data
+        .get(key)
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| ClientError::DecodeError {
+            source: None,
+            key: key.to_string(),
+        })?;
+    base64::decode(value).map_err(|e| ClientError::DecodeError {
+        source: Some(e),
+        key: key.to_string(),
+    })
+}
+
 /// Strips the wrapping information out of an [EndpointResult], returning the
 /// enclosing information as a [WrapInfo].
 fn strip_wrap<T>(result: EndpointResult<T>) -> Result<WrapInfo, ClientError> {
     info!("Stripping wrap info from API response");
     if let Some(w) = &result.warnings {
-        match w.is_empty() {
-            false => warn!("Server returned warnings with response: {:#?}", w),
-            true => {}
+        if !w.is_empty() {
+            warn!("Server returned warnings with response: {:#?}", w);
         }
     }
     result.wrap_info.ok_or(ClientError::ResponseWrapError {})
@@ -361,20 +1040,89 @@ where
 {
     info!("Stripping response wrapper from API response");
     if let Some(w) = &result.warnings {
-        match w.is_empty() {
-            false => warn!("Detected warnings in API response: {:#?}", w),
-            true => {}
+        if !w.is_empty() {
+            warn!("Detected warnings in API response: {:#?}", w);
         }
     }
     result.data
 }
 
+/// The maximum number of characters of the response body included in a
+/// [ClientError::DeserializationError]
+const ERROR_BODY_LIMIT: usize = 512;
+
+/// Converts a [rustify::errors::ClientError::ResponseParseError] returned when
+/// the response of the endpoint at `path` doesn't match the expected type into
+/// a [ClientError::DeserializationError]. Other errors are converted as is.
+fn parse_response_err(path: &str, e: RestClientError) -> ClientError {
+    match e {
+        RestClientError::ResponseParseError { source, content } => {
+            deserialization_err(path, source.to_string(), content)
+        }
+        e => ClientError::from(e),
+    }
+}
+
+/// Returns a [ClientError::DeserializationError] for the response of the
+/// endpoint at `path`, including its body truncated to [ERROR_BODY_LIMIT]
+/// with the values of secret fields redacted.
+fn deserialization_err(path: &str, message: String, body: Option<String>) -> ClientError {
+    error!("Error deserializing the response of {}: {}", path, message);
+    let body = body.map(|b| {
+        let b = match serde_json::from_str::<Value>(&b) {
+            Ok(mut v) => {
+                redact_secrets(&mut v);
+                v.to_string()
+            }
+            Err(_) => b,
+        };
+        match b.char_indices().nth(ERROR_BODY_LIMIT) {
+            Some((i, _)) => format!("{}...", &b[..i]),
+            None => b,
+        }
+    });
+    ClientError::DeserializationError {
+        path: path.to_string(),
+        message,
+        body,
+    }
+}
+
+/// Replaces the values of fields which are likely to hold secrets, like
+/// tokens, passwords and keys, with `[redacted]`.
+fn redact_secrets(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (name, v) in map.iter_mut() {
+                let name = name.to_lowercase();
+                let secret = ["token", "password", "secret", "private"]
+                    .iter()
+                    .any(|s| name.contains(s))
+                    || ["key", "keys", "keys_base64"]
+                        .iter()
+                        .any(|s| name.ends_with(s));
+                if secret && !v.is_null() {
+                    *v = Value::String("[redacted]".to_string());
+                } else {
+                    redact_secrets(v);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_secrets),
+        _ => {}
+    }
+}
+
 /// Attempts to parse the enclosed API errors returned from a
 /// [rustify::errors::ClientError::ServerResponseError]. If errors can be parsed
 /// it returns the result as a [ClientError::APIError], otherwise it returns a
 /// [ClientError::RestClientError].
-fn parse_err(e: RestClientError) -> ClientError {
-    if let RestClientError::ServerResponseError { code, content } = &e {
+fn parse_err(e: impl Into<ClientError>) -> ClientError {
+    let e = e.into();
+    if let ClientError::RestClientError {
+        source: RestClientError::ServerResponseError { code, content },
+    } = &e
+    {
         match content {
             Some(c) => {
                 let errs: Result<EndpointError, _> = serde_json::from_str(c.as_str());
@@ -386,12 +1134,12 @@ fn parse_err(e: RestClientError) -> ClientError {
                             errors: err.errors,
                         }
                     }
-                    Err(_) => ClientError::from(e),
+                    Err(_) => e,
                 }
             }
-            None => ClientError::from(e),
+            None => e,
         }
     } else {
-        ClientError::from(e)
+        e
     }
 }
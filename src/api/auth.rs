@@ -1,5 +1,9 @@
 pub mod approle;
 pub mod aws;
+pub mod azure;
+pub mod cert;
+pub mod gcp;
 pub mod kubernetes;
+pub mod ldap;
 pub mod oidc;
 pub mod userpass;
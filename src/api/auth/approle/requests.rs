@@ -3,6 +3,7 @@ use super::responses::{
     ListSecretIDResponse, ReadAppRoleResponse, ReadRoleIDResponse, ReadSecretIDResponse,
 };
 use rustify_derive::Endpoint;
+use std::fmt;
 
 /// ## Login with Approle
 /// Issues a Vault token based on the presented credentials.
@@ -11,7 +12,7 @@ use rustify_derive::Endpoint;
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/approle#login-with-approle
-#[derive(Builder, Debug, Endpoint)]
+#[derive(Builder, Endpoint)]
 #[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
 #[builder(setter(into))]
 pub struct LoginWithApproleRequest {
@@ -21,6 +22,16 @@ pub struct LoginWithApproleRequest {
     pub secret_id: String,
 }
 
+impl fmt::Debug for LoginWithApproleRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginWithApproleRequest")
+            .field("mount", &self.mount)
+            .field("role_id", &self.role_id)
+            .field("secret_id", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## List Roles
 /// This endpoint returns a list the existing AppRoles in the method.
 ///
@@ -215,7 +226,7 @@ pub struct ListSecretIDRequest {
 /// * Method: POST
 /// * Response: [ReadSecretIDResponse]
 /// * Reference: https://www.vaultproject.io/api-docs/auth/approle#read-approle-secret-id
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/{self.mount}/role/{self.role_name}/secret-id/lookup",
     method = "POST",
@@ -231,6 +242,16 @@ pub struct ReadSecretIDRequest {
     pub secret_id: String,
 }
 
+impl fmt::Debug for ReadSecretIDRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadSecretIDRequest")
+            .field("mount", &self.mount)
+            .field("role_name", &self.role_name)
+            .field("secret_id", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Destroy AppRole Secret ID
 /// Destroy an AppRole secret ID.
 ///
@@ -238,7 +259,7 @@ pub struct ReadSecretIDRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/approle#destroy-approle-secret-id
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/{self.mount}/role/{self.role_name}/secret-id/destroy",
     method = "POST",
@@ -253,6 +274,16 @@ pub struct DeleteSecretIDRequest {
     pub secret_id: String,
 }
 
+impl fmt::Debug for DeleteSecretIDRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeleteSecretIDRequest")
+            .field("mount", &self.mount)
+            .field("role_name", &self.role_name)
+            .field("secret_id", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Read AppRole Secret ID Accessor
 /// Reads out the properties of a SecretID.
 ///
@@ -305,7 +336,7 @@ pub struct DeleteSecretIDAccessorRequest {
 /// * Method: POST
 /// * Response: [CreateCustomSecretIDResponse]
 /// * Reference: https://www.vaultproject.io/api-docs/auth/approle#create-custom-approle-secret-id
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/{self.mount}/role/{self.role_name}/custom-secret-id",
     method = "POST",
@@ -324,6 +355,19 @@ pub struct CreateCustomSecretIDRequest {
     pub token_bound_cidrs: Option<Vec<String>>,
 }
 
+impl fmt::Debug for CreateCustomSecretIDRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateCustomSecretIDRequest")
+            .field("mount", &self.mount)
+            .field("role_name", &self.role_name)
+            .field("secret_id", &"[redacted]")
+            .field("metadata", &self.metadata)
+            .field("cidr_list", &self.cidr_list)
+            .field("token_bound_cidrs", &self.token_bound_cidrs)
+            .finish()
+    }
+}
+
 /// ## Tidy Tokens
 /// Performs some maintenance tasks to clean up invalid entries that may remain
 /// in the token store.
@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use serde::{Deserialize, Serialize};
 
 /// Response from executing
 /// [ListRolesRequest][crate::api::auth::approle::requests::ListRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListRolesResponse {
     pub keys: Vec<String>,
 }
@@ -14,6 +14,8 @@ pub struct ListRolesResponse {
 #[derive(Deserialize, Debug, Serialize)]
 pub struct ReadAppRoleResponse {
     pub bind_secret_id: bool,
+    #[serde(default)]
+    pub local_secret_ids: bool,
     pub secret_id_bound_cidrs: Option<Vec<String>>,
     pub secret_id_num_uses: u64,
     pub secret_id_ttl: u64,
@@ -37,16 +39,26 @@ pub struct ReadRoleIDResponse {
 
 /// Response from executing
 /// [GenerateNewSecretIDRequest][crate::api::auth::approle::requests::GenerateNewSecretIDRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct GenerateNewSecretIDResponse {
     pub secret_id_accessor: String,
     pub secret_id: String,
     pub secret_id_ttl: u64,
 }
 
+impl fmt::Debug for GenerateNewSecretIDResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateNewSecretIDResponse")
+            .field("secret_id_accessor", &self.secret_id_accessor)
+            .field("secret_id", &"[redacted]")
+            .field("secret_id_ttl", &self.secret_id_ttl)
+            .finish()
+    }
+}
+
 /// Response from executing
 /// [ListSecretIDRequest][crate::api::auth::approle::requests::ListSecretIDRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListSecretIDResponse {
     pub keys: Vec<String>,
 }
@@ -68,8 +80,17 @@ pub struct ReadSecretIDResponse {
 
 /// Response from executing
 /// [CreateCustomSecretIDRequest][crate::api::auth::approle::requests::CreateCustomSecretIDRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct CreateCustomSecretIDResponse {
     pub secret_id_accessor: String,
     pub secret_id: String,
 }
+
+impl fmt::Debug for CreateCustomSecretIDResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateCustomSecretIDResponse")
+            .field("secret_id_accessor", &self.secret_id_accessor)
+            .field("secret_id", &"[redacted]")
+            .finish()
+    }
+}
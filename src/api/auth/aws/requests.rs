@@ -8,6 +8,7 @@ use super::responses::{
 };
 use rustify_derive::Endpoint;
 use serde::Serialize;
+use std::fmt;
 
 /// ## Configure Client
 /// Configures the credentials required to perform API calls to AWS as well as custom endpoints to talk to AWS APIs.
@@ -16,7 +17,7 @@ use serde::Serialize;
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/aws#configure-client
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/{self.mount}/config/client",
     method = "POST",
@@ -37,6 +38,29 @@ pub struct ConfigureClientRequest {
     pub allowed_sts_header_values: Option<String>,
 }
 
+impl fmt::Debug for ConfigureClientRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigureClientRequest")
+            .field("mount", &self.mount)
+            .field("max_retries", &self.max_retries)
+            .field("access_key", &self.access_key)
+            .field(
+                "secret_key",
+                &self.secret_key.as_ref().map(|_| "[redacted]"),
+            )
+            .field("endpoint", &self.endpoint)
+            .field("iam_endpoint", &self.iam_endpoint)
+            .field("sts_endpoint", &self.sts_endpoint)
+            .field("sts_region", &self.sts_region)
+            .field(
+                "iam_server_id_header_value",
+                &self.iam_server_id_header_value,
+            )
+            .field("allowed_sts_header_values", &self.allowed_sts_header_values)
+            .finish()
+    }
+}
+
 /// ## Read Client Configuration
 /// Returns the previously configured AWS access credentials.
 ///
@@ -569,7 +593,7 @@ pub struct CreateRoleTagRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/aws#login
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct IamLoginRequest {
@@ -582,6 +606,19 @@ pub struct IamLoginRequest {
     pub iam_request_headers: String,
 }
 
+impl fmt::Debug for IamLoginRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IamLoginRequest")
+            .field("mount", &self.mount)
+            .field("role", &self.role)
+            .field("iam_http_request_method", &self.iam_http_request_method)
+            .field("iam_request_url", &self.iam_request_url)
+            .field("iam_request_body", &self.iam_request_body)
+            .field("iam_request_headers", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Login(EC2 method)
 /// This endpoint verifies the pkcs7 signature of the instance identity document.
 ///
@@ -589,7 +626,7 @@ pub struct IamLoginRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/aws#login
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct Ec2LoginRequest {
@@ -602,6 +639,19 @@ pub struct Ec2LoginRequest {
     pub pkcs7: String,
 }
 
+impl fmt::Debug for Ec2LoginRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ec2LoginRequest")
+            .field("mount", &self.mount)
+            .field("role", &self.role)
+            .field("nonce", &self.nonce)
+            .field("identity", &self.identity)
+            .field("signature", &self.signature)
+            .field("pkcs7", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Place Role Tags in Deny List
 /// Places a valid role tag in a deny list
 ///
@@ -42,7 +42,7 @@ pub struct ReadCertificateConfigurationResponse {
 
 /// Response from executing
 /// [ListCertificateConfigurationResponse][crate::api::auth::aws::requests::ListCertificateConfigurationResponse]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListCertificateConfigurationsResponse {
     pub keys: Vec<String>,
 }
@@ -56,7 +56,7 @@ pub struct ReadStsRoleResponse {
 
 /// Response from executing
 /// [ListStsRolesRequest][crate::api::auth::aws::requests::ListStsRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListStsRolesResponse {
     pub keys: Vec<String>,
 }
@@ -110,7 +110,7 @@ pub struct ReadRoleResponse {
 
 /// Response from executing
 /// [ListRolesRequest][crate::api::auth::aws::requests::ListRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListRolesResponse {
     pub keys: Vec<String>,
 }
@@ -133,7 +133,7 @@ pub struct ReadRoleTagDenyListResponse {
 
 /// Response from executing
 /// [ListDenyListTagsRequest][crate::api::auth::aws::requests::ListDenyListTagsRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListDenyListTagsResponse {
     pub keys: Vec<String>,
 }
@@ -151,7 +151,7 @@ pub struct ReadIdentityAccessListInformationResponse {
 
 /// Response from executing
 /// [ListIdentityAccessListEntriesRequest][crate::api::auth::aws::requests::ListIdentityAccessListEntriesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListIdentityAccessListEntriesResponse {
     pub keys: Vec<String>,
 }
@@ -0,0 +1,42 @@
+use std::fmt;
+
+use rustify_derive::Endpoint;
+
+/// ## Login
+/// Fetches a token using the signed JSON Web Token issued by the Azure
+/// managed identity of a virtual machine, along with the metadata of the
+/// machine which is verified against the bound constraints of the role.
+///
+/// * Path: /auth/{self.mount}/login
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/azure#login
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct LoginWithAzureRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    pub role: String,
+    pub jwt: String,
+    pub subscription_id: Option<String>,
+    pub resource_group_name: Option<String>,
+    pub vm_name: Option<String>,
+    pub vmss_name: Option<String>,
+    pub resource_id: Option<String>,
+}
+
+impl fmt::Debug for LoginWithAzureRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginWithAzureRequest")
+            .field("mount", &self.mount)
+            .field("role", &self.role)
+            .field("jwt", &"[redacted]")
+            .field("subscription_id", &self.subscription_id)
+            .field("resource_group_name", &self.resource_group_name)
+            .field("vm_name", &self.vm_name)
+            .field("vmss_name", &self.vmss_name)
+            .field("resource_id", &self.resource_id)
+            .finish()
+    }
+}
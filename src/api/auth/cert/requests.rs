@@ -0,0 +1,120 @@
+use super::responses::{ListCaCertificateRoleResponse, ReadCaCertificateRoleResponse};
+use rustify_derive::Endpoint;
+
+/// ## Create CA Certificate Role
+/// Sets a CA cert and associated parameters in a role name.
+///
+/// * Path: /auth/{self.mount}/certs/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/cert#create-ca-certificate-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/certs/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateCaCertificateRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    pub certificate: String,
+    pub allowed_common_names: Option<Vec<String>>,
+    pub allowed_dns_sans: Option<Vec<String>>,
+    pub allowed_email_sans: Option<Vec<String>>,
+    pub allowed_uri_sans: Option<Vec<String>>,
+    pub allowed_organizational_units: Option<Vec<String>>,
+    pub required_extensions: Option<Vec<String>>,
+    pub display_name: Option<String>,
+    pub token_ttl: Option<String>,
+    pub token_max_ttl: Option<String>,
+    pub token_policies: Option<Vec<String>>,
+    pub token_bound_cidrs: Option<Vec<String>>,
+    pub token_explicit_max_ttl: Option<String>,
+    pub token_no_default_policy: Option<bool>,
+    pub token_num_uses: Option<u64>,
+    pub token_period: Option<String>,
+    pub token_type: Option<String>,
+}
+
+/// ## Read CA Certificate Role
+/// Gets information associated with the named role.
+///
+/// * Path: /auth/{self.mount}/certs/{self.name}
+/// * Method: GET
+/// * Response: [ReadCaCertificateRoleResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/auth/cert#read-ca-certificate-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/certs/{self.name}",
+    response = "ReadCaCertificateRoleResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadCaCertificateRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Certificate Roles
+/// Lists configured certificate names.
+///
+/// * Path: /auth/{self.mount}/certs
+/// * Method: LIST
+/// * Response: [ListCaCertificateRoleResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/auth/cert#list-certificate-roles
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/certs",
+    method = "LIST",
+    response = "ListCaCertificateRoleResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListCaCertificateRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Delete Certificate Role
+/// Deletes the named role and CA cert from the method mount.
+///
+/// * Path: /auth/{self.mount}/certs/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/cert#delete-certificate-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/certs/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteCaCertificateRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## TLS Certificate Login
+/// Log in and fetch a token using the client certificate presented over TLS.
+/// When no name is given, the certificate is matched against all configured
+/// roles.
+///
+/// * Path: /auth/{self.mount}/login
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/cert#login-with-tls-certificate-method
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct LoginRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    pub name: Option<String>,
+}
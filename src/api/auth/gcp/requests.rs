@@ -0,0 +1,31 @@
+use std::fmt;
+
+use rustify_derive::Endpoint;
+
+/// ## Login
+/// Fetches a token using a signed JSON Web Token of a GCP service account or
+/// the identity token of a GCE instance.
+///
+/// * Path: /auth/{self.mount}/login
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/gcp#login
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
+#[builder(setter(into), default)]
+pub struct LoginWithGcpRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    pub role: String,
+    pub jwt: String,
+}
+
+impl fmt::Debug for LoginWithGcpRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginWithGcpRequest")
+            .field("mount", &self.mount)
+            .field("role", &self.role)
+            .field("jwt", &"[redacted]")
+            .finish()
+    }
+}
@@ -2,6 +2,7 @@ use super::responses::{
     ListRolesResponse, ReadKubernetesAuthConfigResponse, ReadKubernetesRoleResponse,
 };
 use rustify_derive::Endpoint;
+use std::fmt;
 
 /// ## Configure Kubernetes Auth
 /// Sets backend configuration for the Kubernetes auth mount
@@ -51,7 +52,7 @@ pub struct ReadKubernetesAuthConfigRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api/auth/kubernetes#login
-#[derive(Builder, Debug, Endpoint)]
+#[derive(Builder, Endpoint)]
 #[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
 #[builder(setter(into))]
 pub struct LoginWithKubernetesRequest {
@@ -61,6 +62,16 @@ pub struct LoginWithKubernetesRequest {
     pub jwt: String,
 }
 
+impl fmt::Debug for LoginWithKubernetesRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginWithKubernetesRequest")
+            .field("mount", &self.mount)
+            .field("role", &self.role)
+            .field("jwt", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## List Roles
 /// Returns a list the existing Kubernetes roles.
 ///
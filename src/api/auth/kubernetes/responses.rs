@@ -14,7 +14,7 @@ pub struct ReadKubernetesAuthConfigResponse {
 
 /// Response from executing
 /// [ListRolesRequest][crate::api::auth::kubernetes::requests::ListRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListRolesResponse {
     pub keys: Vec<String>,
 }
@@ -0,0 +1,207 @@
+use super::responses::{
+    ListGroupsResponse, ListUsersResponse, ReadGroupResponse, ReadUserResponse,
+};
+use rustify_derive::Endpoint;
+use std::fmt;
+
+/// ## Login
+/// Login with the username and password of an LDAP user.
+///
+/// * Path: /auth/{self.mount}/login/{self.username}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#login-with-ldap-user
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/login/{self.username}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into))]
+pub struct LoginRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub username: String,
+    pub password: String,
+}
+
+impl fmt::Debug for LoginRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginRequest")
+            .field("mount", &self.mount)
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
+
+/// ## Create/Update LDAP Group
+/// Creates or updates the policies associated with an LDAP group.
+///
+/// * Path: /auth/{self.mount}/groups/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#create-update-ldap-group
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/groups/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct SetGroupRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    pub policies: Vec<String>,
+}
+
+/// ## Read LDAP Group
+/// Reads the policies associated with an LDAP group.
+///
+/// * Path: /auth/{self.mount}/groups/{self.name}
+/// * Method: GET
+/// * Response: [ReadGroupResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#read-ldap-group
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/groups/{self.name}",
+    response = "ReadGroupResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadGroupRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Delete LDAP Group
+/// Deletes the policy mapping of an LDAP group.
+///
+/// * Path: /auth/{self.mount}/groups/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#delete-ldap-group
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/groups/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeleteGroupRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List LDAP Groups
+/// Lists the LDAP groups which have a policy mapping.
+///
+/// * Path: /auth/{self.mount}/groups
+/// * Method: LIST
+/// * Response: [ListGroupsResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#list-ldap-groups
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/groups",
+    method = "LIST",
+    response = "ListGroupsResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListGroupsRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Create/Update LDAP User
+/// Creates or updates the groups and policies associated with an LDAP user,
+/// in addition to those of the groups the user is a member of in LDAP.
+///
+/// * Path: /auth/{self.mount}/users/{self.username}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#create-update-ldap-user
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/users/{self.username}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct SetUserRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub username: String,
+    pub groups: Vec<String>,
+    pub policies: Vec<String>,
+}
+
+/// ## Read LDAP User
+/// Reads the groups and policies associated with an LDAP user.
+///
+/// * Path: /auth/{self.mount}/users/{self.username}
+/// * Method: GET
+/// * Response: [ReadUserResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#read-ldap-user
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/users/{self.username}",
+    response = "ReadUserResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadUserRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub username: String,
+}
+
+/// ## Delete LDAP User
+/// Deletes the groups and policies associated with an LDAP user.
+///
+/// * Path: /auth/{self.mount}/users/{self.username}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#delete-ldap-user
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/users/{self.username}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeleteUserRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub username: String,
+}
+
+/// ## List LDAP Users
+/// Lists the LDAP users which have groups or policies associated.
+///
+/// * Path: /auth/{self.mount}/users
+/// * Method: LIST
+/// * Response: [ListUsersResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/auth/ldap#list-ldap-users
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/auth/{self.mount}/users",
+    method = "LIST",
+    response = "ListUsersResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListUsersRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
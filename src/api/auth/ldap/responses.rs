@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Response from executing
+/// [ReadGroupRequest][crate::api::auth::ldap::requests::ReadGroupRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadGroupResponse {
+    pub policies: Vec<String>,
+}
+
+/// Response from executing
+/// [ListGroupsRequest][crate::api::auth::ldap::requests::ListGroupsRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListGroupsResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadUserRequest][crate::api::auth::ldap::requests::ReadUserRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadUserResponse {
+    /// The groups are returned as a comma separated list
+    pub groups: String,
+    pub policies: Vec<String>,
+}
+
+/// Response from executing
+/// [ListUsersRequest][crate::api::auth::ldap::requests::ListUsersRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListUsersResponse {
+    pub keys: Vec<String>,
+}
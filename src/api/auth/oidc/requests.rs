@@ -2,7 +2,10 @@ use super::responses::{
     ListRolesResponse, OIDCAuthResponse, ReadConfigurationResponse, ReadRoleResponse,
 };
 use rustify_derive::Endpoint;
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+};
 
 // ## Configure
 /// Configures the validation information to be used globally across all roles.
@@ -11,7 +14,7 @@ use std::{collections::HashMap, fmt::Debug};
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api/auth/jwt#configure
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/{self.mount}/config", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct SetConfigurationRequest {
@@ -33,6 +36,31 @@ pub struct SetConfigurationRequest {
     pub provider_config: Option<HashMap<String, String>>,
 }
 
+impl fmt::Debug for SetConfigurationRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetConfigurationRequest")
+            .field("mount", &self.mount)
+            .field("bound_issuer", &self.bound_issuer)
+            .field("default_role", &self.default_role)
+            .field("jwks_ca_pem", &self.jwks_ca_pem)
+            .field("jwt_supported_algs", &self.jwt_supported_algs)
+            .field("jwks_url", &self.jwks_url)
+            .field("jwt_validation_pubkeys", &self.jwt_validation_pubkeys)
+            .field("namespace_in_state", &self.namespace_in_state)
+            .field("oidc_discovery_ca_pem", &self.oidc_discovery_ca_pem)
+            .field("oidc_discovery_url", &self.oidc_discovery_url)
+            .field("oidc_client_id", &self.oidc_client_id)
+            .field(
+                "oidc_client_secret",
+                &self.oidc_client_secret.as_ref().map(|_| "[redacted]"),
+            )
+            .field("oidc_response_mode", &self.oidc_response_mode)
+            .field("oidc_response_types", &self.oidc_response_types)
+            .field("provider_config", &self.provider_config)
+            .finish()
+    }
+}
+
 /// ## Read Config
 /// Returns the previously configured config.
 ///
@@ -188,7 +216,7 @@ pub struct OIDCAuthRequest {
 /// * Method: GET
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api/auth/jwt#oidc-callback
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/{self.mount}/oidc/callback", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct OIDCCallbackRequest {
@@ -205,6 +233,17 @@ pub struct OIDCCallbackRequest {
     pub code: String,
 }
 
+impl fmt::Debug for OIDCCallbackRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OIDCCallbackRequest")
+            .field("mount", &self.mount)
+            .field("state", &"[redacted]")
+            .field("nonce", &"[redacted]")
+            .field("code", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## JWT Login
 /// This endpoint takes a signed JSON Web Token (JWT) and a role name for some
 // entity.
@@ -213,7 +252,7 @@ pub struct OIDCCallbackRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api/auth/jwt#jwt-login
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/{self.mount}/login", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct JWTLoginRequest {
@@ -222,3 +261,13 @@ pub struct JWTLoginRequest {
     pub jwt: String,
     pub role: Option<String>,
 }
+
+impl fmt::Debug for JWTLoginRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JWTLoginRequest")
+            .field("mount", &self.mount)
+            .field("jwt", &"[redacted]")
+            .field("role", &self.role)
+            .finish()
+    }
+}
@@ -54,7 +54,7 @@ pub struct ReadRoleResponse {
 
 /// Response from executing
 /// [ListRolesRequest][crate::api::auth::oidc::requests::ListRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListRolesResponse {
     pub keys: Vec<String>,
 }
@@ -1,5 +1,6 @@
 use super::responses::{ListUsersResponse, ReadUserResponse};
 use rustify_derive::Endpoint;
+use std::fmt;
 
 /// ## Create/Update User
 /// Create a new user or update an existing user.
@@ -8,7 +9,7 @@ use rustify_derive::Endpoint;
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/userpass#create-update-user
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/{self.mount}/users/{self.username}",
     method = "POST",
@@ -32,6 +33,25 @@ pub struct CreateUserRequest {
     pub token_type: Option<String>,
 }
 
+impl fmt::Debug for CreateUserRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateUserRequest")
+            .field("mount", &self.mount)
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .field("token_bound_cidrs", &self.token_bound_cidrs)
+            .field("token_explicit_max_ttl", &self.token_explicit_max_ttl)
+            .field("token_no_default_policy", &self.token_no_default_policy)
+            .field("token_num_uses", &self.token_num_uses)
+            .field("token_ttl", &self.token_ttl)
+            .field("token_max_ttl", &self.token_max_ttl)
+            .field("token_period", &self.token_period)
+            .field("token_policies", &self.token_policies)
+            .field("token_type", &self.token_type)
+            .finish()
+    }
+}
+
 /// ## Read User
 /// Reads the properties of an existing username.
 ///
@@ -81,7 +101,7 @@ pub struct DeleteUserRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/userpass#update-password-on-user
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/{self.mount}/users/{self.username}/password",
     method = "POST",
@@ -96,6 +116,16 @@ pub struct UpdatePasswordRequest {
     pub password: String,
 }
 
+impl fmt::Debug for UpdatePasswordRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpdatePasswordRequest")
+            .field("mount", &self.mount)
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Update Policies on User
 /// Update policies for an existing user.
 ///
@@ -145,7 +175,7 @@ pub struct ListUsersRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/userpass#login
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/{self.mount}/login/{self.username}",
     method = "POST",
@@ -159,3 +189,13 @@ pub struct LoginRequest {
     pub username: String,
     pub password: String,
 }
+
+impl fmt::Debug for LoginRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginRequest")
+            .field("mount", &self.mount)
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
@@ -17,7 +17,7 @@ pub struct ReadUserResponse {
 
 /// Response from executing
 /// [ListUsersRequest][crate::api::auth::userpass::requests::ListUsersRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListUsersResponse {
     pub keys: Vec<String>,
 }
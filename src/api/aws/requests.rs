@@ -0,0 +1,35 @@
+use super::responses::GenerateCredentialsResponse;
+use rustify_derive::Endpoint;
+
+/// ## Generate Credentials
+/// This endpoint generates a new set of dynamic AWS credentials based on the
+/// named role. The type of credentials returned depends on the credential
+/// type of the role.
+///
+/// * Path: {self.mount}/creds/{self.name}
+/// * Method: POST
+/// * Response: [GenerateCredentialsResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/aws#generate-credentials
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/creds/{self.name}",
+    method = "POST",
+    response = "GenerateCredentialsResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct GenerateCredentialsRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// The ARN of the role to assume if the role has multiple role ARNs
+    /// configured. Only valid for `assumed_role` credentials.
+    pub role_arn: Option<String>,
+    /// The role session name to attach to the assumed role ARN. Only valid for
+    /// `assumed_role` credentials.
+    pub role_session_name: Option<String>,
+    /// Specifies the TTL for the use of the STS token. Not valid for
+    /// `iam_user` credentials.
+    pub ttl: Option<String>,
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Response from executing
+/// [GenerateCredentialsRequest][crate::api::aws::requests::GenerateCredentialsRequest]
+///
+/// Credentials of `iam_user` roles are long-lived IAM access keys and come
+/// without a `security_token`. The STS based `assumed_role`,
+/// `federation_token` and `session_token` credentials always include one.
+#[derive(Deserialize, Serialize)]
+pub struct GenerateCredentialsResponse {
+    pub access_key: String,
+    pub secret_key: String,
+    pub security_token: Option<String>,
+    pub arn: Option<String>,
+}
+
+impl fmt::Debug for GenerateCredentialsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateCredentialsResponse")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"[redacted]")
+            .field(
+                "security_token",
+                &self.security_token.as_ref().map(|_| "[redacted]"),
+            )
+            .field("arn", &self.arn)
+            .finish()
+    }
+}
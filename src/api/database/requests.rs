@@ -4,7 +4,7 @@ use super::responses::{
     ReadStaticRoleResponse,
 };
 use rustify_derive::Endpoint;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
 /// ## Configure Connection
 /// This endpoint configures the connection string used to communicate with the
@@ -13,7 +13,7 @@ use std::fmt::Debug;
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api/secret/databases#configure-connection
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "{self.mount}/config/{self.name}",
     method = "POST",
@@ -39,6 +39,25 @@ pub struct PostgreSQLConnectionRequest {
     pub username_template: Option<String>,
 }
 
+impl fmt::Debug for PostgreSQLConnectionRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PostgreSQLConnectionRequest")
+            .field("mount", &self.mount)
+            .field("name", &self.name)
+            .field("connection_url", &self.connection_url)
+            .field("plugin_name", &self.plugin_name)
+            .field("password_policy", &self.password_policy)
+            .field("root_rotation_statements", &self.root_rotation_statements)
+            .field("verify_connection", &self.verify_connection)
+            .field("max_idle_connections", &self.max_idle_connections)
+            .field("max_open_connections", &self.max_open_connections)
+            .field("password", &self.password.as_ref().map(|_| "[redacted]"))
+            .field("username", &self.username)
+            .field("username_template", &self.username_template)
+            .finish()
+    }
+}
+
 /// ## Read Connection
 /// This endpoint returns the configuration settings for a connection.
 ///
@@ -127,7 +146,7 @@ pub struct ResetConnectionRequest {
 /// This endpoint is used to rotate the "root" user credentials stored for the
 /// database connection.
 ///
-/// * Path: {{self.mount}/rotate-root/{self.name}
+/// * Path: {self.mount}/rotate-root/{self.name}
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/secret/databases#rotate-root-credentials
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 /// Response from executing
@@ -17,7 +19,7 @@ pub struct ConnectionDetails {
 
 /// Response from executing
 /// [ListConnectionsRequest][crate::api::database::requests::ListConnectionsRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListConnectionsResponse {
     pub keys: Vec<String>,
 }
@@ -37,19 +39,28 @@ pub struct ReadRoleResponse {
 
 /// Response from executing
 /// [ListRolesRequest][crate::api::database::requests::ListRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListRolesResponse {
     pub keys: Vec<String>,
 }
 
 /// Response from executing
 /// [GenerateCredentialsRequest][crate::api::database::requests::GenerateCredentialsRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct GenerateCredentialsResponse {
     pub username: String,
     pub password: String,
 }
 
+impl fmt::Debug for GenerateCredentialsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateCredentialsResponse")
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
+
 /// Response from executing
 /// [ReadStaticRoleRequest][crate::api::database::requests::ReadStaticRoleRequest]
 #[derive(Deserialize, Debug, Serialize)]
@@ -62,14 +73,14 @@ pub struct ReadStaticRoleResponse {
 
 /// Response from executing
 /// [ListStaticRolesRequest][crate::api::database::requests::ListStaticRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListStaticRolesResponse {
     pub keys: Vec<String>,
 }
 
 /// Response from executing
 /// [GetStaticCredentialsRequest][crate::api::database::requests::GetStaticCredentialsRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct GetStaticCredentialsResponse {
     pub last_vault_rotation: String,
     pub password: String,
@@ -77,3 +88,15 @@ pub struct GetStaticCredentialsResponse {
     pub ttl: u64,
     pub username: String,
 }
+
+impl fmt::Debug for GetStaticCredentialsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetStaticCredentialsResponse")
+            .field("last_vault_rotation", &self.last_vault_rotation)
+            .field("password", &"[redacted]")
+            .field("rotation_period", &self.rotation_period)
+            .field("ttl", &self.ttl)
+            .field("username", &self.username)
+            .finish()
+    }
+}
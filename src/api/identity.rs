@@ -0,0 +1,17 @@
+pub mod requests;
+pub mod responses;
+
+use serde::{Deserialize, Serialize};
+
+/// The type of an identity group
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupType {
+    /// Membership is managed in Vault through member entities and groups
+    /// (default)
+    #[default]
+    Internal,
+    /// Membership is managed by an identity provider and mapped to the group
+    /// through a single group alias
+    External,
+}
@@ -0,0 +1,832 @@
+use super::{
+    responses::{
+        CreateEntityAliasResponse, CreateEntityResponse, CreateGroupAliasResponse,
+        CreateGroupResponse, GenerateSignedIdTokenResponse, ListEntitiesResponse,
+        ListEntityAliasesResponse, ListGroupAliasesResponse, ListGroupsResponse,
+        ListNamedKeysResponse, ListRolesResponse, ReadEntityAliasResponse, ReadEntityResponse,
+        ReadGroupAliasResponse, ReadGroupResponse, ReadNamedKeyResponse, ReadPublicKeysResponse,
+        ReadRoleResponse,
+    },
+    GroupType,
+};
+use rustify_derive::Endpoint;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// ## Create an Entity
+/// This endpoint creates an entity.
+///
+/// * Path: /identity/entity
+/// * Method: POST
+/// * Response: [CreateEntityResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#create-an-entity
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity",
+    method = "POST",
+    response = "CreateEntityResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateEntityRequest {
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+    pub disabled: Option<bool>,
+}
+
+/// ## Read Entity by ID
+/// This endpoint queries the entity by its identifier.
+///
+/// * Path: /identity/entity/id/{self.id}
+/// * Method: GET
+/// * Response: [ReadEntityResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#read-entity-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/id/{self.id}",
+    response = "ReadEntityResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadEntityRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## Update Entity by ID
+/// This endpoint is used to update an existing entity.
+///
+/// * Path: /identity/entity/id/{self.id}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#update-entity-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/id/{self.id}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct UpdateEntityRequest {
+    #[endpoint(skip)]
+    pub id: String,
+    pub name: Option<String>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+    pub disabled: Option<bool>,
+}
+
+/// ## Delete Entity by ID
+/// This endpoint deletes an entity and all its associated aliases.
+///
+/// * Path: /identity/entity/id/{self.id}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#delete-entity-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/id/{self.id}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteEntityRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## List Entities by ID
+/// This endpoint returns a list of available entities by their identifiers.
+///
+/// * Path: /identity/entity/id
+/// * Method: LIST
+/// * Response: [ListEntitiesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#list-entities-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/id",
+    method = "LIST",
+    response = "ListEntitiesResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListEntitiesRequest {}
+
+/// ## Create/Update Entity by Name
+/// This endpoint is used to create or update an entity by a given name.
+///
+/// * Path: /identity/entity/name/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#create-update-entity-by-name
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/name/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateEntityByNameRequest {
+    #[endpoint(skip)]
+    pub name: String,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+    pub disabled: Option<bool>,
+}
+
+/// ## Read Entity by Name
+/// This endpoint queries the entity by its name.
+///
+/// * Path: /identity/entity/name/{self.name}
+/// * Method: GET
+/// * Response: [ReadEntityResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#read-entity-by-name
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/name/{self.name}",
+    response = "ReadEntityResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadEntityByNameRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Delete Entity by Name
+/// This endpoint deletes an entity and all its associated aliases, given the
+/// entity name.
+///
+/// * Path: /identity/entity/name/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#delete-entity-by-name
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/name/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteEntityByNameRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Entities by Name
+/// This endpoint returns a list of available entities by their names.
+///
+/// * Path: /identity/entity/name
+/// * Method: LIST
+/// * Response: [ListEntitiesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#list-entities-by-name
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity/name",
+    method = "LIST",
+    response = "ListEntitiesResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListEntitiesByNameRequest {}
+
+/// ## Merge Entities
+/// This endpoint merges many entities into one entity.
+///
+/// * Path: /identity/entity/merge
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity#merge-entities
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "/identity/entity/merge", method = "POST", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct MergeEntitiesRequest {
+    pub from_entity_ids: Vec<String>,
+    pub to_entity_id: String,
+    pub force: Option<bool>,
+    pub conflicting_alias_ids_to_keep: Option<Vec<String>>,
+}
+
+/// ## Create an Entity Alias
+/// This endpoint creates a new alias for an entity.
+///
+/// * Path: /identity/entity-alias
+/// * Method: POST
+/// * Response: [CreateEntityAliasResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity-alias#create-an-entity-alias
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity-alias",
+    method = "POST",
+    response = "CreateEntityAliasResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateEntityAliasRequest {
+    pub name: String,
+    pub canonical_id: String,
+    pub mount_accessor: String,
+    pub id: Option<String>,
+    pub custom_metadata: Option<HashMap<String, String>>,
+}
+
+/// ## Read Entity Alias by ID
+/// This endpoint queries the entity alias by its identifier.
+///
+/// * Path: /identity/entity-alias/id/{self.id}
+/// * Method: GET
+/// * Response: [ReadEntityAliasResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity-alias#read-entity-alias-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity-alias/id/{self.id}",
+    response = "ReadEntityAliasResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadEntityAliasRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## Update Entity Alias by ID
+/// This endpoint is used to update an existing entity alias.
+///
+/// * Path: /identity/entity-alias/id/{self.id}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity-alias#update-entity-alias-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity-alias/id/{self.id}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct UpdateEntityAliasRequest {
+    #[endpoint(skip)]
+    pub id: String,
+    pub name: Option<String>,
+    pub canonical_id: Option<String>,
+    pub mount_accessor: Option<String>,
+    pub custom_metadata: Option<HashMap<String, String>>,
+}
+
+/// ## Delete Entity Alias by ID
+/// This endpoint deletes an alias from its corresponding entity.
+///
+/// * Path: /identity/entity-alias/id/{self.id}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity-alias#delete-entity-alias-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity-alias/id/{self.id}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteEntityAliasRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## List Entity Aliases by ID
+/// This endpoint returns a list of available entity aliases by their
+/// identifiers.
+///
+/// * Path: /identity/entity-alias/id
+/// * Method: LIST
+/// * Response: [ListEntityAliasesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/entity-alias#list-entity-aliases-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/entity-alias/id",
+    method = "LIST",
+    response = "ListEntityAliasesResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListEntityAliasesRequest {}
+
+/// ## Create a Group
+/// This endpoint creates a group.
+///
+/// Only internal groups can have members, external groups get their
+/// membership from a group alias instead.
+///
+/// * Path: /identity/group
+/// * Method: POST
+/// * Response: [CreateGroupResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#create-a-group
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(
+    path = "/identity/group",
+    method = "POST",
+    response = "CreateGroupResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateGroupRequest {
+    pub name: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub group_type: Option<GroupType>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+    pub member_group_ids: Option<Vec<String>>,
+    pub member_entity_ids: Option<Vec<String>>,
+}
+
+/// ## Read Group by ID
+/// This endpoint queries the group by its identifier.
+///
+/// * Path: /identity/group/id/{self.id}
+/// * Method: GET
+/// * Response: [ReadGroupResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#read-group-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group/id/{self.id}",
+    response = "ReadGroupResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadGroupRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## Update Group by ID
+/// This endpoint is used to update an existing group.
+///
+/// * Path: /identity/group/id/{self.id}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#update-group-by-id
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(
+    path = "/identity/group/id/{self.id}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct UpdateGroupRequest {
+    #[endpoint(skip)]
+    pub id: String,
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub group_type: Option<GroupType>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+    pub member_group_ids: Option<Vec<String>>,
+    pub member_entity_ids: Option<Vec<String>>,
+}
+
+/// ## Delete Group by ID
+/// This endpoint deletes a group.
+///
+/// * Path: /identity/group/id/{self.id}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#delete-group-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group/id/{self.id}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteGroupRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## List Groups by ID
+/// This endpoint returns a list of available groups by their identifiers.
+///
+/// * Path: /identity/group/id
+/// * Method: LIST
+/// * Response: [ListGroupsResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#list-groups-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group/id",
+    method = "LIST",
+    response = "ListGroupsResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListGroupsRequest {}
+
+/// ## Create/Update Group by Name
+/// This endpoint is used to create or update a group by its name.
+///
+/// * Path: /identity/group/name/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#create-update-group-by-name
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(
+    path = "/identity/group/name/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateGroupByNameRequest {
+    #[endpoint(skip)]
+    pub name: String,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub group_type: Option<GroupType>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+    pub member_group_ids: Option<Vec<String>>,
+    pub member_entity_ids: Option<Vec<String>>,
+}
+
+/// ## Read Group by Name
+/// This endpoint queries the group by its name.
+///
+/// * Path: /identity/group/name/{self.name}
+/// * Method: GET
+/// * Response: [ReadGroupResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#read-group-by-name
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group/name/{self.name}",
+    response = "ReadGroupResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadGroupByNameRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Delete Group by Name
+/// This endpoint deletes a group, given its name.
+///
+/// * Path: /identity/group/name/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#delete-group-by-name
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group/name/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteGroupByNameRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Groups by Name
+/// This endpoint returns a list of available groups by their names.
+///
+/// * Path: /identity/group/name
+/// * Method: LIST
+/// * Response: [ListGroupsResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group#list-groups-by-name
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group/name",
+    method = "LIST",
+    response = "ListGroupsResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListGroupsByNameRequest {}
+
+/// ## Create a Group Alias
+/// This endpoint creates a group alias, mapping a group from an identity
+/// provider to an external group.
+///
+/// * Path: /identity/group-alias
+/// * Method: POST
+/// * Response: [CreateGroupAliasResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group-alias#create-a-group-alias
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group-alias",
+    method = "POST",
+    response = "CreateGroupAliasResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateGroupAliasRequest {
+    pub name: String,
+    pub mount_accessor: String,
+    pub canonical_id: Option<String>,
+    pub id: Option<String>,
+}
+
+/// ## Read Group Alias by ID
+/// This endpoint queries the group alias by its identifier.
+///
+/// * Path: /identity/group-alias/id/{self.id}
+/// * Method: GET
+/// * Response: [ReadGroupAliasResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group-alias#read-group-alias-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group-alias/id/{self.id}",
+    response = "ReadGroupAliasResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadGroupAliasRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## Update Group Alias by ID
+/// This endpoint is used to update an existing group alias.
+///
+/// * Path: /identity/group-alias/id/{self.id}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group-alias#update-group-alias-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group-alias/id/{self.id}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct UpdateGroupAliasRequest {
+    #[endpoint(skip)]
+    pub id: String,
+    pub name: String,
+    pub mount_accessor: String,
+    pub canonical_id: Option<String>,
+}
+
+/// ## Delete Group Alias by ID
+/// This endpoint deletes a group alias.
+///
+/// * Path: /identity/group-alias/id/{self.id}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group-alias#delete-group-alias-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group-alias/id/{self.id}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteGroupAliasRequest {
+    #[endpoint(skip)]
+    pub id: String,
+}
+
+/// ## List Group Aliases by ID
+/// This endpoint returns a list of available group aliases by their
+/// identifiers.
+///
+/// * Path: /identity/group-alias/id
+/// * Method: LIST
+/// * Response: [ListGroupAliasesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/identity/group-alias#list-group-alias-by-id
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/group-alias/id",
+    method = "LIST",
+    response = "ListGroupAliasesResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListGroupAliasesRequest {}
+
+/// ## Create a Named Key
+/// This endpoint creates or updates a named key which is used by a role to
+/// sign tokens.
+///
+/// * Path: /identity/oidc/key/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#create-a-named-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/key/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateNamedKeyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+    /// How often to generate a new signing key.
+    pub rotation_period: Option<String>,
+    /// How long a public key is available for verification after it was
+    /// rotated.
+    pub verification_ttl: Option<String>,
+    /// The signing algorithm, like `RS256` (default) or `ES256`.
+    pub algorithm: Option<String>,
+    /// The client IDs of the roles allowed to use the key, `*` allows all.
+    pub allowed_client_ids: Option<Vec<String>>,
+}
+
+/// ## Read a Named Key
+/// This endpoint queries a named key.
+///
+/// * Path: /identity/oidc/key/{self.name}
+/// * Method: GET
+/// * Response: [ReadNamedKeyResponse]
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#read-a-named-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/key/{self.name}",
+    response = "ReadNamedKeyResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadNamedKeyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Delete a Named Key
+/// This endpoint deletes a named key, which must not be used by any role.
+///
+/// * Path: /identity/oidc/key/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#delete-a-named-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/key/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteNamedKeyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Named Keys
+/// This endpoint returns a list of the names of all keys.
+///
+/// * Path: /identity/oidc/key
+/// * Method: LIST
+/// * Response: [ListNamedKeysResponse]
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#list-named-keys
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/key",
+    method = "LIST",
+    response = "ListNamedKeysResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListNamedKeysRequest {}
+
+/// ## Rotate a Named Key
+/// This endpoint rotates a named key, generating a new signing key. The
+/// previous public key stays available for verification for the
+/// `verification_ttl`.
+///
+/// * Path: /identity/oidc/key/{self.name}/rotate
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#rotate-a-named-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/key/{self.name}/rotate",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct RotateNamedKeyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+    /// Overrides the `verification_ttl` of the key for the rotated key.
+    pub verification_ttl: Option<String>,
+}
+
+/// ## Create a Role
+/// This endpoint creates or updates a role which issues identity tokens
+/// signed by the given `key`.
+///
+/// * Path: /identity/oidc/role/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#create-a-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/role/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateRoleRequest {
+    #[endpoint(skip)]
+    pub name: String,
+    /// The name of the key used to sign tokens.
+    pub key: String,
+    /// The template of additional claims, as a JSON string.
+    pub template: Option<String>,
+    /// The client ID to use as the audience of tokens, which is generated if
+    /// not set.
+    pub client_id: Option<String>,
+    /// The TTL of tokens.
+    pub ttl: Option<String>,
+}
+
+/// ## Read a Role
+/// This endpoint queries a role.
+///
+/// * Path: /identity/oidc/role/{self.name}
+/// * Method: GET
+/// * Response: [ReadRoleResponse]
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#read-a-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/role/{self.name}",
+    response = "ReadRoleResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadRoleRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Delete a Role
+/// This endpoint deletes a role.
+///
+/// * Path: /identity/oidc/role/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#delete-a-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/role/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteRoleRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Roles
+/// This endpoint returns a list of the names of all roles.
+///
+/// * Path: /identity/oidc/role
+/// * Method: LIST
+/// * Response: [ListRolesResponse]
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#list-roles
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/role",
+    method = "LIST",
+    response = "ListRolesResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListRolesRequest {}
+
+/// ## Generate a Signed ID Token
+/// This endpoint generates a signed identity token for the entity of the
+/// requesting token using the given role.
+///
+/// * Path: /identity/oidc/token/{self.name}
+/// * Method: GET
+/// * Response: [GenerateSignedIdTokenResponse]
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#generate-a-signed-id-token
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/token/{self.name}",
+    response = "GenerateSignedIdTokenResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct GenerateSignedIdTokenRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Read .well-known Keys
+/// This endpoint returns the public keys used to verify identity tokens as a
+/// JSON Web Key Set. The response isn't wrapped in the usual API response.
+///
+/// * Path: /identity/oidc/.well-known/keys
+/// * Method: GET
+/// * Response: [ReadPublicKeysResponse]
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/identity/tokens#read-well-known-keys
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "/identity/oidc/.well-known/keys",
+    response = "ReadPublicKeysResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadPublicKeysRequest {}
@@ -0,0 +1,211 @@
+use std::{collections::HashMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use super::GroupType;
+
+/// Response from executing
+/// [CreateEntityRequest][crate::api::identity::requests::CreateEntityRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct CreateEntityResponse {
+    pub id: String,
+    pub name: String,
+}
+
+/// Response from executing
+/// [ReadEntityRequest][crate::api::identity::requests::ReadEntityRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadEntityResponse {
+    pub id: String,
+    pub name: String,
+    pub aliases: Vec<ReadEntityAliasResponse>,
+    pub creation_time: String,
+    pub disabled: bool,
+    pub last_update_time: String,
+    pub merged_entity_ids: Option<Vec<String>>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+}
+
+/// Response from executing
+/// [ListEntitiesRequest][crate::api::identity::requests::ListEntitiesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListEntitiesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [CreateEntityAliasRequest][crate::api::identity::requests::CreateEntityAliasRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct CreateEntityAliasResponse {
+    pub id: String,
+    pub canonical_id: String,
+}
+
+/// Response from executing
+/// [ReadEntityAliasRequest][crate::api::identity::requests::ReadEntityAliasRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadEntityAliasResponse {
+    pub id: String,
+    pub canonical_id: String,
+    pub name: String,
+    pub creation_time: String,
+    pub last_update_time: String,
+    pub merged_from_canonical_ids: Option<Vec<String>>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub custom_metadata: Option<HashMap<String, String>>,
+    pub mount_accessor: String,
+    #[serde(default)]
+    pub mount_path: String,
+    #[serde(default)]
+    pub mount_type: String,
+}
+
+/// Response from executing
+/// [ListEntityAliasesRequest][crate::api::identity::requests::ListEntityAliasesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListEntityAliasesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [CreateGroupRequest][crate::api::identity::requests::CreateGroupRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct CreateGroupResponse {
+    pub id: String,
+    pub name: String,
+}
+
+/// Response from executing
+/// [ReadGroupRequest][crate::api::identity::requests::ReadGroupRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadGroupResponse {
+    pub id: String,
+    pub name: String,
+    pub alias: Option<ReadGroupAliasResponse>,
+    pub creation_time: String,
+    pub last_update_time: String,
+    pub member_entity_ids: Option<Vec<String>>,
+    pub member_group_ids: Option<Vec<String>>,
+    pub parent_group_ids: Option<Vec<String>>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub policies: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    pub group_type: GroupType,
+}
+
+/// Response from executing
+/// [ListGroupsRequest][crate::api::identity::requests::ListGroupsRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListGroupsResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [CreateGroupAliasRequest][crate::api::identity::requests::CreateGroupAliasRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct CreateGroupAliasResponse {
+    pub id: String,
+    pub canonical_id: String,
+}
+
+/// Response from executing
+/// [ReadGroupAliasRequest][crate::api::identity::requests::ReadGroupAliasRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadGroupAliasResponse {
+    pub id: String,
+    pub canonical_id: String,
+    pub name: String,
+    pub creation_time: String,
+    pub last_update_time: String,
+    pub merged_from_canonical_ids: Option<Vec<String>>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub mount_accessor: String,
+    #[serde(default)]
+    pub mount_path: String,
+    #[serde(default)]
+    pub mount_type: String,
+}
+
+/// Response from executing
+/// [ListGroupAliasesRequest][crate::api::identity::requests::ListGroupAliasesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListGroupAliasesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadNamedKeyRequest][crate::api::identity::requests::ReadNamedKeyRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadNamedKeyResponse {
+    pub algorithm: String,
+    pub allowed_client_ids: Vec<String>,
+    pub rotation_period: u64,
+    pub verification_ttl: u64,
+}
+
+/// Response from executing
+/// [ListNamedKeysRequest][crate::api::identity::requests::ListNamedKeysRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListNamedKeysResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadRoleRequest][crate::api::identity::requests::ReadRoleRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadRoleResponse {
+    pub client_id: String,
+    pub key: String,
+    pub template: String,
+    pub ttl: u64,
+}
+
+/// Response from executing
+/// [ListRolesRequest][crate::api::identity::requests::ListRolesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListRolesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [GenerateSignedIdTokenRequest][crate::api::identity::requests::GenerateSignedIdTokenRequest]
+#[derive(Deserialize, Serialize)]
+pub struct GenerateSignedIdTokenResponse {
+    pub client_id: String,
+    pub token: String,
+    pub ttl: u64,
+}
+
+impl fmt::Debug for GenerateSignedIdTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateSignedIdTokenResponse")
+            .field("client_id", &self.client_id)
+            .field("token", &"[redacted]")
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+/// Response from executing
+/// [ReadPublicKeysRequest][crate::api::identity::requests::ReadPublicKeysRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadPublicKeysResponse {
+    pub keys: Vec<JsonWebKey>,
+}
+
+/// A public key of a [ReadPublicKeysResponse]. RSA keys carry the modulus `n`
+/// and exponent `e`, EC keys the curve `crv` and the coordinates `x` and `y`.
+#[derive(Deserialize, Debug, Serialize)]
+pub struct JsonWebKey {
+    pub kid: String,
+    pub kty: String,
+    pub alg: Option<String>,
+    #[serde(rename = "use")]
+    pub key_use: Option<String>,
+    pub n: Option<String>,
+    pub e: Option<String>,
+    pub crv: Option<String>,
+    pub x: Option<String>,
+    pub y: Option<String>,
+}
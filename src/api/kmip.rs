@@ -0,0 +1,22 @@
+pub mod requests;
+pub mod responses;
+
+use serde::{Deserialize, Serialize};
+
+/// The format of generated KMIP client credentials
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialFormat {
+    #[default]
+    Pem,
+    PemBundle,
+    Der,
+}
+
+/// The type of the keys used for TLS
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TlsKeyType {
+    Ec,
+    Rsa,
+}
@@ -0,0 +1,363 @@
+use super::responses::{
+    GenerateCredentialResponse, ListCredentialsResponse, ListRolesResponse, ListScopesResponse,
+    LookupCredentialResponse, ReadConfigurationResponse, ReadRoleResponse,
+};
+use super::{CredentialFormat, TlsKeyType};
+use rustify_derive::Endpoint;
+
+/// ## Write Config
+/// This endpoint configures the KMIP server which is run by the secrets
+/// engine.
+///
+/// * Path: {self.mount}/config
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#write-config
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "{self.mount}/config", method = "POST", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct SetConfigurationRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Addresses the KMIP server should listen on (`host:port`).
+    pub listen_addrs: Option<Vec<String>>,
+    /// Hostnames to include in the server's TLS certificate as SAN DNS names.
+    pub server_hostnames: Option<Vec<String>>,
+    /// IPs to include in the server's TLS certificate as SAN IP addresses.
+    pub server_ips: Option<Vec<String>>,
+    /// Duration in seconds before closing an inactive client connection.
+    pub connection_timeout: Option<u64>,
+    /// CA key type.
+    pub tls_ca_key_type: Option<TlsKeyType>,
+    /// CA key bits, valid values depend on the key type.
+    pub tls_ca_key_bits: Option<u64>,
+    /// Minimum TLS version to accept, one of `tls12` or `tls13`.
+    pub tls_min_version: Option<String>,
+    /// The default key type of generated client certificates.
+    pub default_tls_client_key_type: Option<TlsKeyType>,
+    /// The default key bits of generated client certificates.
+    pub default_tls_client_key_bits: Option<u64>,
+    /// The default TTL of generated client certificates.
+    pub default_tls_client_ttl: Option<String>,
+}
+
+/// ## Read Config
+/// This endpoint returns the configuration of the KMIP server.
+///
+/// * Path: {self.mount}/config
+/// * Method: GET
+/// * Response: ReadConfigurationResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#read-config
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/config",
+    response = "ReadConfigurationResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadConfigurationRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Create Scope
+/// This endpoint creates a new KMIP scope, which isolates the managed objects
+/// of its roles from other scopes.
+///
+/// * Path: {self.mount}/scope/{self.scope}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#create-scope
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct CreateScopeRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+}
+
+/// ## List Scopes
+/// This endpoint lists the KMIP scopes.
+///
+/// * Path: {self.mount}/scope
+/// * Method: LIST
+/// * Response: ListScopesResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#list-scopes
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope",
+    response = "ListScopesResponse",
+    method = "LIST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListScopesRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Delete Scope
+/// This endpoint deletes a KMIP scope. A scope which still contains managed
+/// objects can only be deleted when `force` is set.
+///
+/// * Path: {self.mount}/scope/{self.scope}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#delete-scope
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteScopeRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    /// Deletes the scope even if it contains managed objects.
+    #[endpoint(query)]
+    pub force: Option<bool>,
+}
+
+/// ## Write Role
+/// This endpoint creates or updates a role within a scope. The `operation_*`
+/// flags define which KMIP operations clients of the role may perform.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#write-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct SetRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// The key type of generated client certificates.
+    pub tls_client_key_type: Option<TlsKeyType>,
+    /// The key bits of generated client certificates.
+    pub tls_client_key_bits: Option<u64>,
+    /// The TTL of generated client certificates.
+    pub tls_client_ttl: Option<String>,
+    /// Grants all KMIP operations.
+    pub operation_all: Option<bool>,
+    /// Grants no KMIP operations, which takes precedence over the others.
+    pub operation_none: Option<bool>,
+    /// Grants the activate operation.
+    pub operation_activate: Option<bool>,
+    /// Grants the add attribute operation.
+    pub operation_add_attribute: Option<bool>,
+    /// Grants the create operation.
+    pub operation_create: Option<bool>,
+    /// Grants the delete attribute operation.
+    pub operation_delete_attribute: Option<bool>,
+    /// Grants the destroy operation.
+    pub operation_destroy: Option<bool>,
+    /// Grants the discover versions operation.
+    pub operation_discover_versions: Option<bool>,
+    /// Grants the get operation.
+    pub operation_get: Option<bool>,
+    /// Grants the get attribute list operation.
+    pub operation_get_attribute_list: Option<bool>,
+    /// Grants the get attributes operation.
+    pub operation_get_attributes: Option<bool>,
+    /// Grants the locate operation.
+    pub operation_locate: Option<bool>,
+    /// Grants the modify attribute operation.
+    pub operation_modify_attribute: Option<bool>,
+    /// Grants the query operation.
+    pub operation_query: Option<bool>,
+    /// Grants the register operation.
+    pub operation_register: Option<bool>,
+    /// Grants the rekey operation.
+    pub operation_rekey: Option<bool>,
+    /// Grants the revoke operation.
+    pub operation_revoke: Option<bool>,
+}
+
+/// ## Read Role
+/// This endpoint reads a role within a scope.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role/{self.name}
+/// * Method: GET
+/// * Response: ReadRoleResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#read-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role/{self.name}",
+    response = "ReadRoleResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Roles
+/// This endpoint lists the roles within a scope.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role
+/// * Method: LIST
+/// * Response: ListRolesResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#list-roles
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role",
+    response = "ListRolesResponse",
+    method = "LIST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListRolesRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+}
+
+/// ## Delete Role
+/// This endpoint deletes a role within a scope.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#delete-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeleteRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Generate Credential
+/// This endpoint generates a client certificate which KMIP clients use to
+/// authenticate as the given role.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role/{self.role}/credential/generate
+/// * Method: POST
+/// * Response: GenerateCredentialResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#generate-credential
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role/{self.role}/credential/generate",
+    method = "POST",
+    response = "GenerateCredentialResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct GenerateCredentialRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    #[endpoint(skip)]
+    pub role: String,
+    /// The format of the returned credential.
+    pub format: Option<CredentialFormat>,
+}
+
+/// ## Lookup Credential
+/// This endpoint returns the client certificate with the given serial number.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role/{self.role}/credential/lookup
+/// * Method: GET
+/// * Response: LookupCredentialResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#lookup-credential
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role/{self.role}/credential/lookup",
+    response = "LookupCredentialResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct LookupCredentialRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    #[endpoint(skip)]
+    pub role: String,
+    #[endpoint(query)]
+    pub serial_number: String,
+}
+
+/// ## List Credentials
+/// This endpoint lists the serial numbers of the client certificates of a
+/// role.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role/{self.role}/credential
+/// * Method: LIST
+/// * Response: ListCredentialsResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#list-credential-serial-numbers
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role/{self.role}/credential",
+    response = "ListCredentialsResponse",
+    method = "LIST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListCredentialsRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    #[endpoint(skip)]
+    pub role: String,
+}
+
+/// ## Revoke Credential
+/// This endpoint revokes a client certificate, identified either by its
+/// `serial_number` or the PEM encoded `certificate`.
+///
+/// * Path: {self.mount}/scope/{self.scope}/role/{self.role}/credential/revoke
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/kmip#revoke-credential
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/scope/{self.scope}/role/{self.role}/credential/revoke",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct RevokeCredentialRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub scope: String,
+    #[endpoint(skip)]
+    pub role: String,
+    pub serial_number: Option<String>,
+    pub certificate: Option<String>,
+}
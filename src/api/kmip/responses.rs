@@ -0,0 +1,123 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::TlsKeyType;
+
+/// Response from executing
+/// [ReadConfigurationRequest][crate::api::kmip::requests::ReadConfigurationRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadConfigurationResponse {
+    #[serde(default)]
+    pub listen_addrs: Vec<String>,
+    #[serde(default)]
+    pub server_hostnames: Vec<String>,
+    #[serde(default)]
+    pub server_ips: Vec<String>,
+    pub connection_timeout: Option<u64>,
+    pub tls_ca_key_type: Option<TlsKeyType>,
+    pub tls_ca_key_bits: Option<u64>,
+    pub tls_min_version: Option<String>,
+    pub default_tls_client_key_type: Option<TlsKeyType>,
+    pub default_tls_client_key_bits: Option<u64>,
+    pub default_tls_client_ttl: Option<u64>,
+}
+
+/// Response from executing
+/// [ListScopesRequest][crate::api::kmip::requests::ListScopesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListScopesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadRoleRequest][crate::api::kmip::requests::ReadRoleRequest]
+///
+/// Vault only returns the operations which are granted, so the others are
+/// `false`.
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadRoleResponse {
+    pub tls_client_key_type: Option<TlsKeyType>,
+    pub tls_client_key_bits: Option<u64>,
+    pub tls_client_ttl: Option<u64>,
+    #[serde(default)]
+    pub operation_all: bool,
+    #[serde(default)]
+    pub operation_none: bool,
+    #[serde(default)]
+    pub operation_activate: bool,
+    #[serde(default)]
+    pub operation_add_attribute: bool,
+    #[serde(default)]
+    pub operation_create: bool,
+    #[serde(default)]
+    pub operation_delete_attribute: bool,
+    #[serde(default)]
+    pub operation_destroy: bool,
+    #[serde(default)]
+    pub operation_discover_versions: bool,
+    #[serde(default)]
+    pub operation_get: bool,
+    #[serde(default)]
+    pub operation_get_attribute_list: bool,
+    #[serde(default)]
+    pub operation_get_attributes: bool,
+    #[serde(default)]
+    pub operation_locate: bool,
+    #[serde(default)]
+    pub operation_modify_attribute: bool,
+    #[serde(default)]
+    pub operation_query: bool,
+    #[serde(default)]
+    pub operation_register: bool,
+    #[serde(default)]
+    pub operation_rekey: bool,
+    #[serde(default)]
+    pub operation_revoke: bool,
+}
+
+/// Response from executing
+/// [ListRolesRequest][crate::api::kmip::requests::ListRolesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListRolesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [GenerateCredentialRequest][crate::api::kmip::requests::GenerateCredentialRequest]
+#[derive(Deserialize, Serialize)]
+pub struct GenerateCredentialResponse {
+    #[serde(default)]
+    pub ca_chain: Vec<String>,
+    pub certificate: String,
+    pub private_key: String,
+    pub serial_number: String,
+}
+
+impl fmt::Debug for GenerateCredentialResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateCredentialResponse")
+            .field("ca_chain", &self.ca_chain)
+            .field("certificate", &self.certificate)
+            .field("private_key", &"[redacted]")
+            .field("serial_number", &self.serial_number)
+            .finish()
+    }
+}
+
+/// Response from executing
+/// [LookupCredentialRequest][crate::api::kmip::requests::LookupCredentialRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct LookupCredentialResponse {
+    #[serde(default)]
+    pub ca_chain: Vec<String>,
+    pub certificate: String,
+    pub serial_number: String,
+}
+
+/// Response from executing
+/// [ListCredentialsRequest][crate::api::kmip::requests::ListCredentialsRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListCredentialsResponse {
+    pub keys: Vec<String>,
+}
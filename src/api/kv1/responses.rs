@@ -13,11 +13,12 @@ pub struct GetSecretResponse {
     pub lease_id: String,
     pub renewable: bool,
     pub request_id: String,
+    pub warnings: Option<Vec<String>>,
 }
 
 /// Response from executing
 /// [ListSecretRequest][crate::api::kv1::requests::ListSecretRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListSecretResponse {
     pub data: ListSecretResponseKeys,
 
@@ -28,7 +29,7 @@ pub struct ListSecretResponse {
     pub renewable: bool,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListSecretResponseKeys {
     pub keys: Vec<String>,
 }
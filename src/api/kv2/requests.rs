@@ -1,10 +1,10 @@
 use super::responses::{
     ListSecretsResponse, ReadConfigurationResponse, ReadSecretMetadataResponse, ReadSecretResponse,
-    SecretVersionMetadata,
+    ReadSecretSubkeysResponse, SecretVersionMetadata,
 };
 use rustify_derive::Endpoint;
 use serde_json::Value;
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 /// ## Configure the KV Engine
 /// This path configures backend level settings that are applied to every key in
@@ -69,6 +69,35 @@ pub struct ReadSecretRequest {
     pub version: Option<u64>,
 }
 
+/// ## Read Secret Subkeys
+/// This endpoint provides the subkeys within a secret entry that exists at the
+/// requested path. The secret entry at this path will be retrieved and
+/// stripped of all data by replacing underlying values of leaf keys with null.
+///
+/// * Path: {self.mount}/subkeys/{self.path}
+/// * Method: GET
+/// * Response: ReadSecretSubkeysResponse
+/// * Reference: https://www.vaultproject.io/api-docs/secret/kv/kv-v2#read-secret-subkeys
+#[derive(Builder, Debug, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/subkeys/{self.path}",
+    response = "ReadSecretSubkeysResponse",
+    builder = "true"
+)]
+#[builder(setter(into))]
+pub struct ReadSecretSubkeysRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub path: String,
+    #[builder(default = "None")]
+    #[endpoint(query)]
+    pub version: Option<u64>,
+    #[builder(default = "None")]
+    #[endpoint(query)]
+    pub depth: Option<u64>,
+}
+
 /// ## Create/Update Secret
 /// This endpoint creates a new version of a secret at the specified location.
 ///
@@ -100,6 +129,33 @@ pub struct SetSecretRequestOptions {
     pub cas: u32,
 }
 
+/// ## Patch Secret
+/// This endpoint provides the ability to patch an existing secret at the
+/// specified location. The secret must not be deleted or destroyed. Keys set
+/// to `null` in the patch are removed from the secret.
+///
+/// * Path: {self.mount}/data/{self.path}
+/// * Method: PATCH
+/// * Response: SecretVersionMetadata
+/// * Reference: https://www.vaultproject.io/api-docs/secret/kv/kv-v2#patch-secret
+#[derive(Builder, Debug, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/data/{self.path}",
+    response = "SecretVersionMetadata",
+    method = "PATCH",
+    builder = "true"
+)]
+#[builder(setter(into))]
+pub struct PatchSecretRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub path: String,
+    pub data: Value,
+    #[builder(setter(strip_option), default)]
+    pub options: Option<SetSecretRequestOptions>,
+}
+
 /// ## Delete Latest Version of Secret
 /// This endpoint issues a soft delete of the secret's latest version at the
 /// specified location.
@@ -255,7 +311,11 @@ pub struct SetSecretMetadataRequest {
     pub path: String,
     pub max_versions: Option<u64>,
     pub cas_required: Option<bool>,
+    /// A duration string like `3h25m19s` after which versions are deleted.
     pub delete_version_after: Option<String>,
+    /// Arbitrary string key/value pairs, which replace any existing custom
+    /// metadata of the secret.
+    pub custom_metadata: Option<HashMap<String, String>>,
 }
 
 /// ## Delete Metadata and All Versions
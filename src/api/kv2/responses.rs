@@ -30,6 +30,14 @@ pub struct SecretVersionMetadata {
     pub version: u64,
 }
 
+/// Response from executing
+/// [ReadSecretSubkeysRequest][crate::api::kv2::requests::ReadSecretSubkeysRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadSecretSubkeysResponse {
+    pub subkeys: Value,
+    pub metadata: SecretVersionMetadata,
+}
+
 /// Response from executing
 /// [ListSecretsRequest][crate::api::kv2::requests::ListSecretsRequest]
 #[derive(Deserialize, Debug, Serialize)]
@@ -44,11 +52,16 @@ pub struct ReadSecretMetadataResponse {
     pub cas_required: bool,
     pub created_time: String,
     pub current_version: u64,
+    pub custom_metadata: Option<HashMap<String, String>>,
     pub delete_version_after: String,
     pub max_versions: u64,
     pub oldest_version: u64,
     pub updated_time: String,
+    /// The metadata of every version of the secret, keyed by version number
     pub versions: HashMap<String, SecretMetadata>,
+    /// The fields of the response which aren't known to this crate
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 /// Response from executing
@@ -56,6 +69,7 @@ pub struct ReadSecretMetadataResponse {
 #[derive(Deserialize, Debug, Serialize)]
 pub struct SecretMetadata {
     pub created_time: String,
+    /// Empty unless the version was deleted
     pub deletion_time: String,
     pub destroyed: bool,
 }
@@ -2,9 +2,10 @@ use super::responses::{
     GenerateCertificateResponse, GenerateIntermediateResponse, GenerateRootResponse,
     ListCertificatesResponse, ListRolesResponse, ReadCRLConfigResponse, ReadCertificateResponse,
     ReadRoleResponse, ReadURLsResponse, RevokeCertificateResponse, RotateCRLsResponse,
-    SignCertificateResponse, SignIntermediateResponse, SignSelfIssuedResponse,
+    SignCertificateResponse, SignIntermediateResponse, SignSelfIssuedResponse, TidyStatusResponse,
 };
 use rustify_derive::Endpoint;
+use std::fmt;
 
 /// ## Submit CA Information
 /// This endpoint allows submitting the CA information for the backend via a PEM
@@ -15,7 +16,7 @@ use rustify_derive::Endpoint;
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api/secret/pki#submit-ca-information
 
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "{self.mount}/config/ca", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct SubmitCARequest {
@@ -24,6 +25,15 @@ pub struct SubmitCARequest {
     pub pem_bundle: String,
 }
 
+impl fmt::Debug for SubmitCARequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubmitCARequest")
+            .field("mount", &self.mount)
+            .field("pem_bundle", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Generate Root
 /// https://www.vaultproject.io/api/secret/pki#generate-root
 /// This endpoint generates a new self-signed CA certificate and private key. If
@@ -186,6 +196,52 @@ pub struct SignSelfIssuedRequest {
     pub certificate: String,
 }
 
+/// ## Read CA Certificate
+/// This endpoint retrieves the CA certificate in raw DER-encoded form.
+///
+/// * Path: {self.mount}/ca
+/// * Method: GET
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api/secret/pki#read-ca-certificate
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "{self.mount}/ca", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadCACertificateRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Read CA Certificate (PEM)
+/// This endpoint retrieves the CA certificate in raw PEM-encoded form.
+///
+/// * Path: {self.mount}/ca/pem
+/// * Method: GET
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api/secret/pki#read-ca-certificate
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "{self.mount}/ca/pem", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadCACertificatePemRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Read CA Certificate Chain
+/// This endpoint retrieves the CA certificate chain, including the CA, in
+/// PEM format.
+///
+/// * Path: {self.mount}/ca_chain
+/// * Method: GET
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api/secret/pki#read-ca-certificate-chain
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "{self.mount}/ca_chain", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadCAChainRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
 /// ## List Certificates
 /// This endpoint returns a list of the current certificates by serial number
 /// only.
@@ -240,7 +296,7 @@ pub struct ReadCertificateRequest {
 /// * Path: {self.mount}/issue/{self.role}
 /// * Method: POST
 /// * Response: [GenerateCertificateResponse]
-/// * Reference: https://www.vaultproject.io/api/secret/pki#read-certificate
+/// * Reference: https://www.vaultproject.io/api/secret/pki#generate-certificate
 #[derive(Builder, Debug, Default, Endpoint)]
 #[endpoint(
     path = "{self.mount}/issue/{self.role}",
@@ -259,6 +315,7 @@ pub struct GenerateCertificateRequest {
     pub exclude_cn_from_sans: Option<bool>,
     pub format: Option<String>,
     pub ip_sans: Option<String>,
+    pub not_after: Option<String>,
     pub other_sans: Option<Vec<String>>,
     pub private_key_format: Option<String>,
     pub ttl: Option<String>,
@@ -288,6 +345,73 @@ pub struct RevokeCertificateRequest {
     pub serial_number: String,
 }
 
+/// ## Revoke Certificate with Private Key
+/// This endpoint revokes a certificate using its private key as proof that the
+/// request is authorized by an appropriate individual. Either the serial
+/// number or the PEM encoded certificate must be given. This endpoint is only
+/// available in Vault 1.12 and later.
+///
+/// * Path: {self.mount}/revoke-with-key
+/// * Method: POST
+/// * Response: [RevokeCertificateResponse]
+/// * Reference: https://www.vaultproject.io/api/secret/pki#revoke-certificate-with-private-key
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/revoke-with-key",
+    method = "POST",
+    response = "RevokeCertificateResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct RevokeCertificateWithKeyRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    pub serial_number: Option<String>,
+    pub certificate: Option<String>,
+    pub private_key: String,
+}
+
+impl fmt::Debug for RevokeCertificateWithKeyRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevokeCertificateWithKeyRequest")
+            .field("mount", &self.mount)
+            .field("serial_number", &self.serial_number)
+            .field("certificate", &self.certificate)
+            .field("private_key", &"[redacted]")
+            .finish()
+    }
+}
+
+/// ## Read CRL
+/// This endpoint retrieves the current CRL in raw DER-encoded form.
+///
+/// * Path: {self.mount}/crl
+/// * Method: GET
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api/secret/pki#read-crl
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "{self.mount}/crl", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadCRLRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Read CRL (PEM)
+/// This endpoint retrieves the current CRL in raw PEM-encoded form.
+///
+/// * Path: {self.mount}/crl/pem
+/// * Method: GET
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api/secret/pki#read-crl
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "{self.mount}/crl/pem", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadCRLPemRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
 /// ## Read CRL Configuration
 /// This endpoint allows getting the duration for which the generated CRL should
 /// be marked valid.
@@ -601,3 +725,23 @@ pub struct TidyRequest {
     pub tidy_revoked_certs: Option<bool>,
     pub safety_buffer: Option<String>,
 }
+
+/// ## Tidy Status
+/// This endpoint allows monitoring the status of the tidy operation which is
+/// currently running or was last run.
+///
+/// * Path: {self.mount}/tidy-status
+/// * Method: GET
+/// * Response: [TidyStatusResponse]
+/// * Reference: https://www.vaultproject.io/api/secret/pki#tidy-status
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/tidy-status",
+    response = "TidyStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct TidyStatusRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
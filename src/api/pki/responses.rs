@@ -1,8 +1,10 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 /// Response from executing
 /// [GenerateCertificateRequest][crate::api::pki::requests::GenerateCertificateRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct GenerateCertificateResponse {
     pub ca_chain: Option<Vec<String>>,
     pub certificate: String,
@@ -12,15 +14,41 @@ pub struct GenerateCertificateResponse {
     pub serial_number: String,
 }
 
+impl fmt::Debug for GenerateCertificateResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateCertificateResponse")
+            .field("ca_chain", &self.ca_chain)
+            .field("certificate", &self.certificate)
+            .field("issuing_ca", &self.issuing_ca)
+            .field("private_key", &"[redacted]")
+            .field("private_key_type", &self.private_key_type)
+            .field("serial_number", &self.serial_number)
+            .finish()
+    }
+}
+
 /// Response from executing
 /// [GenerateIntermediateRequest][crate::api::pki::requests::GenerateIntermediateRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct GenerateIntermediateResponse {
     pub csr: String,
     pub private_key: Option<String>,
     pub private_key_type: Option<String>,
 }
 
+impl fmt::Debug for GenerateIntermediateResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateIntermediateResponse")
+            .field("csr", &self.csr)
+            .field(
+                "private_key",
+                &self.private_key.as_ref().map(|_| "[redacted]"),
+            )
+            .field("private_key_type", &self.private_key_type)
+            .finish()
+    }
+}
+
 /// Response from executing
 /// [GenerateRootRequest][crate::api::pki::requests::GenerateRootRequest]
 #[derive(Deserialize, Debug, Serialize)]
@@ -39,7 +67,7 @@ pub struct ListCertificatesResponse {
 
 /// Response from executing
 /// [ListRolesRequest][crate::api::pki::requests::ListRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListRolesResponse {
     pub keys: Vec<String>,
 }
@@ -155,3 +183,19 @@ pub struct SignSelfIssuedResponse {
     pub certificate: String,
     pub issuing_ca: String,
 }
+
+/// Response from executing
+/// [TidyStatusRequest][crate::api::pki::requests::TidyStatusRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct TidyStatusResponse {
+    pub cert_store_deleted_count: Option<u64>,
+    pub error: Option<String>,
+    pub message: Option<String>,
+    pub revoked_cert_deleted_count: Option<u64>,
+    pub safety_buffer: Option<u64>,
+    pub state: String,
+    pub tidy_cert_store: Option<bool>,
+    pub tidy_revoked_certs: Option<bool>,
+    pub time_finished: Option<String>,
+    pub time_started: Option<String>,
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use super::responses::{
     GenerateSSHCredsResponse, ListRolesByIPResponse, ListRolesResponse,
@@ -14,7 +14,7 @@ use rustify_derive::Endpoint;
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/secret/ssh#create-update-key
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "{self.mount}/keys/{self.name}",
     method = "POST",
@@ -29,6 +29,16 @@ pub struct SetKeyRequest {
     pub key: String,
 }
 
+impl fmt::Debug for SetKeyRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetKeyRequest")
+            .field("mount", &self.mount)
+            .field("name", &self.name)
+            .field("key", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Delete Key
 /// This endpoint deletes a named key.
 ///
@@ -291,7 +301,7 @@ pub struct VerifySSHOTPRequest {
 /// * Method: POST
 /// * Response: [SubmitCAInfoResponse]
 /// * Reference: https://www.vaultproject.io/api-docs/secret/ssh#submit-ca-information
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "{self.mount}/config/ca",
     method = "POST",
@@ -307,6 +317,20 @@ pub struct SubmitCAInfoRequest {
     pub public_key: Option<String>,
 }
 
+impl fmt::Debug for SubmitCAInfoRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubmitCAInfoRequest")
+            .field("mount", &self.mount)
+            .field("generate_signing_key", &self.generate_signing_key)
+            .field(
+                "private_key",
+                &self.private_key.as_ref().map(|_| "[redacted]"),
+            )
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
 /// ## Delete CA Information
 /// This endpoint deletes the CA information for the backend via an SSH key pair.
 ///
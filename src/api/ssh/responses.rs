@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use serde::{Deserialize, Serialize};
 
@@ -33,7 +33,7 @@ pub struct ReadRoleResponse {
 
 /// Response from executing
 /// [ListRolesRequest][crate::api::ssh::requests::ListRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListRolesResponse {
     pub keys: Vec<String>,
     pub key_info: HashMap<String, KeyInfo>,
@@ -55,7 +55,7 @@ pub struct ListZeroAddressRolesResponse {
 
 /// Response from executing
 /// [GenerateSSHCredsRequest][crate::api::ssh::requests::GenerateSSHCredsRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct GenerateSSHCredsResponse {
     pub allowed_users: Option<String>,
     pub admin_user: Option<String>,
@@ -63,11 +63,33 @@ pub struct GenerateSSHCredsResponse {
     pub default_user: Option<String>,
     pub exclude_cidr_list: Option<String>,
     pub install_script: Option<String>,
+    pub ip: Option<String>,
     pub key: String,
     pub key_bits: Option<u64>,
     pub key_option_specs: Option<String>,
     pub key_type: String,
     pub port: u64,
+    pub username: Option<String>,
+}
+
+impl fmt::Debug for GenerateSSHCredsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateSSHCredsResponse")
+            .field("allowed_users", &self.allowed_users)
+            .field("admin_user", &self.admin_user)
+            .field("cidr_list", &self.cidr_list)
+            .field("default_user", &self.default_user)
+            .field("exclude_cidr_list", &self.exclude_cidr_list)
+            .field("install_script", &self.install_script)
+            .field("ip", &self.ip)
+            .field("key", &"[redacted]")
+            .field("key_bits", &self.key_bits)
+            .field("key_option_specs", &self.key_option_specs)
+            .field("key_type", &self.key_type)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .finish()
+    }
 }
 
 /// Response from executing
@@ -82,6 +104,7 @@ pub struct ListRolesByIPResponse {
 #[derive(Deserialize, Debug, Serialize)]
 pub struct VerifySSHOTPResponse {
     pub ip: String,
+    pub role_name: Option<String>,
     pub username: String,
 }
 
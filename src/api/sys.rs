@@ -1,2 +1,61 @@
 pub mod requests;
 pub mod responses;
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The enforcement level of a Sentinel policy
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EnforcementLevel {
+    /// Failing the policy is allowed and only logged
+    Advisory,
+    /// Failing the policy can be overridden with the `X-Vault-Policy-Override`
+    /// header
+    SoftMandatory,
+    /// Failing the policy always denies the request (default)
+    #[default]
+    HardMandatory,
+}
+
+/// The format of the telemetry returned by
+/// [ReadMetricsRequest][requests::ReadMetricsRequest]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsFormat {
+    /// The metrics of the in-memory sink as JSON (default)
+    #[default]
+    Json,
+    /// The Prometheus text exposition format
+    Prometheus,
+}
+
+/// The type of a plugin in the plugin catalog
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginType {
+    Auth,
+    Database,
+    #[default]
+    Secret,
+}
+
+impl fmt::Display for PluginType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auth => write!(f, "auth"),
+            Self::Database => write!(f, "database"),
+            Self::Secret => write!(f, "secret"),
+        }
+    }
+}
+
+/// The backends reloaded by [reload][crate::sys::plugins::reload]
+#[derive(Clone, Debug)]
+pub enum PluginReloadTarget {
+    /// All mounts which use the plugin with the given name
+    Plugin(String),
+    /// The given mount paths
+    Mounts(Vec<String>),
+}
@@ -1,11 +1,22 @@
-use super::responses::{
-    AuthResponse, ListPoliciesResponse, MountResponse, ReadHealthResponse, ReadPolicyResponse,
-    UnsealResponse, WrappingLookupResponse,
+use super::{
+    responses::{
+        AuditDeviceResponse, AuditHashResponse, AuthResponse, CapabilitiesResponse,
+        GenerateRootStatusResponse, InitResponse, ListEGPPoliciesResponse, ListLeasesResponse,
+        ListPluginsResponse, ListPoliciesResponse, ListRGPPoliciesResponse, MountResponse,
+        ReadAuthTuneResponse, ReadEGPPolicyResponse, ReadHealthResponse, ReadInitStatusResponse,
+        ReadLeaderResponse, ReadLeaseResponse, ReadMetricsResponse, ReadMountTuneResponse,
+        ReadPluginResponse, ReadPolicyResponse, ReadRGPPolicyResponse,
+        ReadRaftAutopilotConfigurationResponse, ReadRaftAutopilotStateResponse,
+        ReadRaftConfigurationResponse, ReadSealStatusResponse, RekeyStatusResponse,
+        RekeyUpdateResponse, RekeyVerifyResponse, RenewLeaseResponse, UnsealResponse,
+        WrappingLookupResponse,
+    },
+    EnforcementLevel, MetricsFormat, PluginType,
 };
 use rustify_derive::Endpoint;
 use serde::Serialize;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 /// ## Enable Secrets Engine
 /// This endpoint enables a new secrets engine at the given path.
@@ -25,6 +36,7 @@ pub struct EnableEngineRequest {
     pub engine_type: Option<String>,
     pub description: Option<String>,
     pub config: Option<EnableEngineDataConfig>,
+    /// Options specific to the engine, like `version` for the KV engine.
     pub options: Option<HashMap<String, String>>,
 }
 
@@ -58,6 +70,57 @@ pub struct EnableEngineDataConfig {
 #[builder(setter(into, strip_option), default)]
 pub struct ListMountsRequest {}
 
+/// ## Read Mount Configuration
+/// This endpoint reads the given mount's configuration.
+///
+/// * Path: sys/mounts/{self.path}/tune
+/// * Method: GET
+/// * Response: [ReadMountTuneResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/mounts#read-mount-configuration
+
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "sys/mounts/{self.path}/tune",
+    response = "ReadMountTuneResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadMountTuneRequest {
+    #[endpoint(skip)]
+    pub path: String,
+}
+
+/// ## Tune Mount Configuration
+/// This endpoint tunes configuration parameters for a given mount point.
+///
+/// TTLs accept either a number of seconds or a duration string like `"1h"`.
+///
+/// * Path: sys/mounts/{self.path}/tune
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/mounts#tune-mount-configuration
+
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(
+    path = "sys/mounts/{self.path}/tune",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct TuneMountRequest {
+    #[endpoint(skip)]
+    pub path: String,
+    pub default_lease_ttl: Option<String>,
+    pub max_lease_ttl: Option<String>,
+    pub description: Option<String>,
+    pub audit_non_hmac_request_keys: Option<Vec<String>>,
+    pub audit_non_hmac_response_keys: Option<Vec<String>>,
+    pub listing_visibility: Option<String>,
+    pub passthrough_request_headers: Option<Vec<String>>,
+    pub allowed_response_headers: Option<Vec<String>>,
+    pub options: Option<HashMap<String, String>>,
+}
+
 /// ## Enable Auth Method
 /// This endpoint enables a new auth method.
 ///
@@ -89,6 +152,7 @@ pub struct EnableAuthDataConfig {
     pub listing_visibility: Option<String>,
     pub passthrough_request_headers: Option<Vec<String>>,
     pub allowed_response_headers: Option<Vec<String>>,
+    pub token_type: Option<String>,
 }
 
 /// ## List Auth Methods
@@ -108,6 +172,155 @@ pub struct EnableAuthDataConfig {
 #[builder(setter(into, strip_option), default)]
 pub struct ListAuthsRequest {}
 
+/// ## Disable Auth Method
+/// This endpoint disables the auth method at the given auth path.
+///
+/// * Path: sys/auth/{self.path}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/auth#disable-auth-method
+
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "sys/auth/{self.path}", method = "DELETE", builder = "true")]
+#[builder(setter(into), default)]
+pub struct DisableAuthRequest {
+    #[endpoint(skip)]
+    pub path: String,
+}
+
+/// ## Read Auth Method Tuning
+/// This endpoint reads the given auth path's configuration.
+///
+/// * Path: sys/auth/{self.path}/tune
+/// * Method: GET
+/// * Response: [ReadAuthTuneResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/auth#read-auth-method-tuning
+
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "sys/auth/{self.path}/tune",
+    response = "ReadAuthTuneResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadAuthTuneRequest {
+    #[endpoint(skip)]
+    pub path: String,
+}
+
+/// ## Tune Auth Method
+/// This endpoint tunes configuration parameters for a given auth path.
+///
+/// TTLs accept either a number of seconds or a duration string like `"1h"`.
+///
+/// * Path: sys/auth/{self.path}/tune
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/auth#tune-auth-method
+
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(path = "sys/auth/{self.path}/tune", method = "POST", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct TuneAuthRequest {
+    #[endpoint(skip)]
+    pub path: String,
+    pub default_lease_ttl: Option<String>,
+    pub max_lease_ttl: Option<String>,
+    pub description: Option<String>,
+    pub audit_non_hmac_request_keys: Option<Vec<String>>,
+    pub audit_non_hmac_response_keys: Option<Vec<String>>,
+    pub listing_visibility: Option<String>,
+    pub passthrough_request_headers: Option<Vec<String>>,
+    pub allowed_response_headers: Option<Vec<String>>,
+    pub token_type: Option<String>,
+}
+
+/// ## Enable Audit Device
+/// This endpoint enables a new audit device at the supplied path.
+///
+/// * Path: sys/audit/{self.path}
+/// * Method: PUT
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/audit#enable-audit-device
+
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(path = "sys/audit/{self.path}", method = "PUT", builder = "true")]
+#[builder(setter(into, strip_option), default)]
+pub struct EnableAuditRequest {
+    #[endpoint(skip)]
+    pub path: String,
+    #[serde(rename = "type")]
+    pub device_type: String,
+    pub description: Option<String>,
+    pub options: Option<HashMap<String, String>>,
+    pub local: Option<bool>,
+}
+
+/// ## Disable Audit Device
+/// This endpoint disables the audit device at the given path.
+///
+/// * Path: sys/audit/{self.path}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/audit#disable-audit-device
+
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(path = "sys/audit/{self.path}", method = "DELETE", builder = "true")]
+#[builder(setter(into), default)]
+pub struct DisableAuditRequest {
+    #[endpoint(skip)]
+    pub path: String,
+}
+
+/// ## List Enabled Audit Devices
+/// This endpoint lists only the enabled audit devices.
+///
+/// * Path: sys/audit
+/// * Method: GET
+/// * Response: `HashMap<String, AuditDeviceResponse>` ([AuditDeviceResponse])
+/// * Reference: https://www.vaultproject.io/api-docs/system/audit#list-enabled-audit-devices
+
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "sys/audit",
+    response = "HashMap<String, AuditDeviceResponse>",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListAuditsRequest {}
+
+/// ## Calculate Hash
+/// This endpoint hashes the given input data with the specified audit device's
+/// hash function and salt.
+///
+/// * Path: sys/audit-hash/{self.path}
+/// * Method: POST
+/// * Response: [AuditHashResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/audit-hash
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "sys/audit-hash/{self.path}",
+    method = "POST",
+    response = "AuditHashResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct AuditHashRequest {
+    #[endpoint(skip)]
+    pub path: String,
+    pub input: String,
+}
+
+impl fmt::Debug for AuditHashRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuditHashRequest")
+            .field("path", &self.path)
+            .field("input", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Wrapping Unwrap
 /// This endpoint returns the original response inside the given wrapping token.
 ///
@@ -123,6 +336,46 @@ pub struct UnwrapRequest {
     pub token: Option<String>,
 }
 
+/// ## Wrapping Wrap
+/// This endpoint wraps the given user-supplied data inside a response-wrapped
+/// token.
+///
+/// * Path: /sys/wrapping/wrap
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/wrapping-wrap#wrapping-wrap
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/wrapping/wrap",
+    method = "POST",
+    response = "Value",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct WrapRequest {
+    // The data is sent as is rather than being enclosed in a `data` field
+    #[endpoint(raw)]
+    pub data: Vec<u8>,
+}
+
+/// ## Wrapping Rewrap
+/// This endpoint rewraps a response-wrapped token. The new token will use the
+/// same creation TTL as the original token and contain the same response. The
+/// old token will be invalidated.
+///
+/// * Path: /sys/wrapping/rewrap
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/wrapping-rewrap#wrapping-rewrap
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(path = "/sys/wrapping/rewrap", method = "POST", builder = "true")]
+#[builder(setter(into), default)]
+pub struct RewrapRequest {
+    pub token: String,
+}
+
 /// ## Wrapping Lookup
 /// This endpoint returns the wrapping token properties.
 ///
@@ -173,8 +426,91 @@ pub struct ReadHealthRequest {}
 #[builder(setter(into), default)]
 pub struct SealRequest {}
 
+/// ## Read Initialization Status
+/// This endpoint returns the initialization status of Vault.
+///
+/// * Path: /sys/init
+/// * Method: GET
+/// * Response: [ReadInitStatusResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/init#read-initialization-status
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/init",
+    response = "ReadInitStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadInitStatusRequest {}
+
+/// ## Start Initialization
+/// This endpoint initializes a new Vault. The Vault must not have been
+/// previously initialized.
+///
+/// * Path: /sys/init
+/// * Method: PUT
+/// * Response: [InitResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/init#start-initialization
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/init",
+    method = "PUT",
+    response = "InitResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct InitRequest {
+    pub pgp_keys: Option<Vec<String>>,
+    pub root_token_pgp_key: Option<String>,
+    pub secret_shares: u64,
+    pub secret_threshold: u64,
+    pub stored_shares: Option<u64>,
+    pub recovery_shares: Option<u64>,
+    pub recovery_threshold: Option<u64>,
+    pub recovery_pgp_keys: Option<Vec<String>>,
+}
+
+/// ## Read Leader and HA Status
+/// This endpoint returns the high availability status and current leader
+/// instance of Vault. It doesn't require authentication.
+///
+/// * Path: /sys/leader
+/// * Method: GET
+/// * Response: [ReadLeaderResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/leader
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/leader",
+    response = "ReadLeaderResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadLeaderRequest {}
+
+/// ## Seal Status
+/// This endpoint returns the seal status of the Vault. It doesn't require
+/// authentication.
+///
+/// * Path: /sys/seal-status
+/// * Method: GET
+/// * Response: [ReadSealStatusResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/seal-status
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/seal-status",
+    response = "ReadSealStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadSealStatusRequest {}
+
 /// ## Unseal
-/// This endpoint unseals the Vault.
+/// This endpoint is used to enter a single unseal key share to progress the
+/// unsealing of the Vault. Setting `reset` discards the key shares entered
+/// so far.
 ///
 /// * Path: /sys/unseal
 /// * Method: PUT
@@ -195,69 +531,868 @@ pub struct UnsealRequest {
     pub migrate: Option<bool>,
 }
 
-/// ## List Policies
-/// This endpoint lists all configured policies.
+/// ## List ACL Policies
+/// This endpoint lists all configured ACL policies.
 ///
-/// * Path: /sys/policy
-/// * Method: GET
+/// The legacy `/sys/policy` endpoints only manage ACL policies as well, while
+/// `/sys/policies/acl` is part of the newer API which also covers the Sentinel
+/// policies of Vault Enterprise.
+///
+/// * Path: /sys/policies/acl
+/// * Method: LIST
 /// * Response: [ListPoliciesResponse]
-/// * Reference: https://www.vaultproject.io/api-docs/system/policy#list-policies
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#list-acl-policies
 
 #[derive(Builder, Default, Endpoint)]
 #[endpoint(
-    path = "/sys/policy",
+    path = "/sys/policies/acl",
+    method = "LIST",
     response = "ListPoliciesResponse",
     builder = "true"
 )]
 #[builder(setter(into), default)]
 pub struct ListPoliciesRequest {}
 
-/// ## Read Policy
-/// This endpoint retrieve the policy body for the named policy.
+/// ## Read ACL Policy
+/// This endpoint retrieves the policy document of the named ACL policy.
 ///
-/// * Path: /sys/policy/{self.name}
+/// * Path: /sys/policies/acl/{self.name}
 /// * Method: GET
 /// * Response: [ReadPolicyResponse]
-/// * Reference: https://www.vaultproject.io/api-docs/system/policy#read-policy
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#read-acl-policy
 
 #[derive(Builder, Default, Endpoint)]
 #[endpoint(
-    path = "/sys/policy/{self.name}",
+    path = "/sys/policies/acl/{self.name}",
     response = "ReadPolicyResponse",
     builder = "true"
 )]
 #[builder(setter(into), default)]
 pub struct ReadPolicyRequest {
+    #[endpoint(skip)]
     pub name: String,
 }
 
-/// ## Create/Update Policy
-/// This endpoint adds a new or updates an existing policy.
+/// ## Create/Update ACL Policy
+/// This endpoint adds a new or updates an existing ACL policy.
 ///
-/// * Path: /sys/policy/{self.name}
+/// * Path: /sys/policies/acl/{self.name}
 /// * Method: PUT
 /// * Response: N/A
-/// * Reference: https://www.vaultproject.io/api-docs/system/policy#create-update-policy
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#create-update-acl-policy
 
 #[derive(Builder, Default, Endpoint)]
-#[endpoint(path = "/sys/policy/{self.name}", method = "PUT", builder = "true")]
+#[endpoint(
+    path = "/sys/policies/acl/{self.name}",
+    method = "PUT",
+    builder = "true"
+)]
 #[builder(setter(into), default)]
 pub struct CreatePolicyRequest {
+    #[endpoint(skip)]
     pub name: String,
     pub policy: String,
 }
 
-/// ## Delete Policy
-/// This endpoint deletes the policy with the given name.
+/// ## Delete ACL Policy
+/// This endpoint deletes the ACL policy with the given name.
 ///
-/// * Path: /sys/policy/{self.name}
+/// * Path: /sys/policies/acl/{self.name}
 /// * Method: DELETE
 /// * Response: N/A
-/// * Reference: https://www.vaultproject.io/api-docs/system/policy#delete-policy
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#delete-acl-policy
 
 #[derive(Builder, Default, Endpoint)]
-#[endpoint(path = "/sys/policy/{self.name}", method = "DELETE", builder = "true")]
+#[endpoint(
+    path = "/sys/policies/acl/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
 #[builder(setter(into), default)]
 pub struct DeletePolicyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List RGP Policies
+/// This endpoint lists all configured role governing policies. It's only available
+/// in Vault Enterprise.
+///
+/// * Path: /sys/policies/rgp
+/// * Method: LIST
+/// * Response: [ListRGPPoliciesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#list-rgp-policies
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/rgp",
+    method = "LIST",
+    response = "ListRGPPoliciesResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListRGPPoliciesRequest {}
+
+/// ## Read RGP Policy
+/// This endpoint retrieves information about the named role governing policy. It's
+/// only available in Vault Enterprise.
+///
+/// * Path: /sys/policies/rgp/{self.name}
+/// * Method: GET
+/// * Response: [ReadRGPPolicyResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#read-rgp-policy
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/rgp/{self.name}",
+    response = "ReadRGPPolicyResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadRGPPolicyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Create/Update RGP Policy
+/// This endpoint adds a new or updates an existing role governing policy. It's only
+/// available in Vault Enterprise.
+///
+/// * Path: /sys/policies/rgp/{self.name}
+/// * Method: PUT
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#create-update-rgp-policy
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/rgp/{self.name}",
+    method = "PUT",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct CreateRGPPolicyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+    pub policy: String,
+    pub enforcement_level: EnforcementLevel,
+}
+
+/// ## Delete RGP Policy
+/// This endpoint deletes the role governing policy with the given name. It's only
+/// available in Vault Enterprise.
+///
+/// * Path: /sys/policies/rgp/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#delete-rgp-policy
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/rgp/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeleteRGPPolicyRequest {
+    #[endpoint(skip)]
     pub name: String,
 }
+
+/// ## List EGP Policies
+/// This endpoint lists all configured endpoint governing policies. It's only available
+/// in Vault Enterprise.
+///
+/// * Path: /sys/policies/egp
+/// * Method: LIST
+/// * Response: [ListEGPPoliciesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#list-egp-policies
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/egp",
+    method = "LIST",
+    response = "ListEGPPoliciesResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListEGPPoliciesRequest {}
+
+/// ## Read EGP Policy
+/// This endpoint retrieves information about the named endpoint governing policy. It's
+/// only available in Vault Enterprise.
+///
+/// * Path: /sys/policies/egp/{self.name}
+/// * Method: GET
+/// * Response: [ReadEGPPolicyResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#read-egp-policy
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/egp/{self.name}",
+    response = "ReadEGPPolicyResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadEGPPolicyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Create/Update EGP Policy
+/// This endpoint adds a new or updates an existing endpoint governing policy. It's only
+/// available in Vault Enterprise.
+///
+/// * Path: /sys/policies/egp/{self.name}
+/// * Method: PUT
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#create-update-egp-policy
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/egp/{self.name}",
+    method = "PUT",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct CreateEGPPolicyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+    pub policy: String,
+    pub enforcement_level: EnforcementLevel,
+    pub paths: Vec<String>,
+}
+
+/// ## Delete EGP Policy
+/// This endpoint deletes the endpoint governing policy with the given name. It's only
+/// available in Vault Enterprise.
+///
+/// * Path: /sys/policies/egp/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/policies#delete-egp-policy
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/policies/egp/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeleteEGPPolicyRequest {
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Read Lease
+/// This endpoint retrieves lease metadata.
+///
+/// * Path: /sys/leases/lookup
+/// * Method: PUT
+/// * Response: [ReadLeaseResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/leases#read-lease
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/leases/lookup",
+    method = "PUT",
+    response = "ReadLeaseResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadLeaseRequest {
+    pub lease_id: String,
+}
+
+/// ## List Leases
+/// This endpoint returns a list of lease ids under the given prefix.
+///
+/// * Path: /sys/leases/lookup/{self.prefix}
+/// * Method: LIST
+/// * Response: [ListLeasesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/leases#list-leases
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/leases/lookup/{self.prefix}",
+    method = "LIST",
+    response = "ListLeasesResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListLeasesRequest {
+    #[endpoint(skip)]
+    pub prefix: String,
+}
+
+/// ## Renew Lease
+/// This endpoint renews a lease, requesting to extend the lease.
+///
+/// * Path: /sys/leases/renew
+/// * Method: PUT
+/// * Response: [RenewLeaseResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/leases#renew-lease
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/leases/renew",
+    method = "PUT",
+    response = "RenewLeaseResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct RenewLeaseRequest {
+    pub lease_id: String,
+    pub increment: Option<String>,
+}
+
+/// ## Revoke Lease
+/// This endpoint revokes a lease immediately.
+///
+/// * Path: /sys/leases/revoke
+/// * Method: PUT
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/leases#revoke-lease
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(path = "/sys/leases/revoke", method = "PUT", builder = "true")]
+#[builder(setter(into), default)]
+pub struct RevokeLeaseRequest {
+    pub lease_id: String,
+}
+
+/// ## Revoke Prefix
+/// This endpoint revokes all secrets or tokens generated under a given prefix
+/// immediately.
+///
+/// * Path: /sys/leases/revoke-prefix/{self.prefix}
+/// * Method: PUT
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/leases#revoke-prefix
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/leases/revoke-prefix/{self.prefix}",
+    method = "PUT",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct RevokePrefixRequest {
+    #[endpoint(skip)]
+    pub prefix: String,
+}
+
+/// ## Query Self Capabilities
+/// This endpoint returns the capabilities of the token used to call it on the
+/// given paths.
+///
+/// * Path: /sys/capabilities-self
+/// * Method: POST
+/// * Response: [CapabilitiesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/capabilities-self
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/capabilities-self",
+    method = "POST",
+    response = "CapabilitiesResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct CapabilitiesSelfRequest {
+    pub paths: Vec<String>,
+}
+
+/// ## Query Token Capabilities
+/// This endpoint returns the capabilities of the given token on the given
+/// paths.
+///
+/// * Path: /sys/capabilities
+/// * Method: POST
+/// * Response: [CapabilitiesResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/capabilities
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/capabilities",
+    method = "POST",
+    response = "CapabilitiesResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct CapabilitiesRequest {
+    pub paths: Vec<String>,
+    pub token: String,
+}
+
+/// ## Read Raft Configuration
+/// This endpoint returns the details of all the peers of the Raft cluster.
+///
+/// * Path: /sys/storage/raft/configuration
+/// * Method: GET
+/// * Response: [ReadRaftConfigurationResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raft#get-raft-configuration
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/storage/raft/configuration",
+    response = "ReadRaftConfigurationResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadRaftConfigurationRequest {}
+
+/// ## Remove a Raft Peer
+/// This endpoint removes a node from the Raft cluster.
+///
+/// * Path: /sys/storage/raft/remove-peer
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raft#remove-a-node-from-raft-cluster
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/storage/raft/remove-peer",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct RemoveRaftPeerRequest {
+    pub server_id: String,
+}
+
+/// ## Read Autopilot State
+/// This endpoint returns the state of the Raft cluster as seen by autopilot,
+/// including the health of each of its servers.
+///
+/// * Path: /sys/storage/raft/autopilot/state
+/// * Method: GET
+/// * Response: [ReadRaftAutopilotStateResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raftautopilot#get-cluster-state
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/storage/raft/autopilot/state",
+    response = "ReadRaftAutopilotStateResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadRaftAutopilotStateRequest {}
+
+/// ## Read Autopilot Configuration
+/// This endpoint returns the configuration of autopilot.
+///
+/// * Path: /sys/storage/raft/autopilot/configuration
+/// * Method: GET
+/// * Response: [ReadRaftAutopilotConfigurationResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raftautopilot#get-configuration
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/storage/raft/autopilot/configuration",
+    response = "ReadRaftAutopilotConfigurationResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadRaftAutopilotConfigurationRequest {}
+
+/// ## Set Autopilot Configuration
+/// This endpoint updates the configuration of autopilot. Durations are given
+/// as strings like `10s`.
+///
+/// * Path: /sys/storage/raft/autopilot/configuration
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raftautopilot#set-configuration
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/storage/raft/autopilot/configuration",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct SetRaftAutopilotConfigurationRequest {
+    pub cleanup_dead_servers: Option<bool>,
+    pub last_contact_threshold: Option<String>,
+    pub dead_server_last_contact_threshold: Option<String>,
+    pub max_trailing_logs: Option<u64>,
+    pub min_quorum: Option<u64>,
+    pub server_stabilization_time: Option<String>,
+    pub disable_upgrade_migration: Option<bool>,
+}
+
+/// ## Take a Snapshot of the Raft Cluster
+/// This endpoint returns a snapshot of the current state of the Raft cluster
+/// as a gzip compressed archive.
+///
+/// * Path: /sys/storage/raft/snapshot
+/// * Method: GET
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raft#take-a-snapshot-of-the-raft-cluster
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(path = "/sys/storage/raft/snapshot", builder = "true")]
+#[builder(setter(into), default)]
+pub struct SaveRaftSnapshotRequest {}
+
+/// ## Restore Raft using a Snapshot
+/// This endpoint installs the given snapshot, as returned by
+/// [SaveRaftSnapshotRequest], on the Raft cluster.
+///
+/// * Path: /sys/storage/raft/snapshot
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raft#restore-raft-using-a-snapshot
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(path = "/sys/storage/raft/snapshot", method = "POST", builder = "true")]
+#[builder(setter(into), default)]
+pub struct RestoreRaftSnapshotRequest {
+    #[endpoint(raw)]
+    pub snapshot: Vec<u8>,
+}
+
+/// ## Force Restore Raft using a Snapshot
+/// This endpoint installs the given snapshot on the Raft cluster even if its
+/// keyring doesn't match the cluster, for example when restoring it on a new
+/// cluster.
+///
+/// * Path: /sys/storage/raft/snapshot-force
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/storage/raft#force-restore-raft-using-a-snapshot
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/storage/raft/snapshot-force",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct RestoreRaftSnapshotForceRequest {
+    #[endpoint(raw)]
+    pub snapshot: Vec<u8>,
+}
+
+/// ## List Plugins
+/// This endpoint lists the plugins in the catalog by type.
+///
+/// * Path: /sys/plugins/catalog
+/// * Method: GET
+/// * Response: [ListPluginsResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/plugins-catalog#list-plugins
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/plugins/catalog",
+    response = "ListPluginsResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListPluginsRequest {}
+
+/// ## Register Plugin
+/// This endpoint registers a new plugin, or updates an existing one, with the
+/// supplied name and type. Vault verifies the SHA-256 checksum of the binary
+/// when registering the plugin.
+///
+/// * Path: /sys/plugins/catalog/{self.plugin_type}/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/plugins-catalog#register-plugin
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/plugins/catalog/{self.plugin_type}/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct RegisterPluginRequest {
+    #[endpoint(skip)]
+    pub plugin_type: PluginType,
+    #[endpoint(skip)]
+    pub name: String,
+    pub sha256: String,
+    pub command: String,
+    pub args: Option<Vec<String>>,
+    pub env: Option<Vec<String>>,
+    pub version: Option<String>,
+}
+
+/// ## Read Plugin
+/// This endpoint returns the configuration data for the plugin with the given
+/// name and type.
+///
+/// * Path: /sys/plugins/catalog/{self.plugin_type}/{self.name}
+/// * Method: GET
+/// * Response: [ReadPluginResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/plugins-catalog#read-plugin
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/plugins/catalog/{self.plugin_type}/{self.name}",
+    response = "ReadPluginResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadPluginRequest {
+    #[endpoint(skip)]
+    pub plugin_type: PluginType,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Remove Plugin from Catalog
+/// This endpoint removes the plugin with the given name and type from the
+/// catalog.
+///
+/// * Path: /sys/plugins/catalog/{self.plugin_type}/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/plugins-catalog#remove-plugin-from-catalog
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/plugins/catalog/{self.plugin_type}/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeregisterPluginRequest {
+    #[endpoint(skip)]
+    pub plugin_type: PluginType,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Reload Plugins
+/// This endpoint reloads mounted plugin backends. Either the plugin, which
+/// reloads all mounts using it, or the mounts must be given.
+///
+/// * Path: /sys/plugins/reload/backend
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/plugins-reload-backend
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/plugins/reload/backend",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReloadPluginsRequest {
+    pub plugin: Option<String>,
+    pub mounts: Option<Vec<String>>,
+    pub scope: Option<String>,
+}
+
+/// ## Read Root Generation Progress
+/// This endpoint reads the configuration and process of the current root
+/// generation attempt.
+///
+/// * Path: /sys/generate-root/attempt
+/// * Method: GET
+/// * Response: [GenerateRootStatusResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/generate-root#read-root-generation-progress
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/generate-root/attempt",
+    response = "GenerateRootStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadGenerateRootStatusRequest {}
+
+/// ## Start Root Token Generation
+/// This endpoint initializes a new root generation attempt. Only a single
+/// root generation attempt can take place at a time. Vault generates an OTP
+/// for encoding the token unless a PGP key is given.
+///
+/// * Path: /sys/generate-root/attempt
+/// * Method: PUT
+/// * Response: [GenerateRootStatusResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/generate-root#start-root-token-generation
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/generate-root/attempt",
+    method = "PUT",
+    response = "GenerateRootStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct StartGenerateRootRequest {
+    pub otp: Option<String>,
+    pub pgp_key: Option<String>,
+}
+
+/// ## Cancel Root Generation
+/// This endpoint cancels any in-progress root generation attempt. This clears
+/// any progress made.
+///
+/// * Path: /sys/generate-root/attempt
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/generate-root#cancel-root-generation
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/generate-root/attempt",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct CancelGenerateRootRequest {}
+
+/// ## Provide Key Share to Generate Root
+/// This endpoint is used to enter a single unseal key share to progress the
+/// root generation attempt. Once the required number of shares is reached,
+/// the encoded root token is returned.
+///
+/// * Path: /sys/generate-root/update
+/// * Method: PUT
+/// * Response: [GenerateRootStatusResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/generate-root#provide-key-share-to-generate-root
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/generate-root/update",
+    method = "PUT",
+    response = "GenerateRootStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct UpdateGenerateRootRequest {
+    pub key: String,
+    pub nonce: String,
+}
+
+/// ## Read Rekey Progress
+/// This endpoint reads the configuration and progress of the current rekey
+/// attempt.
+///
+/// * Path: /sys/rekey/init
+/// * Method: GET
+/// * Response: [RekeyStatusResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/rekey#read-rekey-progress
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/rekey/init",
+    response = "RekeyStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadRekeyStatusRequest {}
+
+/// ## Start Rekey
+/// This endpoint initializes a new rekey attempt. Only a single rekey attempt
+/// can take place at a time, and changing the parameters of a rekey requires
+/// canceling and starting a new rekey.
+///
+/// * Path: /sys/rekey/init
+/// * Method: PUT
+/// * Response: [RekeyStatusResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/rekey#start-rekey
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/rekey/init",
+    method = "PUT",
+    response = "RekeyStatusResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct StartRekeyRequest {
+    pub secret_shares: u64,
+    pub secret_threshold: u64,
+    pub pgp_keys: Option<Vec<String>>,
+    pub backup: Option<bool>,
+    pub require_verification: Option<bool>,
+}
+
+/// ## Cancel Rekey
+/// This endpoint cancels any in-progress rekey. This clears the rekey
+/// settings as well as any progress made.
+///
+/// * Path: /sys/rekey/init
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/system/rekey#cancel-rekey
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(path = "/sys/rekey/init", method = "DELETE", builder = "true")]
+#[builder(setter(into), default)]
+pub struct CancelRekeyRequest {}
+
+/// ## Submit Key
+/// This endpoint is used to enter a single unseal key share to progress the
+/// rekey of the Vault. Once the required number of shares is reached, the new
+/// key shares are returned.
+///
+/// * Path: /sys/rekey/update
+/// * Method: PUT
+/// * Response: [RekeyUpdateResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/rekey#submit-key
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/rekey/update",
+    method = "PUT",
+    response = "RekeyUpdateResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct UpdateRekeyRequest {
+    pub key: String,
+    pub nonce: String,
+}
+
+/// ## Submit Verification Key
+/// This endpoint is used to enter a single new key share to progress the
+/// verification of a rekey which requires verification. The rekey only takes
+/// effect once the threshold of new key shares has been submitted.
+///
+/// * Path: /sys/rekey/verify
+/// * Method: PUT
+/// * Response: [RekeyVerifyResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/rekey#submit-verification-key
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/rekey/verify",
+    method = "PUT",
+    response = "RekeyVerifyResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct VerifyRekeyRequest {
+    pub key: String,
+    pub nonce: String,
+}
+
+/// ## Read Metrics
+/// This endpoint returns the telemetry metrics of Vault. It returns JSON by
+/// default, or the Prometheus text exposition format if the `format` is
+/// `prometheus`, which requires enabling Prometheus in the telemetry
+/// configuration.
+///
+/// * Path: /sys/metrics
+/// * Method: GET
+/// * Response: [ReadMetricsResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/system/metrics
+
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "/sys/metrics",
+    response = "ReadMetricsResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadMetricsRequest {
+    #[endpoint(query)]
+    pub format: Option<MetricsFormat>,
+}
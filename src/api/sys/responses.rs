@@ -1,6 +1,9 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{EnforcementLevel, PluginType};
 
 /// Response from executing
 /// [ListMountsRequest][crate::api::sys::requests::ListMountsRequest]
@@ -27,6 +30,44 @@ pub struct MountConfigResponse {
     pub max_lease_ttl: u64,
 }
 
+/// Response from executing
+/// [ReadMountTuneRequest][crate::api::sys::requests::ReadMountTuneRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadMountTuneResponse {
+    pub default_lease_ttl: u64,
+    pub max_lease_ttl: u64,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub force_no_cache: bool,
+    pub audit_non_hmac_request_keys: Option<Vec<String>>,
+    pub audit_non_hmac_response_keys: Option<Vec<String>>,
+    pub listing_visibility: Option<String>,
+    pub passthrough_request_headers: Option<Vec<String>>,
+    pub allowed_response_headers: Option<Vec<String>>,
+    pub options: Option<HashMap<String, String>>,
+}
+
+/// Response from executing
+/// [ListAuditsRequest][crate::api::sys::requests::ListAuditsRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct AuditDeviceResponse {
+    pub description: String,
+    #[serde(default)]
+    pub local: bool,
+    pub options: Option<HashMap<String, String>>,
+    pub path: String,
+    #[serde(rename = "type")]
+    pub device_type: String,
+}
+
+/// Response from executing
+/// [AuditHashRequest][crate::api::sys::requests::AuditHashRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct AuditHashResponse {
+    pub hash: String,
+}
+
 /// Response from executing
 /// [ListAuthsRequest][crate::api::sys::requests::ListAuthsRequest]
 #[derive(Deserialize, Debug, Serialize)]
@@ -53,6 +94,24 @@ pub struct AuthConfigResponse {
     pub token_type: String,
 }
 
+/// Response from executing
+/// [ReadAuthTuneRequest][crate::api::sys::requests::ReadAuthTuneRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadAuthTuneResponse {
+    pub default_lease_ttl: u64,
+    pub max_lease_ttl: u64,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub force_no_cache: bool,
+    pub token_type: Option<String>,
+    pub audit_non_hmac_request_keys: Option<Vec<String>>,
+    pub audit_non_hmac_response_keys: Option<Vec<String>>,
+    pub listing_visibility: Option<String>,
+    pub passthrough_request_headers: Option<Vec<String>>,
+    pub allowed_response_headers: Option<Vec<String>>,
+}
+
 /// Response from executing
 /// [WrappingLookupRequest][crate::api::sys::requests::WrappingLookupRequest]
 #[derive(Deserialize, Debug, Serialize)]
@@ -66,28 +125,126 @@ pub struct WrappingLookupResponse {
 /// [ReadHealthRequest][crate::api::sys::requests::ReadHealthRequest]
 #[derive(Deserialize, Debug, Serialize)]
 pub struct ReadHealthResponse {
+    #[serde(default)]
     pub cluster_id: String,
+    #[serde(default)]
     pub cluster_name: String,
     pub initialized: bool,
+    #[serde(default)]
     pub performance_standby: bool,
     pub replication_dr_mode: Option<String>,
+    #[serde(
+        rename = "replication_performance_mode",
+        alias = "replication_perf_mode"
+    )]
     pub replication_perf_mode: Option<String>,
     pub sealed: bool,
     pub server_time_utc: u64,
     pub standby: bool,
     pub version: String,
+    /// The fields of the response which aren't known to this crate, like those
+    /// added by newer versions of Vault
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Response from executing
+/// [ReadInitStatusRequest][crate::api::sys::requests::ReadInitStatusRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadInitStatusResponse {
+    pub initialized: bool,
+}
+
+/// Response from executing
+/// [InitRequest][crate::api::sys::requests::InitRequest]
+///
+/// The unseal keys and the root token are redacted from the [fmt::Debug]
+/// output.
+#[derive(Deserialize, Serialize)]
+pub struct InitResponse {
+    pub keys: Vec<String>,
+    pub keys_base64: Vec<String>,
+    #[serde(default)]
+    pub recovery_keys: Vec<String>,
+    #[serde(default)]
+    pub recovery_keys_base64: Vec<String>,
+    pub root_token: String,
+}
+
+impl fmt::Debug for InitResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitResponse")
+            .field("keys", &"[redacted]")
+            .field("keys_base64", &"[redacted]")
+            .field("recovery_keys", &"[redacted]")
+            .field("recovery_keys_base64", &"[redacted]")
+            .field("root_token", &"[redacted]")
+            .finish()
+    }
+}
+
+/// Response from executing
+/// [ReadLeaderRequest][crate::api::sys::requests::ReadLeaderRequest]
+///
+/// The addresses are empty if high availability is disabled or no leader has
+/// been elected yet.
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadLeaderResponse {
+    pub ha_enabled: bool,
+    pub is_self: bool,
+    #[serde(default)]
+    pub leader_address: String,
+    #[serde(default)]
+    pub leader_cluster_address: String,
+    #[serde(default)]
+    pub performance_standby: bool,
+    pub performance_standby_last_remote_wal: Option<u64>,
+    pub active_time: Option<String>,
+}
+
+/// Response from executing
+/// [ReadSealStatusRequest][crate::api::sys::requests::ReadSealStatusRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadSealStatusResponse {
+    #[serde(rename = "type")]
+    pub seal_type: String,
+    pub initialized: bool,
+    pub sealed: bool,
+    #[serde(rename = "t")]
+    pub threshold: u64,
+    #[serde(rename = "n")]
+    pub n_shares: u64,
+    pub progress: u64,
+    pub nonce: String,
+    pub version: String,
+    #[serde(default)]
+    pub migration: bool,
+    pub cluster_name: Option<String>,
+    pub cluster_id: Option<String>,
+    #[serde(default)]
+    pub recovery_seal: bool,
+    pub storage_type: Option<String>,
+    /// The fields of the response which aren't known to this crate
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 /// Response from executing
 /// [UnsealRequest][crate::api::sys::requests::UnsealRequest]
 #[derive(Deserialize, Debug, Serialize)]
 pub struct UnsealResponse {
+    #[serde(rename = "type", default)]
+    pub seal_type: String,
+    #[serde(default)]
+    pub initialized: bool,
     pub sealed: bool,
     #[serde(rename = "t")]
     pub threshold: u64,
     #[serde(rename = "n")]
     pub n_shares: u64,
     pub progress: u64,
+    #[serde(default)]
+    pub nonce: String,
     pub version: String,
     pub cluster_name: Option<String>,
     pub cluster_id: Option<String>,
@@ -95,8 +252,9 @@ pub struct UnsealResponse {
 
 /// Response from executing
 /// [ListPoliciesRequest][crate::api::sys::requests::ListPoliciesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListPoliciesResponse {
+    #[serde(alias = "keys")]
     pub policies: Vec<String>,
 }
 
@@ -105,5 +263,371 @@ pub struct ListPoliciesResponse {
 #[derive(Deserialize, Debug, Serialize)]
 pub struct ReadPolicyResponse {
     pub name: String,
+    /// The raw HCL or JSON policy document
+    #[serde(alias = "policy")]
     pub rules: String,
 }
+
+/// Response from executing
+/// [ListRGPPoliciesRequest][crate::api::sys::requests::ListRGPPoliciesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListRGPPoliciesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadRGPPolicyRequest][crate::api::sys::requests::ReadRGPPolicyRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadRGPPolicyResponse {
+    pub name: String,
+    pub policy: String,
+    pub enforcement_level: EnforcementLevel,
+}
+
+/// Response from executing
+/// [ListEGPPoliciesRequest][crate::api::sys::requests::ListEGPPoliciesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListEGPPoliciesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadEGPPolicyRequest][crate::api::sys::requests::ReadEGPPolicyRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadEGPPolicyResponse {
+    pub name: String,
+    pub policy: String,
+    pub enforcement_level: EnforcementLevel,
+    pub paths: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadLeaseRequest][crate::api::sys::requests::ReadLeaseRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadLeaseResponse {
+    pub id: String,
+    pub issue_time: String,
+    pub expire_time: Option<String>,
+    pub last_renewal: Option<String>,
+    pub renewable: bool,
+    pub ttl: u64,
+}
+
+/// Response from executing
+/// [ListLeasesRequest][crate::api::sys::requests::ListLeasesRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ListLeasesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [RenewLeaseRequest][crate::api::sys::requests::RenewLeaseRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct RenewLeaseResponse {
+    pub lease_id: String,
+    pub lease_duration: u64,
+    pub renewable: bool,
+}
+
+/// Response from executing
+/// [CapabilitiesSelfRequest][crate::api::sys::requests::CapabilitiesSelfRequest]
+/// or [CapabilitiesRequest][crate::api::sys::requests::CapabilitiesRequest]
+///
+/// Maps each of the requested paths to its capabilities. Vault also returns
+/// the capabilities of a single requested path under the `capabilities` key.
+#[derive(Deserialize, Debug, Serialize)]
+pub struct CapabilitiesResponse {
+    #[serde(flatten)]
+    pub capabilities: HashMap<String, Vec<String>>,
+}
+
+/// Response from executing
+/// [ReadRaftConfigurationRequest][crate::api::sys::requests::ReadRaftConfigurationRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadRaftConfigurationResponse {
+    pub config: RaftConfigurationResponse,
+}
+
+/// The configuration of a Raft cluster
+#[derive(Deserialize, Debug, Serialize)]
+pub struct RaftConfigurationResponse {
+    pub index: u64,
+    pub servers: Vec<RaftServerResponse>,
+}
+
+/// A peer of a Raft cluster
+#[derive(Deserialize, Debug, Serialize)]
+pub struct RaftServerResponse {
+    pub address: String,
+    pub leader: bool,
+    pub node_id: String,
+    pub protocol_version: String,
+    pub voter: bool,
+}
+
+/// Response from executing
+/// [ReadRaftAutopilotStateRequest][crate::api::sys::requests::ReadRaftAutopilotStateRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadRaftAutopilotStateResponse {
+    pub healthy: bool,
+    pub failure_tolerance: u64,
+    pub leader: String,
+    pub voters: Vec<String>,
+    #[serde(default)]
+    pub non_voters: Vec<String>,
+    pub servers: HashMap<String, RaftAutopilotServerResponse>,
+}
+
+/// The state of a server of a Raft cluster as seen by autopilot
+#[derive(Deserialize, Debug, Serialize)]
+pub struct RaftAutopilotServerResponse {
+    pub id: String,
+    pub name: String,
+    pub address: String,
+    pub node_status: String,
+    pub last_contact: String,
+    pub last_term: u64,
+    pub last_index: u64,
+    pub healthy: bool,
+    pub stable_since: String,
+    pub status: String,
+    pub meta: Option<HashMap<String, String>>,
+    pub version: Option<String>,
+}
+
+/// Response from executing
+/// [ReadRaftAutopilotConfigurationRequest][crate::api::sys::requests::ReadRaftAutopilotConfigurationRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadRaftAutopilotConfigurationResponse {
+    pub cleanup_dead_servers: bool,
+    pub last_contact_threshold: String,
+    pub dead_server_last_contact_threshold: String,
+    pub max_trailing_logs: u64,
+    pub min_quorum: u64,
+    pub server_stabilization_time: String,
+    #[serde(default)]
+    pub disable_upgrade_migration: bool,
+}
+
+/// Response from executing
+/// [ListPluginsRequest][crate::api::sys::requests::ListPluginsRequest]
+///
+/// The names of the plugins are listed by type, while `detailed` contains the
+/// details of all plugins.
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ListPluginsResponse {
+    #[serde(default)]
+    pub auth: Vec<String>,
+    #[serde(default)]
+    pub database: Vec<String>,
+    #[serde(default)]
+    pub secret: Vec<String>,
+    #[serde(default)]
+    pub detailed: Vec<PluginResponse>,
+}
+
+/// A plugin in the catalog as listed by
+/// [ListPluginsRequest][crate::api::sys::requests::ListPluginsRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct PluginResponse {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub plugin_type: PluginType,
+    pub builtin: bool,
+    #[serde(default)]
+    pub version: String,
+    pub deprecation_status: Option<String>,
+}
+
+/// Response from executing
+/// [ReadPluginRequest][crate::api::sys::requests::ReadPluginRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadPluginResponse {
+    pub name: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    pub builtin: bool,
+    pub command: String,
+    pub sha256: String,
+    #[serde(default)]
+    pub version: String,
+    pub deprecation_status: Option<String>,
+}
+
+/// Response from executing
+/// [ReadGenerateRootStatusRequest][crate::api::sys::requests::ReadGenerateRootStatusRequest],
+/// [StartGenerateRootRequest][crate::api::sys::requests::StartGenerateRootRequest]
+/// or [UpdateGenerateRootRequest][crate::api::sys::requests::UpdateGenerateRootRequest]
+///
+/// The `otp` is only returned when starting an attempt, and the
+/// `encoded_token` only once the attempt is `complete`. Both are redacted from
+/// the [fmt::Debug] output.
+#[derive(Deserialize, Serialize)]
+pub struct GenerateRootStatusResponse {
+    pub started: bool,
+    #[serde(default)]
+    pub nonce: String,
+    #[serde(default)]
+    pub progress: u64,
+    #[serde(default)]
+    pub required: u64,
+    #[serde(default)]
+    pub complete: bool,
+    #[serde(default)]
+    pub encoded_token: String,
+    #[serde(default)]
+    pub encoded_root_token: String,
+    #[serde(default)]
+    pub pgp_fingerprint: String,
+    #[serde(default)]
+    pub otp: String,
+    #[serde(default)]
+    pub otp_length: u64,
+}
+
+impl fmt::Debug for GenerateRootStatusResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateRootStatusResponse")
+            .field("started", &self.started)
+            .field("nonce", &self.nonce)
+            .field("progress", &self.progress)
+            .field("required", &self.required)
+            .field("complete", &self.complete)
+            .field("encoded_token", &"[redacted]")
+            .field("encoded_root_token", &"[redacted]")
+            .field("pgp_fingerprint", &self.pgp_fingerprint)
+            .field("otp", &"[redacted]")
+            .field("otp_length", &self.otp_length)
+            .finish()
+    }
+}
+
+/// Response from executing
+/// [ReadRekeyStatusRequest][crate::api::sys::requests::ReadRekeyStatusRequest]
+/// or [StartRekeyRequest][crate::api::sys::requests::StartRekeyRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct RekeyStatusResponse {
+    pub started: bool,
+    #[serde(default)]
+    pub nonce: String,
+    #[serde(rename = "t", default)]
+    pub threshold: u64,
+    #[serde(rename = "n", default)]
+    pub n_shares: u64,
+    #[serde(default)]
+    pub progress: u64,
+    #[serde(default)]
+    pub required: u64,
+    pub pgp_fingerprints: Option<Vec<String>>,
+    #[serde(default)]
+    pub backup: bool,
+    #[serde(default)]
+    pub verification_required: bool,
+}
+
+/// Response from executing
+/// [UpdateRekeyRequest][crate::api::sys::requests::UpdateRekeyRequest]
+///
+/// The new key shares are only returned once the rekey is `complete`. If the
+/// rekey requires verification, they only take effect once verified with the
+/// `verification_nonce`. The keys are redacted from the [fmt::Debug] output.
+#[derive(Deserialize, Serialize)]
+pub struct RekeyUpdateResponse {
+    #[serde(default)]
+    pub nonce: String,
+    #[serde(default)]
+    pub started: bool,
+    #[serde(default)]
+    pub progress: u64,
+    #[serde(default)]
+    pub required: u64,
+    #[serde(default)]
+    pub complete: bool,
+    #[serde(default)]
+    pub keys: Vec<String>,
+    #[serde(default)]
+    pub keys_base64: Vec<String>,
+    pub pgp_fingerprints: Option<Vec<String>>,
+    #[serde(default)]
+    pub backup: bool,
+    #[serde(default)]
+    pub verification_required: bool,
+    pub verification_nonce: Option<String>,
+}
+
+impl fmt::Debug for RekeyUpdateResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RekeyUpdateResponse")
+            .field("nonce", &self.nonce)
+            .field("started", &self.started)
+            .field("progress", &self.progress)
+            .field("required", &self.required)
+            .field("complete", &self.complete)
+            .field("keys", &"[redacted]")
+            .field("keys_base64", &"[redacted]")
+            .field("pgp_fingerprints", &self.pgp_fingerprints)
+            .field("backup", &self.backup)
+            .field("verification_required", &self.verification_required)
+            .field("verification_nonce", &self.verification_nonce)
+            .finish()
+    }
+}
+
+/// Response from executing
+/// [VerifyRekeyRequest][crate::api::sys::requests::VerifyRekeyRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct RekeyVerifyResponse {
+    pub nonce: String,
+    #[serde(default)]
+    pub started: bool,
+    #[serde(rename = "t", default)]
+    pub threshold: u64,
+    #[serde(rename = "n", default)]
+    pub n_shares: u64,
+    #[serde(default)]
+    pub progress: u64,
+    #[serde(default)]
+    pub complete: bool,
+}
+
+/// Response from executing
+/// [ReadMetricsRequest][crate::api::sys::requests::ReadMetricsRequest] in
+/// the JSON format
+#[derive(Deserialize, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReadMetricsResponse {
+    pub timestamp: String,
+    #[serde(default)]
+    pub gauges: Vec<GaugeMetricResponse>,
+    #[serde(default)]
+    pub counters: Vec<SampledMetricResponse>,
+    #[serde(default)]
+    pub samples: Vec<SampledMetricResponse>,
+}
+
+/// A gauge of [ReadMetricsResponse]
+#[derive(Deserialize, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GaugeMetricResponse {
+    pub name: String,
+    pub value: f64,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
+/// A counter or sample of [ReadMetricsResponse], aggregated over the current
+/// interval
+#[derive(Deserialize, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SampledMetricResponse {
+    pub name: String,
+    pub count: u64,
+    pub rate: f64,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub stddev: f64,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
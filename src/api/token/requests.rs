@@ -3,7 +3,10 @@ use super::responses::{
 };
 use rustify_derive::Endpoint;
 use serde::Serialize;
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+};
 
 /// ## List Accessors
 /// This endpoint lists token accessors.
@@ -120,7 +123,7 @@ pub struct CreateRoleTokenRequest {
 /// * Method: POST
 /// * Response: [LookupTokenResponse]
 /// * Reference: https://www.vaultproject.io/api-docs/auth/token#lookup-a-token
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(
     path = "/auth/token/lookup",
     method = "POST",
@@ -132,6 +135,14 @@ pub struct LookupTokenRequest {
     pub token: String,
 }
 
+impl fmt::Debug for LookupTokenRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LookupTokenRequest")
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Lookup a Token (Self)
 /// Returns information about the current client token.
 ///
@@ -174,7 +185,7 @@ pub struct LookupTokenAccessorRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/token#renew-a-token
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/token/renew", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct RenewTokenRequest {
@@ -182,6 +193,15 @@ pub struct RenewTokenRequest {
     pub increment: Option<String>,
 }
 
+impl fmt::Debug for RenewTokenRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenewTokenRequest")
+            .field("token", &"[redacted]")
+            .field("increment", &self.increment)
+            .finish()
+    }
+}
+
 /// ## Renew a Token (Self)
 /// Renews a lease associated with the calling token.
 ///
@@ -218,13 +238,21 @@ pub struct RenewTokenAccessorRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/token#revoke-a-token
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/token/revoke", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct RevokeTokenRequest {
     pub token: String,
 }
 
+impl fmt::Debug for RevokeTokenRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevokeTokenRequest")
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Revoke a Token (Self)
 /// Revokes the token used to call it and all child tokens.
 ///
@@ -262,13 +290,21 @@ pub struct RevokeTokenAccessorRequest {
 /// * Method: POST
 /// * Response: N/A
 /// * Reference: https://www.vaultproject.io/api-docs/auth/token#revoke-token-and-orphan-children
-#[derive(Builder, Debug, Default, Endpoint)]
+#[derive(Builder, Default, Endpoint)]
 #[endpoint(path = "/auth/token/revoke-orphan", method = "POST", builder = "true")]
 #[builder(setter(into, strip_option), default)]
 pub struct RevokeTokenOrphanRequest {
     pub token: String,
 }
 
+impl fmt::Debug for RevokeTokenOrphanRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevokeTokenOrphanRequest")
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
 /// ## Read Token Role
 /// Fetches the named role configuration.
 ///
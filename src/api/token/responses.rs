@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Response from executing
 /// [ListAccessorRequest][crate::api::token::requests::ListAccessorRequest]
@@ -11,7 +12,7 @@ pub struct ListAccessorResponse {
 
 /// Response from executing
 /// [LookupTokenRequest][crate::api::token::requests::LookupTokenRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct LookupTokenResponse {
     pub accessor: String,
     pub creation_time: u64,
@@ -30,6 +31,37 @@ pub struct LookupTokenResponse {
     pub policies: Vec<String>,
     pub renewable: bool,
     pub ttl: u64,
+    /// The fields of the response which aren't known to this crate
+    ///
+    /// Only their names are included in the [fmt::Debug] output, as they may
+    /// hold sensitive values.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl fmt::Debug for LookupTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LookupTokenResponse")
+            .field("accessor", &self.accessor)
+            .field("creation_time", &self.creation_time)
+            .field("creation_ttl", &self.creation_ttl)
+            .field("display_name", &self.display_name)
+            .field("entity_id", &self.entity_id)
+            .field("expire_time", &self.expire_time)
+            .field("explicit_max_ttl", &self.explicit_max_ttl)
+            .field("id", &"[redacted]")
+            .field("identity_policies", &self.identity_policies)
+            .field("issue_time", &self.issue_time)
+            .field("meta", &self.meta)
+            .field("num_uses", &self.num_uses)
+            .field("orphan", &self.orphan)
+            .field("path", &self.path)
+            .field("policies", &self.policies)
+            .field("renewable", &self.renewable)
+            .field("ttl", &self.ttl)
+            .field("extra", &self.extra.keys().collect::<Vec<_>>())
+            .finish()
+    }
 }
 
 /// Response from executing
@@ -45,14 +77,19 @@ pub struct ReadTokenRoleResponse {
     pub path_suffix: String,
     pub period: u64,
     pub renewable: bool,
+    pub token_bound_cidrs: Option<Vec<String>>,
     pub token_explicit_max_ttl: u64,
+    #[serde(default)]
+    pub token_no_default_policy: bool,
+    #[serde(default)]
+    pub token_num_uses: u64,
     pub token_period: u64,
     pub token_type: String,
 }
 
 /// Response from executing
 /// [ListTokenRolesRequest][crate::api::token::requests::ListTokenRolesRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListTokenRolesResponse {
     pub keys: Vec<String>,
 }
@@ -0,0 +1,16 @@
+pub mod requests;
+pub mod responses;
+
+use serde::{Deserialize, Serialize};
+
+/// The hashing algorithm used to generate TOTP codes
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub enum TotpAlgorithm {
+    #[default]
+    #[serde(rename = "SHA1")]
+    Sha1,
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA512")]
+    Sha512,
+}
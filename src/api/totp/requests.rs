@@ -0,0 +1,214 @@
+use std::fmt;
+
+use super::responses::{
+    GenerateCodeResponse, GenerateKeyResponse, ListKeysResponse, ReadKeyResponse,
+    ValidateCodeResponse,
+};
+use super::TotpAlgorithm;
+use rustify_derive::Endpoint;
+
+/// ## Create Key
+/// This endpoint imports an existing key, for example one provided by a third
+/// party service. Either the `url` or the `key` must be set.
+///
+/// * Path: {self.mount}/keys/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/totp#create-key
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/keys/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct CreateKeyRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// The TOTP key url string, as usually encoded in a QR code.
+    pub url: Option<String>,
+    /// The base32 encoded shared master key.
+    pub key: Option<String>,
+    pub issuer: Option<String>,
+    pub account_name: Option<String>,
+    pub algorithm: Option<TotpAlgorithm>,
+    /// The number of digits in a generated code, either 6 or 8.
+    pub digits: Option<u64>,
+    /// The length of time a generated code is valid for.
+    pub period: Option<String>,
+}
+
+impl fmt::Debug for CreateKeyRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateKeyRequest")
+            .field("mount", &self.mount)
+            .field("name", &self.name)
+            .field("url", &self.url.as_ref().map(|_| "[redacted]"))
+            .field("key", &self.key.as_ref().map(|_| "[redacted]"))
+            .field("issuer", &self.issuer)
+            .field("account_name", &self.account_name)
+            .field("algorithm", &self.algorithm)
+            .field("digits", &self.digits)
+            .field("period", &self.period)
+            .finish()
+    }
+}
+
+/// ## Generate Key
+/// This endpoint has Vault generate a new key and return the URL and QR code
+/// which are used to enroll it into an authenticator app.
+///
+/// * Path: {self.mount}/keys/{self.name}
+/// * Method: POST
+/// * Response: [GenerateKeyResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/totp#create-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/keys/{self.name}",
+    method = "POST",
+    response = "GenerateKeyResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct GenerateKeyRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    pub generate: bool,
+    pub issuer: String,
+    pub account_name: String,
+    /// The size in bytes of the generated key.
+    pub key_size: Option<u64>,
+    pub algorithm: Option<TotpAlgorithm>,
+    /// The number of digits in a generated code, either 6 or 8.
+    pub digits: Option<u64>,
+    /// The length of time a generated code is valid for.
+    pub period: Option<String>,
+    /// The number of periods before and after the current one in which a code
+    /// is still accepted, either 0 or 1.
+    pub skew: Option<u64>,
+    /// The pixel size of the generated square QR code. Set to 0 to disable the
+    /// QR code.
+    pub qr_size: Option<u64>,
+}
+
+/// ## Read Key
+/// This endpoint queries the key definition.
+///
+/// * Path: {self.mount}/keys/{self.name}
+/// * Method: GET
+/// * Response: [ReadKeyResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/totp#read-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/keys/{self.name}",
+    response = "ReadKeyResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ReadKeyRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Keys
+/// This endpoint returns a list of available keys.
+///
+/// * Path: {self.mount}/keys
+/// * Method: LIST
+/// * Response: [ListKeysResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/totp#list-keys
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/keys",
+    method = "LIST",
+    response = "ListKeysResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ListKeysRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Delete Key
+/// This endpoint deletes the key definition.
+///
+/// * Path: {self.mount}/keys/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/totp#delete-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/keys/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DeleteKeyRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Generate Code
+/// This endpoint generates a new time-based one-time use password based on
+/// the named key.
+///
+/// * Path: {self.mount}/code/{self.name}
+/// * Method: GET
+/// * Response: [GenerateCodeResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/totp#generate-code
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/code/{self.name}",
+    response = "GenerateCodeResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct GenerateCodeRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Validate Code
+/// This endpoint validates a time-based one-time use password generated from
+/// the named key. A code is only accepted once.
+///
+/// * Path: {self.mount}/code/{self.name}
+/// * Method: POST
+/// * Response: [ValidateCodeResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/totp#validate-code
+#[derive(Builder, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/code/{self.name}",
+    method = "POST",
+    response = "ValidateCodeResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ValidateCodeRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    pub code: String,
+}
+
+impl fmt::Debug for ValidateCodeRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidateCodeRequest")
+            .field("mount", &self.mount)
+            .field("name", &self.name)
+            .field("code", &"[redacted]")
+            .finish()
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::TotpAlgorithm;
+
+/// Response from executing
+/// [GenerateKeyRequest][crate::api::totp::requests::GenerateKeyRequest]
+///
+/// The `barcode` is a base64 encoded PNG of the QR code and is missing when
+/// the QR code was disabled.
+#[derive(Deserialize, Serialize)]
+pub struct GenerateKeyResponse {
+    pub barcode: Option<String>,
+    pub url: Option<String>,
+}
+
+impl fmt::Debug for GenerateKeyResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateKeyResponse")
+            .field("barcode", &self.barcode.as_ref().map(|_| "[redacted]"))
+            .field("url", &self.url.as_ref().map(|_| "[redacted]"))
+            .finish()
+    }
+}
+
+/// Response from executing
+/// [ReadKeyRequest][crate::api::totp::requests::ReadKeyRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadKeyResponse {
+    pub account_name: String,
+    pub algorithm: TotpAlgorithm,
+    pub digits: u64,
+    pub issuer: String,
+    pub period: u64,
+}
+
+/// Response from executing
+/// [ListKeysRequest][crate::api::totp::requests::ListKeysRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListKeysResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [GenerateCodeRequest][crate::api::totp::requests::GenerateCodeRequest]
+#[derive(Deserialize, Serialize)]
+pub struct GenerateCodeResponse {
+    pub code: String,
+}
+
+impl fmt::Debug for GenerateCodeResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GenerateCodeResponse")
+            .field("code", &"[redacted]")
+            .finish()
+    }
+}
+
+/// Response from executing
+/// [ValidateCodeRequest][crate::api::totp::requests::ValidateCodeRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ValidateCodeResponse {
+    pub valid: bool,
+}
@@ -0,0 +1,31 @@
+pub mod requests;
+pub mod responses;
+
+use serde::{Deserialize, Serialize};
+
+/// The type of a transformation
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransformationType {
+    /// Format preserving encryption, which encrypts a value into one of the
+    /// same format and can be decoded
+    Fpe,
+    /// Replaces the characters matched by the template with a masking
+    /// character and can't be decoded
+    Masking,
+    /// Replaces the value with a random token which can be decoded
+    Tokenization,
+}
+
+/// The source of the tweak used by format preserving encryption
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TweakSource {
+    /// The tweak is supplied with every encode and decode request
+    Supplied,
+    /// A tweak is generated and returned when encoding and has to be supplied
+    /// when decoding
+    Generated,
+    /// A tweak is generated once and stored by Vault
+    Internal,
+}
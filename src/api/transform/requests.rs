@@ -0,0 +1,327 @@
+use super::responses::{
+    DecodeBatchResponse, DecodeResponse, EncodeBatchResponse, EncodeResponse, ListRolesResponse,
+    ListTransformationsResponse, ReadRoleResponse, ReadTransformationResponse,
+};
+use super::{TransformationType, TweakSource};
+use rustify_derive::Endpoint;
+use serde::Serialize;
+
+/// ## Create/Update Role
+/// This endpoint creates or updates the role with the given name. If a role
+/// with the name does not exist, it will be created. If the role exists, it
+/// will be updated with the new attributes.
+///
+/// * Path: {self.mount}/role/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#create-update-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/role/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct SetRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// Specifies the transformations that can be used with this role.
+    pub transformations: Vec<String>,
+}
+
+/// ## Read Role
+/// This endpoint queries an existing role by the given name.
+///
+/// * Path: {self.mount}/role/{self.name}
+/// * Method: GET
+/// * Response: ReadRoleResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#read-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/role/{self.name}",
+    response = "ReadRoleResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Roles
+/// This endpoint lists all existing roles in the secrets engine.
+///
+/// * Path: {self.mount}/role
+/// * Method: LIST
+/// * Response: ListRolesResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#list-roles
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/role",
+    response = "ListRolesResponse",
+    method = "LIST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListRolesRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Delete Role
+/// This endpoint deletes an existing role by the given name.
+///
+/// * Path: {self.mount}/role/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#delete-role
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/role/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeleteRoleRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Create/Update Transformation
+/// This endpoint creates or updates a transformation with the given name. The
+/// `template` is required for `fpe` and `masking` transformations.
+///
+/// * Path: {self.mount}/transformation/{self.name}
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#create-update-transformation-deprecated-1-6
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(
+    path = "{self.mount}/transformation/{self.name}",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct SetTransformationRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// Specifies the type of the transformation.
+    #[serde(rename = "type")]
+    pub transformation_type: Option<TransformationType>,
+    /// Specifies the template name to use for matching the value.
+    pub template: Option<String>,
+    /// Specifies the source of the tweak value for `fpe` transformations.
+    pub tweak_source: Option<TweakSource>,
+    /// Specifies the character used to mask values of `masking`
+    /// transformations.
+    pub masking_character: Option<String>,
+    /// Specifies a list of allowed roles that this transformation can be
+    /// assigned to.
+    pub allowed_roles: Option<Vec<String>>,
+}
+
+/// ## Read Transformation
+/// This endpoint queries an existing transformation by the given name.
+///
+/// * Path: {self.mount}/transformation/{self.name}
+/// * Method: GET
+/// * Response: ReadTransformationResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#read-transformation-deprecated-1-6
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/transformation/{self.name}",
+    response = "ReadTransformationResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadTransformationRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## List Transformations
+/// This endpoint lists all existing transformations in the secrets engine.
+///
+/// * Path: {self.mount}/transformation
+/// * Method: LIST
+/// * Response: ListTransformationsResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#list-transformations-deprecated-1-6
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/transformation",
+    response = "ListTransformationsResponse",
+    method = "LIST",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ListTransformationsRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Delete Transformation
+/// This endpoint deletes an existing transformation by the given name.
+///
+/// * Path: {self.mount}/transformation/{self.name}
+/// * Method: DELETE
+/// * Response: N/A
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#delete-transformation-deprecated-1-6
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/transformation/{self.name}",
+    method = "DELETE",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct DeleteTransformationRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+}
+
+/// ## Encode
+/// This endpoint encodes the provided value using a named role.
+///
+/// * Path: {self.mount}/encode/{self.role}
+/// * Method: POST
+/// * Response: EncodeResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#encode
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/encode/{self.role}",
+    method = "POST",
+    response = "EncodeResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct EncodeRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the role name to use for this operation.
+    #[endpoint(skip)]
+    pub role: String,
+    /// Specifies the value to be encoded.
+    pub value: String,
+    /// Specifies the transformation within the role that should be used for
+    /// this encode operation. Required if the role has more than one
+    /// transformation.
+    pub transformation: Option<String>,
+    /// Specifies the base64 encoded tweak to use, which is required when the
+    /// tweak source of the transformation is `supplied`.
+    pub tweak: Option<String>,
+    /// A user-supplied string that will be present in the response.
+    pub reference: Option<String>,
+}
+
+/// ## Encode (Batch)
+/// This endpoint encodes a batch of values using a named role in a single
+/// request. The results are returned in the same order as the items.
+///
+/// * Path: {self.mount}/encode/{self.role}
+/// * Method: POST
+/// * Response: EncodeBatchResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#encode
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/encode/{self.role}",
+    method = "POST",
+    response = "EncodeBatchResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct EncodeBatchRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the role name to use for this operation.
+    #[endpoint(skip)]
+    pub role: String,
+    /// Specifies the items to be encoded.
+    pub batch_input: Vec<TransformBatchItem>,
+}
+
+/// ## Decode
+/// This endpoint decodes the provided value using a named role.
+///
+/// * Path: {self.mount}/decode/{self.role}
+/// * Method: POST
+/// * Response: DecodeResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#decode
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/decode/{self.role}",
+    method = "POST",
+    response = "DecodeResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DecodeRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the role name to use for this operation.
+    #[endpoint(skip)]
+    pub role: String,
+    /// Specifies the value to be decoded.
+    pub value: String,
+    /// Specifies the transformation within the role that should be used for
+    /// this decode operation. Required if the role has more than one
+    /// transformation.
+    pub transformation: Option<String>,
+    /// Specifies the base64 encoded tweak which was used when encoding.
+    pub tweak: Option<String>,
+    /// A user-supplied string that will be present in the response.
+    pub reference: Option<String>,
+}
+
+/// ## Decode (Batch)
+/// This endpoint decodes a batch of values using a named role in a single
+/// request. The results are returned in the same order as the items.
+///
+/// * Path: {self.mount}/decode/{self.role}
+/// * Method: POST
+/// * Response: DecodeBatchResponse
+/// * Reference: https://developer.hashicorp.com/vault/api-docs/secret/transform#decode
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/decode/{self.role}",
+    method = "POST",
+    response = "DecodeBatchResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DecodeBatchRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the role name to use for this operation.
+    #[endpoint(skip)]
+    pub role: String,
+    /// Specifies the items to be decoded.
+    pub batch_input: Vec<TransformBatchItem>,
+}
+
+/// A single item of an [EncodeBatchRequest] or [DecodeBatchRequest].
+#[derive(Builder, Clone, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), default)]
+pub struct TransformBatchItem {
+    /// Specifies the value to be encoded or decoded.
+    pub value: String,
+    /// Specifies the transformation within the role that should be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transformation: Option<String>,
+    /// Specifies the base64 encoded tweak to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tweak: Option<String>,
+    /// A user-supplied string that will be present in the result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
@@ -0,0 +1,71 @@
+use super::{TransformationType, TweakSource};
+use serde::{Deserialize, Serialize};
+
+/// Response from executing
+/// [ReadRoleRequest][crate::api::transform::requests::ReadRoleRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadRoleResponse {
+    pub transformations: Vec<String>,
+}
+
+/// Response from executing
+/// [ListRolesRequest][crate::api::transform::requests::ListRolesRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListRolesResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [ReadTransformationRequest][crate::api::transform::requests::ReadTransformationRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ReadTransformationResponse {
+    #[serde(rename = "type")]
+    pub transformation_type: TransformationType,
+    #[serde(default)]
+    pub templates: Vec<String>,
+    pub tweak_source: Option<TweakSource>,
+    pub masking_character: Option<String>,
+    #[serde(default)]
+    pub allowed_roles: Vec<String>,
+}
+
+/// Response from executing
+/// [ListTransformationsRequest][crate::api::transform::requests::ListTransformationsRequest]
+#[derive(Deserialize, Debug, Default, Serialize)]
+pub struct ListTransformationsResponse {
+    pub keys: Vec<String>,
+}
+
+/// Response from executing
+/// [EncodeRequest][crate::api::transform::requests::EncodeRequest]
+///
+/// The `tweak` is only returned by transformations with a `generated` tweak
+/// source and has to be supplied when decoding.
+#[derive(Deserialize, Debug, Serialize)]
+pub struct EncodeResponse {
+    pub encoded_value: String,
+    pub tweak: Option<String>,
+    pub reference: Option<String>,
+}
+
+/// Response from executing
+/// [EncodeBatchRequest][crate::api::transform::requests::EncodeBatchRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct EncodeBatchResponse {
+    pub batch_results: Vec<EncodeResponse>,
+}
+
+/// Response from executing
+/// [DecodeRequest][crate::api::transform::requests::DecodeRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct DecodeResponse {
+    pub decoded_value: String,
+    pub reference: Option<String>,
+}
+
+/// Response from executing
+/// [DecodeBatchRequest][crate::api::transform::requests::DecodeBatchRequest]
+#[derive(Deserialize, Debug, Serialize)]
+pub struct DecodeBatchResponse {
+    pub batch_results: Vec<DecodeResponse>,
+}
@@ -3,7 +3,7 @@ pub mod responses;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum KeyType {
     /// AES-128 wrapped with GCM using a 96-bit nonce size AEAD (symmetric,
@@ -11,6 +11,7 @@ pub enum KeyType {
     Aes128Gcm96,
     /// AES-256 wrapped with GCM using a 96-bit nonce size AEAD (symmetric,
     /// supports derivation and convergent encryption, default)
+    #[default]
     Aes256Gcm96,
     /// ChaCha20-Poly1305 AEAD (symmetric, supports derivation and convergent
     /// encryption)
@@ -33,25 +34,14 @@ pub enum KeyType {
     Rsa4096,
 }
 
-impl Default for KeyType {
-    fn default() -> Self {
-        Self::Aes256Gcm96
-    }
-}
-
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum OutputFormat {
+    #[default]
     Base64,
     Hex,
 }
 
-impl Default for OutputFormat {
-    fn default() -> Self {
-        Self::Base64
-    }
-}
-
 /// Note: In FIPS 140-2 mode, the following algorithms are not certified and
 /// thus should not be used: sha3-224, sha3-256, sha3-384, and sha3-512.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -67,6 +57,19 @@ pub enum HashAlgorithm {
     Sha3_512,
 }
 
+/// The hash function used for wrapping keys imported with
+/// [import][crate::transit::key::import].
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ImportHashFunction {
+    Sha1,
+    Sha224,
+    #[default]
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum SignatureAlgorithm {
@@ -1,10 +1,14 @@
 use super::responses::{
     BackupKeyResponse, DecryptDataResponse, EncryptDataResponse, ExportKeyResponse,
     GenerateDataKeyResponse, GenerateHmacResponse, GenerateRandomBytesResponse, HashDataResponse,
-    ListKeysResponse, ReadKeyResponse, ReadTransitCacheConfigurationResponse, RewrapDataResponse,
-    SignDataResponse, VerifySignedDataResponse,
+    ListKeysResponse, ReadKeyResponse, ReadTransitCacheConfigurationResponse,
+    ReadWrappingKeyResponse, RewrapDataResponse, SignDataResponse, TransitBatchResponse,
+    VerifySignedDataResponse,
+};
+use super::{
+    HashAlgorithm, ImportHashFunction, KeyType, MarshalingAlgorithm, OutputFormat,
+    SignatureAlgorithm,
 };
-use super::{HashAlgorithm, KeyType, MarshalingAlgorithm, OutputFormat, SignatureAlgorithm};
 use rustify_derive::Endpoint;
 use serde::Serialize;
 use std::fmt::Debug;
@@ -226,19 +230,14 @@ pub struct ExportKeyRequest {
     pub version: ExportVersion,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub enum ExportKeyType {
+    #[default]
     EncryptionKey,
     SigningKey,
     HmacKey,
 }
 
-impl Default for ExportKeyType {
-    fn default() -> Self {
-        ExportKeyType::EncryptionKey
-    }
-}
-
 impl std::fmt::Display for ExportKeyType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -249,19 +248,14 @@ impl std::fmt::Display for ExportKeyType {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub enum ExportVersion {
     All,
+    #[default]
     Latest,
     Version(u64),
 }
 
-impl Default for ExportVersion {
-    fn default() -> Self {
-        ExportVersion::Latest
-    }
-}
-
 impl std::fmt::Display for ExportVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -335,6 +329,57 @@ pub struct EncryptDataRequest {
     pub convergent_encryption: Option<String>,
 }
 
+/// ## Encrypt Data (Batch)
+/// This endpoint encrypts a batch of base64 encoded plaintexts using the named
+/// key in a single request. The results are returned in the same order as the
+/// items and each item succeeds or fails on its own.
+///
+/// * Path: {self.mount}/encrypt/{self.name}
+/// * Method: POST
+/// * Response: TransitBatchResponse
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#encrypt-data
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/encrypt/{self.name}",
+    method = "POST",
+    response = "TransitBatchResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct EncryptDataBatchRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the name of the encryption key to encrypt against.
+    #[endpoint(skip)]
+    pub name: String,
+    /// Specifies the items to be encrypted.
+    pub batch_input: Vec<EncryptBatchItem>,
+    /// Specifies the version of the key to use for encryption. If not set, uses
+    /// the latest version. Must be greater than or equal to the key's
+    /// min_encryption_version, if set.
+    pub key_version: Option<u64>,
+    /// This parameter is required when encryption key is expected to be
+    /// created. When performing an upsert operation, the type of key to create.
+    pub key_type: Option<KeyType>,
+    /// This parameter will only be used when a key is expected to be created.
+    /// Whether to support convergent encryption.
+    pub convergent_encryption: Option<String>,
+}
+
+/// A single item of an [EncryptDataBatchRequest].
+#[derive(Builder, Clone, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), default)]
+pub struct EncryptBatchItem {
+    /// Specifies base64 encoded plaintext to be encoded.
+    pub plaintext: String,
+    /// Specifies the base64 encoded context for key derivation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// Specifies the base64 encoded nonce value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+}
+
 /// ## Decrypt Data
 /// This endpoint decrypts the provided ciphertext using the named key.
 ///
@@ -367,6 +412,47 @@ pub struct DecryptDataRequest {
     pub nonce: Option<String>,
 }
 
+/// ## Decrypt Data (Batch)
+/// This endpoint decrypts a batch of ciphertexts using the named key in a
+/// single request. The results are returned in the same order as the items and
+/// each item succeeds or fails on its own.
+///
+/// * Path: {self.mount}/decrypt/{self.name}
+/// * Method: POST
+/// * Response: TransitBatchResponse
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#decrypt-data
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/decrypt/{self.name}",
+    method = "POST",
+    response = "TransitBatchResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct DecryptDataBatchRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the name of the encryption key to decrypt against.
+    #[endpoint(skip)]
+    pub name: String,
+    /// Specifies the items to be decrypted.
+    pub batch_input: Vec<DecryptBatchItem>,
+}
+
+/// A single item of a [DecryptDataBatchRequest].
+#[derive(Builder, Clone, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), default)]
+pub struct DecryptBatchItem {
+    /// Specifies the ciphertext to decrypt.
+    pub ciphertext: String,
+    /// Specifies the base64 encoded context for key derivation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// Specifies a base64 encoded nonce value used during encryption.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+}
+
 /// ## Rewrap Data
 /// This endpoint rewraps the provided ciphertext using the latest version of
 /// the named key. Because this never returns plaintext, it is possible to
@@ -405,6 +491,51 @@ pub struct RewrapDataRequest {
     pub nonce: Option<String>,
 }
 
+/// ## Rewrap Data (Batch)
+/// This endpoint rewraps a batch of ciphertexts using the latest version of the
+/// named key in a single request. The results are returned in the same order
+/// as the items and each item succeeds or fails on its own.
+///
+/// * Path: {self.mount}/rewrap/{self.name}
+/// * Method: POST
+/// * Response: TransitBatchResponse
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#rewrap-data
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/rewrap/{self.name}",
+    method = "POST",
+    response = "TransitBatchResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct RewrapDataBatchRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the name of the encryption key to re-encrypt against.
+    #[endpoint(skip)]
+    pub name: String,
+    /// Specifies the items to be re-encrypted.
+    pub batch_input: Vec<RewrapBatchItem>,
+    /// Specifies the version of the key to use for the operation. If not set,
+    /// uses the latest version. Must be greater than or equal to the key's
+    /// min_encryption_version, if set.
+    pub key_version: Option<u64>,
+}
+
+/// A single item of a [RewrapDataBatchRequest].
+#[derive(Builder, Clone, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), default)]
+pub struct RewrapBatchItem {
+    /// Specifies the ciphertext to re-encrypt.
+    pub ciphertext: String,
+    /// Specifies the base64 encoded context for key derivation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// Specifies a base64 encoded nonce value used during encryption.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+}
+
 /// ## Generate Data Key
 /// This endpoint generates a new high-entropy key and the value encrypted with
 /// the named key. Optionally return the plaintext of the key as well. Whether
@@ -451,18 +582,13 @@ pub struct GenerateDataKeyRequest {
     pub bits: Option<u16>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub enum DataKeyType {
     Plaintext,
+    #[default]
     Wrapped,
 }
 
-impl Default for DataKeyType {
-    fn default() -> Self {
-        DataKeyType::Wrapped
-    }
-}
-
 impl std::fmt::Display for DataKeyType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -498,10 +624,11 @@ pub struct GenerateRandomBytesRequest {
     pub source: RandomBytesSource,
 }
 
-#[derive(Clone, Copy, Debug, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum RandomBytesSource {
     /// Sources bytes from the platform's entropy source.
+    #[default]
     Platform,
     /// Sources from entropy augmentation (enterprise only).
     Seal,
@@ -509,12 +636,6 @@ pub enum RandomBytesSource {
     All,
 }
 
-impl Default for RandomBytesSource {
-    fn default() -> Self {
-        RandomBytesSource::Platform
-    }
-}
-
 /// ## Hash Data
 /// This endpoint returns the cryptographic hash of given data using the
 /// specified algorithm.
@@ -670,6 +791,110 @@ pub struct VerifySignedDataRequest {
     pub marshaling_algorithm: Option<MarshalingAlgorithm>,
 }
 
+/// ## Sign Data (Batch)
+/// This endpoint returns the cryptographic signatures of a batch of inputs
+/// using the named key in a single request. The results are returned in the
+/// same order as the items and each item succeeds or fails on its own.
+///
+/// * Path: {self.mount}/sign/{self.name}
+/// * Method: POST
+/// * Response: TransitBatchResponse
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#sign-data
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/sign/{self.name}",
+    method = "POST",
+    response = "TransitBatchResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct SignDataBatchRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// Specifies the items to be signed.
+    pub batch_input: Vec<SignBatchItem>,
+    /// Specifies the version of the key to use for the operation. If not set,
+    /// uses the latest version. Must be greater than or equal to the key's
+    /// min_encryption_version, if set.
+    pub key_version: Option<u64>,
+    /// Specifies the hash algorithm to use.
+    pub hash_algorithm: Option<HashAlgorithm>,
+    /// Set to true when the inputs are already hashed.
+    pub prehashed: Option<bool>,
+    /// When using a RSA key, specifies the RSA signature algorithm to use for
+    /// signing.
+    pub signature_algorithm: Option<SignatureAlgorithm>,
+    /// Specifies the way in which the signatures should be marshaled. This
+    /// currently only applies to ECDSA keys.
+    pub marshaling_algorithm: Option<MarshalingAlgorithm>,
+}
+
+/// A single item of a [SignDataBatchRequest].
+#[derive(Builder, Clone, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), default)]
+pub struct SignBatchItem {
+    /// Specifies the base64 encoded input data.
+    pub input: String,
+    /// Specifies the base64 encoded context for key derivation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// ## Verify Signed Data (Batch)
+/// This endpoint returns whether each of a batch of signatures is valid for
+/// its input in a single request. The results are returned in the same order
+/// as the items and each item succeeds or fails on its own.
+///
+/// * Path: {self.mount}/verify/{self.name}
+/// * Method: POST
+/// * Response: TransitBatchResponse
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#verify-signed-data
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/verify/{self.name}",
+    method = "POST",
+    response = "TransitBatchResponse",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct VerifySignedDataBatchRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// Specifies the items to be verified.
+    pub batch_input: Vec<VerifyBatchItem>,
+    /// Specifies the hash algorithm to use.
+    pub hash_algorithm: Option<HashAlgorithm>,
+    /// Set to true when the inputs are already hashed.
+    pub prehashed: Option<bool>,
+    /// When using a RSA key, specifies the RSA signature algorithm to use for
+    /// signature verification.
+    pub signature_algorithm: Option<SignatureAlgorithm>,
+    /// Specifies the way in which the signatures were originally marshaled.
+    /// This currently only applies to ECDSA keys.
+    pub marshaling_algorithm: Option<MarshalingAlgorithm>,
+}
+
+/// A single item of a [VerifySignedDataBatchRequest].
+#[derive(Builder, Clone, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), default)]
+pub struct VerifyBatchItem {
+    /// Specifies the base64 encoded input data.
+    pub input: String,
+    /// Specifies the signature output from the /transit/sign function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// Specifies the signature output from the /transit/hmac function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hmac: Option<String>,
+    /// Specifies the base64 encoded context for key derivation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
 /// ## Backup Key
 /// This endpoint returns a plaintext backup of a named key. The backup contains
 /// all the configuration data and keys of all the versions along with the HMAC
@@ -747,6 +972,107 @@ pub struct TrimKeyRequest {
     pub min_available_version: u64,
 }
 
+/// ## Get Wrapping Key
+/// This endpoint retrieves the public key of the wrapping key, which is used
+/// for wrapping keys which are imported into the transit engine.
+///
+/// * Path: {self.mount}/wrapping_key
+/// * Method: GET
+/// * Response: [ReadWrappingKeyResponse]
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#get-wrapping-key
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/wrapping_key",
+    response = "ReadWrappingKeyResponse",
+    builder = "true"
+)]
+#[builder(setter(into), default)]
+pub struct ReadWrappingKeyRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+}
+
+/// ## Import Key
+/// This endpoint imports existing key material into a new transit-managed
+/// encryption key. The key material must be wrapped with the wrapping key
+/// returned by [ReadWrappingKeyRequest].
+///
+/// * Path: {self.mount}/keys/{self.name}/import
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#import-key
+#[derive(Builder, Debug, Default, Endpoint, Serialize)]
+#[endpoint(
+    path = "{self.mount}/keys/{self.name}/import",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ImportKeyRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    /// Specifies the name of the encryption key to create.
+    #[endpoint(skip)]
+    pub name: String,
+    /// A base64-encoded string containing the wrapped ephemeral AES key
+    /// followed by the key material wrapped with it.
+    pub ciphertext: String,
+    /// The hash function used for the RSA-OAEP wrapping of the ephemeral AES
+    /// key. Defaults to SHA256.
+    pub hash_function: Option<ImportHashFunction>,
+    /// Specifies the type of key to create.
+    #[serde(rename = "type")]
+    pub key_type: Option<KeyType>,
+    /// If set, the imported key can be rotated within Vault by using the
+    /// rotate endpoint.
+    pub allow_rotation: Option<bool>,
+    /// Specifies if key derivation is to be used. If enabled, all
+    /// encrypt/decrypt requests to this named key must provide a context which
+    /// is used for key derivation.
+    pub derived: Option<bool>,
+    /// A base64-encoded string providing a context for key derivation.
+    /// Required if derived is set to true.
+    pub context: Option<String>,
+    /// Enables keys to be exportable. This allows for all the valid keys in the
+    /// key ring to be exported. Once set, this cannot be disabled.
+    pub exportable: Option<bool>,
+    /// If set, enables taking backup of named key in the plaintext format. Once
+    /// set, this cannot be disabled.
+    pub allow_plaintext_backup: Option<bool>,
+    /// The period at which this key should be rotated automatically. Setting
+    /// this to "0" (the default) will disable automatic key rotation. This
+    /// value cannot be shorter than one hour.
+    pub auto_rotate_period: Option<String>,
+}
+
+/// ## Import Key Version
+/// This endpoint imports new key material into an existing imported key. The
+/// key material must be wrapped like for [ImportKeyRequest].
+///
+/// * Path: {self.mount}/keys/{self.name}/import_version
+/// * Method: POST
+/// * Response: N/A
+/// * Reference: https://www.vaultproject.io/api-docs/secret/transit#import-key-version
+#[derive(Builder, Debug, Default, Endpoint)]
+#[endpoint(
+    path = "{self.mount}/keys/{self.name}/import_version",
+    method = "POST",
+    builder = "true"
+)]
+#[builder(setter(into, strip_option), default)]
+pub struct ImportKeyVersionRequest {
+    #[endpoint(skip)]
+    pub mount: String,
+    #[endpoint(skip)]
+    pub name: String,
+    /// A base64-encoded string containing the wrapped ephemeral AES key
+    /// followed by the key material wrapped with it.
+    pub ciphertext: String,
+    /// The hash function used for the RSA-OAEP wrapping of the ephemeral AES
+    /// key. Defaults to SHA256.
+    pub hash_function: Option<ImportHashFunction>,
+}
+
 /// ## Configure Cache
 /// This endpoint is used to configure the transit engine's cache. Note that
 /// configuration changes will not be applied until the transit plugin is
@@ -1,6 +1,7 @@
 use super::KeyType;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
+use thiserror::Error;
 
 /// Response from executing
 /// [ReadKeyRequest][crate::api::transit::requests::ReadKeyRequest]
@@ -25,19 +26,31 @@ pub struct ReadKeyResponse {
 
 /// Response from executing
 /// [ListKeysRequest][crate::api::transit::requests::ListKeysRequest]
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Default, Serialize)]
 pub struct ListKeysResponse {
     pub keys: Vec<String>,
 }
 
 /// Response from executing
 /// [ExportKeyRequest][crate::api::transit::requests::ExportKeyRequest]
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// The `keys` map each exported version to its key material.
+#[derive(Serialize, Deserialize)]
 pub struct ExportKeyResponse {
     pub name: String,
     pub keys: HashMap<String, String>,
 }
 
+impl fmt::Debug for ExportKeyResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys: HashMap<&String, &str> = self.keys.keys().map(|k| (k, "[redacted]")).collect();
+        f.debug_struct("ExportKeyResponse")
+            .field("name", &self.name)
+            .field("keys", &keys)
+            .finish()
+    }
+}
+
 /// Response from executing
 /// [EncryptDataRequest][crate::api::transit::requests::EncryptDataRequest]
 #[derive(Debug, Serialize, Deserialize)]
@@ -52,6 +65,56 @@ pub struct DecryptDataResponse {
     pub plaintext: String,
 }
 
+/// Response from executing
+/// [EncryptDataBatchRequest][crate::api::transit::requests::EncryptDataBatchRequest],
+/// [DecryptDataBatchRequest][crate::api::transit::requests::DecryptDataBatchRequest],
+/// [RewrapDataBatchRequest][crate::api::transit::requests::RewrapDataBatchRequest],
+/// [SignDataBatchRequest][crate::api::transit::requests::SignDataBatchRequest]
+/// or
+/// [VerifySignedDataBatchRequest][crate::api::transit::requests::VerifySignedDataBatchRequest]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransitBatchResponse {
+    pub batch_results: Vec<TransitBatchItem>,
+}
+
+/// A single entry of a [TransitBatchResponse]. Each entry succeeds or fails
+/// independently of the others in the batch.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TransitBatchItem {
+    Error(TransitItemError),
+    Success(TransitBatchResult),
+}
+
+impl TransitBatchItem {
+    /// Converts this entry into a [Result].
+    pub fn into_result(self) -> Result<TransitBatchResult, TransitItemError> {
+        match self {
+            TransitBatchItem::Error(e) => Err(e),
+            TransitBatchItem::Success(r) => Ok(r),
+        }
+    }
+}
+
+/// The result of a successful entry in a [TransitBatchResponse]. Encryption and
+/// rewrapping return the `ciphertext` and `key_version`, decryption the
+/// `plaintext`, signing the `signature` and verification whether it is `valid`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransitBatchResult {
+    pub ciphertext: Option<String>,
+    pub key_version: Option<u64>,
+    pub plaintext: Option<String>,
+    pub signature: Option<String>,
+    pub valid: Option<bool>,
+}
+
+/// The error of a failed entry in a [TransitBatchResponse].
+#[derive(Debug, Error, Serialize, Deserialize)]
+#[error("{error}")]
+pub struct TransitItemError {
+    pub error: String,
+}
+
 /// Response from executing
 /// [RewrapDataRequest][crate::api::transit::requests::RewrapDataRequest]
 #[derive(Debug, Serialize, Deserialize)]
@@ -65,6 +128,7 @@ pub struct RewrapDataResponse {
 pub struct GenerateDataKeyResponse {
     pub plaintext: Option<String>,
     pub ciphertext: String,
+    pub key_version: Option<u64>,
 }
 
 /// Response from executing
@@ -109,6 +173,14 @@ pub struct BackupKeyResponse {
     pub backup: String,
 }
 
+/// Response from executing
+/// [ReadWrappingKeyRequest][crate::api::transit::requests::ReadWrappingKeyRequest]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReadWrappingKeyResponse {
+    /// The PEM encoded RSA-4096 public key of the wrapping key
+    pub public_key: String,
+}
+
 /// Response from executing
 /// [ReadTransitCacheConfigurationRequest][crate::api::transit::requests::ReadTransitCacheConfigurationRequest]
 #[derive(Debug, Serialize, Deserialize)]
@@ -36,7 +36,6 @@ pub async fn tidy(client: &impl Client, mount: &str) -> Result<(), ClientError>
 }
 
 pub mod role {
-    use crate::api;
     use crate::api::auth::approle::requests::UpdateRoleIDRequest;
     use crate::api::auth::approle::{
         requests::{
@@ -45,6 +44,7 @@ pub mod role {
         },
         responses::{ListRolesResponse, ReadAppRoleResponse, ReadRoleIDResponse},
     };
+    use crate::api::{self, ListFilter};
     use crate::client::Client;
     use crate::error::ClientError;
 
@@ -54,7 +54,22 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
         let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Lists the names of the AppRoles which pass the given filter.
+    ///
+    /// An empty list is returned if there are no AppRoles.
+    ///
+    /// See [ListRolesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list_filtered(
+        client: &impl Client,
+        mount: &str,
+        filter: &ListFilter,
+    ) -> Result<Vec<String>, ClientError> {
+        let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
+        api::exec_with_filtered_list(client, endpoint, filter).await
     }
 
     /// Reads properties of an AppRole.
@@ -148,7 +163,6 @@ pub mod role {
     }
 
     pub mod secret {
-        use crate::api;
         use crate::api::auth::approle::requests::{
             CreateCustomSecretIDRequest, CreateCustomSecretIDRequestBuilder,
             DeleteSecretIDAccessorRequest, DeleteSecretIDRequest, GenerateNewSecretIDRequest,
@@ -159,13 +173,14 @@ pub mod role {
             CreateCustomSecretIDResponse, GenerateNewSecretIDResponse, ListSecretIDResponse,
             ReadSecretIDResponse,
         };
+        use crate::api::{self, ListFilter};
         use crate::client::Client;
         use crate::error::ClientError;
 
         /// Creates a custom secret ID.
         ///
         /// See [CreateCustomSecretIDRequest]
-        #[instrument(skip(client, opts), err)]
+        #[instrument(skip(client, secret_id, opts), err)]
         pub async fn custom(
             client: &impl Client,
             mount: &str,
@@ -187,7 +202,7 @@ pub mod role {
         /// Deletes an AppRole secret ID.
         ///
         /// See [DeleteSecretIDRequest]
-        #[instrument(skip(client), err)]
+        #[instrument(skip(client, secret_id), err)]
         pub async fn delete(
             client: &impl Client,
             mount: &str,
@@ -224,6 +239,10 @@ pub mod role {
 
         /// Generates and issues a new SecretID on an existing AppRole.
         ///
+        /// The `metadata` option takes a JSON encoded object of string values
+        /// which is attached to the tokens issued with the secret ID, while
+        /// `cidr_list` restricts the source addresses it can be used from.
+        ///
         /// See [GenerateNewSecretIDRequest]
         #[instrument(skip(client, opts), err)]
         pub async fn generate(
@@ -242,7 +261,7 @@ pub mod role {
             api::exec_with_result(client, endpoint).await
         }
 
-        /// Lists ApplRole secret IDs.
+        /// Lists the accessors of all AppRole secret IDs.
         ///
         /// See [ListSecretIDRequest]
         #[instrument(skip(client), err)]
@@ -256,13 +275,34 @@ pub mod role {
                 .role_name(role_name)
                 .build()
                 .unwrap();
-            api::exec_with_result(client, endpoint).await
+            api::exec_with_list_result(client, endpoint).await
         }
 
-        /// Reads an AppleRole secret ID.
+        /// Lists the accessors of the AppRole secret IDs which pass the given
+        /// filter.
         ///
-        /// See [ReadSecretIDRequest]
+        /// An empty list is returned if the AppRole has no secret IDs.
+        ///
+        /// See [ListSecretIDRequest]
         #[instrument(skip(client), err)]
+        pub async fn list_filtered(
+            client: &impl Client,
+            mount: &str,
+            role_name: &str,
+            filter: &ListFilter,
+        ) -> Result<Vec<String>, ClientError> {
+            let endpoint = ListSecretIDRequest::builder()
+                .mount(mount)
+                .role_name(role_name)
+                .build()
+                .unwrap();
+            api::exec_with_filtered_list(client, endpoint, filter).await
+        }
+
+        /// Reads an AppRole secret ID.
+        ///
+        /// See [ReadSecretIDRequest]
+        #[instrument(skip(client, secret_id), err)]
         pub async fn read(
             client: &impl Client,
             mount: &str,
@@ -278,7 +318,7 @@ pub mod role {
             api::exec_with_result(client, endpoint).await
         }
 
-        /// Reads an AppleRole secret ID by accessor.
+        /// Reads an AppRole secret ID by accessor.
         ///
         /// See [ReadSecretIDAccessorRequest]
         #[instrument(skip(client), err)]
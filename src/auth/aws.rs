@@ -8,7 +8,10 @@ use crate::{
     error::ClientError,
 };
 
-// See [IamLoginRequest]
+/// Logs in using a signed STS `GetCallerIdentity` request, where the URL,
+/// headers and body of the request are base64 encoded.
+///
+/// See [IamLoginRequest]
 #[instrument(skip(client, iam_request_headers, iam_request_body), err)]
 pub async fn iam_login(
     client: &impl Client,
@@ -34,7 +37,10 @@ pub async fn iam_login(
     api::auth(client, endpoint.mount(mount).build().unwrap()).await
 }
 
-// See [Ec2LoginRequest]
+/// Logs in using the PKCS#7 signature of the identity document of an EC2
+/// instance.
+///
+/// See [Ec2LoginRequest]
 #[instrument(skip(client, pkcs7, nonce), err)]
 pub async fn ec2_login(
     client: &impl Client,
@@ -265,7 +271,7 @@ pub mod config {
                 .mount(mount)
                 .build()
                 .unwrap();
-            api::exec_with_result(client, endpoint).await
+            api::exec_with_list_result(client, endpoint).await
         }
     }
 
@@ -330,7 +336,7 @@ pub mod config {
             mount: &str,
         ) -> Result<ListStsRolesResponse, ClientError> {
             let endpoint = ListStsRolesRequest::builder().mount(mount).build().unwrap();
-            api::exec_with_result(client, endpoint).await
+            api::exec_with_list_result(client, endpoint).await
         }
 
         /// Deletes a previously configured AWS account/STS role association.
@@ -534,7 +540,7 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
         let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Deletes the previously registered role
@@ -634,7 +640,7 @@ pub mod role_tag_deny_list {
             .mount(mount)
             .build()
             .unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Deletes a deny listed role tag
@@ -734,7 +740,7 @@ pub mod identity_access_list {
             .mount(mount)
             .build()
             .unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Cleans up the entries in the access list based on expiration time andsafety_buffer
@@ -0,0 +1,35 @@
+use crate::{
+    api::{
+        self,
+        auth::azure::requests::{LoginWithAzureRequest, LoginWithAzureRequestBuilder},
+        AuthInfo,
+    },
+    client::Client,
+    error::ClientError,
+};
+
+/// Fetch a token using the JWT of an Azure managed identity and a role.
+///
+/// The metadata of the virtual machine, like its `subscription_id` and
+/// `vm_name`, is passed through the options and is required by roles which
+/// are bound to it.
+///
+/// See [LoginWithAzureRequest]
+#[instrument(skip(client, jwt, opts), err)]
+pub async fn login(
+    client: &impl Client,
+    mount: &str,
+    role: &str,
+    jwt: &str,
+    opts: Option<&mut LoginWithAzureRequestBuilder>,
+) -> Result<AuthInfo, ClientError> {
+    let mut t = LoginWithAzureRequest::builder();
+    let endpoint = opts
+        .unwrap_or(&mut t)
+        .mount(mount)
+        .role(role)
+        .jwt(jwt)
+        .build()
+        .unwrap();
+    api::auth(client, endpoint).await
+}
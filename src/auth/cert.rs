@@ -0,0 +1,112 @@
+use crate::{
+    api::{self, auth::cert::requests::LoginRequest, AuthInfo},
+    client::Client,
+    error::ClientError,
+};
+
+/// Fetch a token using the client certificate the client was configured with.
+///
+/// The client must present a certificate, see
+/// [VaultClientSettings::identity][crate::client::VaultClientSettings::identity].
+/// If no `name` is given, Vault tries to match the certificate against all
+/// configured certificate roles.
+///
+/// See [LoginRequest]
+#[instrument(skip(client), err)]
+pub async fn login(
+    client: &impl Client,
+    mount: &str,
+    name: Option<&str>,
+) -> Result<AuthInfo, ClientError> {
+    let mut endpoint = LoginRequest::builder();
+    if let Some(name) = name {
+        endpoint.name(name);
+    }
+    api::auth(client, endpoint.mount(mount).build().unwrap()).await
+}
+
+pub mod ca_cert_role {
+    use crate::{
+        api::{
+            self,
+            auth::cert::{
+                requests::{
+                    CreateCaCertificateRoleRequest, CreateCaCertificateRoleRequestBuilder,
+                    DeleteCaCertificateRoleRequest, ListCaCertificateRoleRequest,
+                    ReadCaCertificateRoleRequest,
+                },
+                responses::{ListCaCertificateRoleResponse, ReadCaCertificateRoleResponse},
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Deletes a CA certificate role.
+    ///
+    /// See [DeleteCaCertificateRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, mount: &str, name: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteCaCertificateRoleRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Lists CA certificate roles.
+    ///
+    /// See [ListCaCertificateRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(
+        client: &impl Client,
+        mount: &str,
+    ) -> Result<ListCaCertificateRoleResponse, ClientError> {
+        let endpoint = ListCaCertificateRoleRequest::builder()
+            .mount(mount)
+            .build()
+            .unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Reads information about a CA certificate role.
+    ///
+    /// See [ReadCaCertificateRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+    ) -> Result<ReadCaCertificateRoleResponse, ClientError> {
+        let endpoint = ReadCaCertificateRoleRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Creates or updates a CA certificate role, trusting client certificates
+    /// signed by the given PEM encoded CA certificate.
+    ///
+    /// See [CreateCaCertificateRoleRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn set(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        certificate: &str,
+        opts: Option<&mut CreateCaCertificateRoleRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = CreateCaCertificateRoleRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .mount(mount)
+            .name(name)
+            .certificate(certificate)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+}
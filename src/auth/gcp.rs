@@ -0,0 +1,25 @@
+use crate::{
+    api::{self, auth::gcp::requests::LoginWithGcpRequest, AuthInfo},
+    client::Client,
+    error::ClientError,
+};
+
+/// Fetch a token using a signed JWT of a GCP service account or GCE instance
+/// and a role.
+///
+/// See [LoginWithGcpRequest]
+#[instrument(skip(client, jwt), err)]
+pub async fn login(
+    client: &impl Client,
+    mount: &str,
+    role: &str,
+    jwt: &str,
+) -> Result<AuthInfo, ClientError> {
+    let endpoint = LoginWithGcpRequest::builder()
+        .mount(mount)
+        .role(role)
+        .jwt(jwt)
+        .build()
+        .unwrap();
+    api::auth(client, endpoint).await
+}
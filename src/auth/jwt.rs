@@ -0,0 +1,28 @@
+use crate::{
+    api::{self, auth::oidc::requests::JWTLoginRequest, AuthInfo},
+    client::Client,
+    error::ClientError,
+};
+
+/// Fetch a token using a pre-obtained JWT and a role, without going through
+/// the OIDC browser flow.
+///
+/// JWT logins are handled by the same backend as OIDC, so the mount is
+/// usually either `jwt` or `oidc` depending on how it was enabled.
+///
+/// See [JWTLoginRequest]
+#[instrument(skip(client, jwt), err)]
+pub async fn login(
+    client: &impl Client,
+    mount: &str,
+    role: &str,
+    jwt: &str,
+) -> Result<AuthInfo, ClientError> {
+    let endpoint = JWTLoginRequest::builder()
+        .mount(mount)
+        .role(role)
+        .jwt(jwt)
+        .build()
+        .unwrap();
+    api::auth(client, endpoint).await
+}
@@ -50,7 +50,7 @@ pub async fn read_config(
 // Fetch a <token with policies using a Kubernetes ServiceAccount.
 //
 // See [LoginWithKubernetesRequest]
-#[instrument(skip(client), err)]
+#[instrument(skip(client, jwt), err)]
 pub async fn login(
     client: &impl Client,
     mount: &str,
@@ -85,7 +85,7 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
         let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Reads properties of a Kubernetes role.
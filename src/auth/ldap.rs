@@ -0,0 +1,166 @@
+use crate::{
+    api::{
+        self,
+        auth::ldap::{
+            requests::{
+                DeleteGroupRequest, DeleteUserRequest, ListGroupsRequest, ListUsersRequest,
+                LoginRequest, ReadGroupRequest, ReadUserRequest, SetGroupRequest, SetUserRequest,
+            },
+            responses::{
+                ListGroupsResponse, ListUsersResponse, ReadGroupResponse, ReadUserResponse,
+            },
+        },
+        AuthInfo,
+    },
+    client::Client,
+    error::ClientError,
+};
+
+/// Fetch a token with the policies of the LDAP user and the groups it's a
+/// member of.
+///
+/// See [LoginRequest]
+#[instrument(skip(client, password), err)]
+pub async fn login(
+    client: &impl Client,
+    mount: &str,
+    username: &str,
+    password: &str,
+) -> Result<AuthInfo, ClientError> {
+    let endpoint = LoginRequest::builder()
+        .mount(mount)
+        .username(username)
+        .password(password)
+        .build()
+        .unwrap();
+    api::auth(client, endpoint).await
+}
+
+/// Deletes the policy mapping of an LDAP group.
+///
+/// See [DeleteGroupRequest]
+#[instrument(skip(client), err)]
+pub async fn delete_group(
+    client: &impl Client,
+    mount: &str,
+    group: &str,
+) -> Result<(), ClientError> {
+    let endpoint = DeleteGroupRequest::builder()
+        .mount(mount)
+        .name(group)
+        .build()
+        .unwrap();
+    api::exec_with_empty(client, endpoint).await
+}
+
+/// Lists the LDAP groups which have a policy mapping.
+///
+/// See [ListGroupsRequest]
+#[instrument(skip(client), err)]
+pub async fn list_groups(
+    client: &impl Client,
+    mount: &str,
+) -> Result<ListGroupsResponse, ClientError> {
+    let endpoint = ListGroupsRequest::builder().mount(mount).build().unwrap();
+    api::exec_with_list_result(client, endpoint).await
+}
+
+/// Reads the policies associated with an LDAP group.
+///
+/// See [ReadGroupRequest]
+#[instrument(skip(client), err)]
+pub async fn read_group(
+    client: &impl Client,
+    mount: &str,
+    group: &str,
+) -> Result<ReadGroupResponse, ClientError> {
+    let endpoint = ReadGroupRequest::builder()
+        .mount(mount)
+        .name(group)
+        .build()
+        .unwrap();
+    api::exec_with_result(client, endpoint).await
+}
+
+/// Maps an LDAP group to the given policies, replacing any existing mapping.
+///
+/// See [SetGroupRequest]
+#[instrument(skip(client), err)]
+pub async fn set_group(
+    client: &impl Client,
+    mount: &str,
+    group: &str,
+    policies: Vec<String>,
+) -> Result<(), ClientError> {
+    let endpoint = SetGroupRequest::builder()
+        .mount(mount)
+        .name(group)
+        .policies(policies)
+        .build()
+        .unwrap();
+    api::exec_with_empty(client, endpoint).await
+}
+
+/// Deletes the groups and policies associated with an LDAP user.
+///
+/// See [DeleteUserRequest]
+#[instrument(skip(client), err)]
+pub async fn delete_user(client: &impl Client, mount: &str, user: &str) -> Result<(), ClientError> {
+    let endpoint = DeleteUserRequest::builder()
+        .mount(mount)
+        .username(user)
+        .build()
+        .unwrap();
+    api::exec_with_empty(client, endpoint).await
+}
+
+/// Lists the LDAP users which have groups or policies associated.
+///
+/// See [ListUsersRequest]
+#[instrument(skip(client), err)]
+pub async fn list_users(
+    client: &impl Client,
+    mount: &str,
+) -> Result<ListUsersResponse, ClientError> {
+    let endpoint = ListUsersRequest::builder().mount(mount).build().unwrap();
+    api::exec_with_list_result(client, endpoint).await
+}
+
+/// Reads the groups and policies associated with an LDAP user.
+///
+/// See [ReadUserRequest]
+#[instrument(skip(client), err)]
+pub async fn read_user(
+    client: &impl Client,
+    mount: &str,
+    user: &str,
+) -> Result<ReadUserResponse, ClientError> {
+    let endpoint = ReadUserRequest::builder()
+        .mount(mount)
+        .username(user)
+        .build()
+        .unwrap();
+    api::exec_with_result(client, endpoint).await
+}
+
+/// Associates an LDAP user with groups and policies, in addition to those of
+/// the groups it's a member of in LDAP.
+///
+/// See [SetUserRequest]
+#[instrument(skip(client), err)]
+pub async fn set_user(
+    client: &impl Client,
+    mount: &str,
+    user: &str,
+    groups: Vec<String>,
+    policies: Vec<String>,
+) -> Result<(), ClientError> {
+    let endpoint = SetUserRequest::builder()
+        .mount(mount)
+        .username(user)
+        .groups(groups)
+        .policies(policies)
+        .build()
+        .unwrap();
+    api::exec_with_empty(client, endpoint).await
+}
@@ -39,7 +39,7 @@ pub async fn auth(
 /// Exchange an authorization code for an OIDC ID Token
 ///
 /// See [OIDCCallbackRequest]
-#[instrument(skip(client), err)]
+#[instrument(skip(client, state, nonce, code), err)]
 pub async fn callback(
     client: &impl Client,
     mount: &str,
@@ -60,7 +60,7 @@ pub async fn callback(
 /// Fetch a token using a JWT token
 ///
 /// See [JWTLoginRequest]
-#[instrument(skip(client), err)]
+#[instrument(skip(client, jwt), err)]
 pub async fn login(
     client: &impl Client,
     mount: &str,
@@ -151,7 +151,7 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
         let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Reads a role
@@ -63,7 +63,7 @@ pub mod user {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListUsersResponse, ClientError> {
         let endpoint = ListUsersRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Reads information about a user.
@@ -86,7 +86,7 @@ pub mod user {
     /// Crates or updates a new user.
     ///
     /// See [CreateUserRequest]
-    #[instrument(skip(client, opts), err)]
+    #[instrument(skip(client, password, opts), err)]
     pub async fn set(
         client: &impl Client,
         mount: &str,
@@ -0,0 +1,28 @@
+use crate::api::aws::requests::{GenerateCredentialsRequest, GenerateCredentialsRequestBuilder};
+use crate::api::aws::responses::GenerateCredentialsResponse;
+use crate::api::{self, LeasedSecret};
+use crate::client::Client;
+use crate::error::ClientError;
+
+/// Generates AWS credentials from a role
+///
+/// The returned credentials include the lease which can be used to renew or
+/// revoke them. Only STS based credentials carry a `security_token`.
+///
+/// See [GenerateCredentialsRequest]
+#[instrument(skip(client, opts), err)]
+pub async fn generate_credentials(
+    client: &impl Client,
+    mount: &str,
+    name: &str,
+    opts: Option<&mut GenerateCredentialsRequestBuilder>,
+) -> Result<LeasedSecret<GenerateCredentialsResponse>, ClientError> {
+    let mut t = GenerateCredentialsRequest::builder();
+    let endpoint = opts
+        .unwrap_or(&mut t)
+        .mount(mount)
+        .name(name)
+        .build()
+        .unwrap();
+    api::exec_with_leased_secret(client, endpoint).await
+}
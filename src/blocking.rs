@@ -0,0 +1,103 @@
+//! A blocking client, available with the optional `blocking` feature.
+//!
+//! The [Client] wraps an asynchronous [VaultClient] together with its own
+//! current-thread Tokio runtime. Any operation of this crate is executed by
+//! passing a closure to [Client::call], which blocks until the operation
+//! completes:
+//!
+//! ```no_run
+//! use std::collections::HashMap;
+//! use vaultrs::blocking::Client;
+//! use vaultrs::client::VaultClientSettingsBuilder;
+//! use vaultrs::kv2;
+//!
+//! let client = Client::new(
+//!     VaultClientSettingsBuilder::default()
+//!         .address("https://127.0.0.1:8200")
+//!         .token("TOKEN")
+//!         .build()
+//!         .unwrap(),
+//! )
+//! .unwrap();
+//!
+//! let secret: HashMap<String, String> = client
+//!     .call(|c| kv2::read(c, "secret", "mysecret"))
+//!     .unwrap();
+//! ```
+//!
+//! ## Runtime
+//!
+//! The runtime is created once along with the client and shared by all of its
+//! calls, rather than creating a runtime per call. Connections to Vault are
+//! pooled by the underlying HTTP client and bound to the runtime they were
+//! opened on, so they are reused across calls and the cost of starting a
+//! runtime is only paid once.
+//!
+//! As with any blocking API, the client must not be used from within an
+//! asynchronous context, where blocking on the runtime panics. Use the
+//! asynchronous [VaultClient] directly there instead.
+
+use std::future::Future;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::client::{Client as _, RequestHook, VaultClient, VaultClientSettings};
+use crate::error::ClientError;
+
+/// A blocking client which executes the asynchronous operations of this crate
+/// on its own runtime.
+///
+/// See the [module documentation][self] for how the runtime is managed.
+pub struct Client {
+    inner: VaultClient,
+    runtime: Runtime,
+}
+
+impl Client {
+    /// Creates a new [Client] using the given [VaultClientSettings].
+    pub fn new(settings: VaultClientSettings) -> Result<Self, ClientError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| ClientError::RuntimeBuildError { source: e })?;
+        Ok(Client {
+            inner: VaultClient::new(settings)?,
+            runtime,
+        })
+    }
+
+    /// Executes the operation returned by the closure, blocking until it
+    /// completes.
+    ///
+    /// The closure is passed the asynchronous client which it is supposed to
+    /// pass on to the operation, like `|c| kv2::read(c, "secret", "app")`.
+    pub fn call<'a, F, Fut, T>(&'a self, f: F) -> T
+    where
+        F: FnOnce(&'a VaultClient) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        self.runtime.block_on(f(&self.inner))
+    }
+
+    /// Returns the asynchronous client wrapped by this client.
+    pub fn inner(&self) -> &VaultClient {
+        &self.inner
+    }
+
+    /// Returns the settings of this client.
+    pub fn settings(&self) -> &VaultClientSettings {
+        self.inner.settings()
+    }
+
+    /// Sets the token used by subsequent calls of this client.
+    pub fn set_token(&mut self, token: &str) {
+        self.inner.set_token(token)
+    }
+
+    /// Registers a hook which is called after each request of this client.
+    ///
+    /// See [VaultClient::on_request].
+    pub fn on_request(&mut self, hook: RequestHook) {
+        self.inner.on_request(hook)
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{client::Client, error::ClientError};
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    sync::{mpsc, RwLock},
+    task::JoinHandle,
+};
+
+/// The default fraction of the token TTL to wait before renewing it.
+const DEFAULT_FRACTION: f64 = 2.0 / 3.0;
+
+/// Periodically renews the token of a [Client] in the background.
+///
+/// The renewer looks up the TTL of the current token and spawns a Tokio task
+/// which calls the `renew-self` endpoint once a fraction of the TTL has
+/// elapsed (2/3 by default). The token returned by Vault is written back to the
+/// shared client, so it must be wrapped in an `Arc<RwLock<_>>` which is also
+/// used for making regular API calls.
+///
+/// Tokens without a TTL (i.e. root tokens) are never renewed. If a renewal
+/// fails, for example because the token was revoked, the error is sent to the
+/// [AutoRenewerHandle] and the task exits. Once the token is close to its max
+/// TTL and a renewal doesn't extend it anymore, a
+/// [ClientError::TokenMaxTtlReached] is sent instead. This is the case when
+/// Vault returns a TTL which is shorter than the requested increment, or which
+/// isn't longer than the TTL the token had left before the renewal.
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use tokio::sync::RwLock;
+/// use vaultrs::client::{renew::AutoRenewer, VaultClient, VaultClientSettingsBuilder};
+///
+/// # async fn run() {
+/// let client = VaultClient::new(
+///     VaultClientSettingsBuilder::default()
+///         .address("https://127.0.0.1:8200")
+///         .token("TOKEN")
+///         .build()
+///         .unwrap()
+/// ).unwrap();
+/// let client = Arc::new(RwLock::new(client));
+///
+/// let mut handle = AutoRenewer::new(client.clone()).increment("1h").start();
+/// if let Some(e) = handle.error().await {
+///     eprintln!("Token renewal failed: {}", e);
+/// }
+/// # }
+/// ```
+pub struct AutoRenewer<C: Client + 'static> {
+    client: Arc<RwLock<C>>,
+    fraction: f64,
+    increment: Option<String>,
+}
+
+impl<C: Client + 'static> AutoRenewer<C> {
+    /// Creates a new [AutoRenewer] for the given client.
+    pub fn new(client: Arc<RwLock<C>>) -> Self {
+        AutoRenewer {
+            client,
+            fraction: DEFAULT_FRACTION,
+            increment: None,
+        }
+    }
+
+    /// Sets the fraction of the token TTL to wait before each renewal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fraction is not greater than 0 and less than or equal
+    /// to 1.
+    pub fn fraction(mut self, fraction: f64) -> Self {
+        assert!(
+            fraction > 0.0 && fraction <= 1.0,
+            "Invalid renewal fraction: {}",
+            fraction
+        );
+        self.fraction = fraction;
+        self
+    }
+
+    /// Sets the increment requested from Vault on each renewal.
+    pub fn increment(mut self, increment: &str) -> Self {
+        self.increment = Some(increment.to_string());
+        self
+    }
+
+    /// Spawns the renewal task and returns a handle for controlling it.
+    pub fn start(self) -> AutoRenewerHandle {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            if let Err(e) = self.run().await {
+                error!("Token renewal failed: {}", e);
+                let _ = tx.send(e);
+            }
+        });
+
+        AutoRenewerHandle { task, errors: rx }
+    }
+
+    async fn run(self) -> Result<(), ClientError> {
+        let increment = self.increment.as_deref().and_then(parse_duration);
+        let mut ttl = self.client.read().await.lookup().await?.ttl;
+        loop {
+            if ttl == 0 {
+                info!("Token has no TTL, stopping renewal");
+                return Ok(());
+            }
+
+            let wait = Duration::from_secs_f64(ttl as f64 * self.fraction);
+            debug!("Renewing token in {:?}", wait);
+            tokio::time::sleep(wait).await;
+
+            let auth = {
+                let client = self.client.read().await;
+                client.renew(self.increment.as_deref()).await?
+            };
+            self.client.write().await.set_token(&auth.client_token);
+
+            // Once the token is close to its max TTL, Vault caps the renewal
+            // to the time the token has left instead of extending it
+            let remaining = ttl.saturating_sub(wait.as_secs());
+            if auth.lease_duration <= remaining
+                || increment.is_some_and(|i| auth.lease_duration < i)
+            {
+                return Err(ClientError::TokenMaxTtlReached);
+            }
+            ttl = auth.lease_duration;
+        }
+    }
+}
+
+/// Parses a duration like `90`, `30m` or `1h30m` into seconds, returning
+/// `None` if it isn't in a format understood by the renewer.
+fn parse_duration(duration: &str) -> Option<u64> {
+    if let Ok(secs) = duration.parse() {
+        return Some(secs);
+    }
+
+    let mut total = 0;
+    let mut value = String::new();
+    for c in duration.chars() {
+        if c.is_ascii_digit() {
+            value.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            _ => return None,
+        };
+        total += value.parse::<u64>().ok()? * unit;
+        value.clear();
+    }
+    value.is_empty().then_some(total)
+}
+
+/// A handle to a running [AutoRenewer] task.
+pub struct AutoRenewerHandle {
+    task: JoinHandle<()>,
+    errors: mpsc::UnboundedReceiver<ClientError>,
+}
+
+impl AutoRenewerHandle {
+    /// Waits for the renewal task to exit, returning the error which caused
+    /// it to stop, if any.
+    pub async fn error(&mut self) -> Option<ClientError> {
+        self.errors.recv().await
+    }
+
+    /// Stops the renewal task.
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    api::{self, kv1::requests::ListSecretRequest},
+    client::Client,
+    error::ClientError,
+    kv1,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
+
+/// The cubbyhole secrets engine is always mounted at this path
+const MOUNT: &str = "cubbyhole";
+
+/// Sets the value of the secret at the given path in the cubbyhole of the
+/// client's token
+///
+/// The secret is only visible to the same token and is destroyed along with it.
+///
+/// See [SetSecretRequest][crate::api::kv1::requests::SetSecretRequest]
+#[instrument(skip(client, data), err)]
+pub async fn set<T: Serialize>(
+    client: &impl Client,
+    path: &str,
+    data: &HashMap<&str, T>,
+) -> Result<(), ClientError> {
+    kv1::set(client, MOUNT, path, data).await
+}
+
+/// Reads the secret at the given path in the cubbyhole of the client's token
+///
+/// Only secrets which were written with the same token can be read.
+///
+/// See [GetSecretRequest][crate::api::kv1::requests::GetSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn read<D: DeserializeOwned>(client: &impl Client, path: &str) -> Result<D, ClientError> {
+    kv1::get(client, MOUNT, path).await
+}
+
+/// Reads the secret at the given path in the cubbyhole of the client's token,
+/// returning `None` if it doesn't exist
+///
+/// See [GetSecretRequest][crate::api::kv1::requests::GetSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn read_optional<D: DeserializeOwned>(
+    client: &impl Client,
+    path: &str,
+) -> Result<Option<D>, ClientError> {
+    kv1::get_optional(client, MOUNT, path).await
+}
+
+/// Lists the secret keys at the given path in the cubbyhole of the client's
+/// token, returning an empty list if there are none
+///
+/// See [ListSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn list(client: &impl Client, path: &str) -> Result<Vec<String>, ClientError> {
+    let endpoint = ListSecretRequest::builder()
+        .mount(MOUNT)
+        .path(path)
+        .build()
+        .unwrap();
+    api::exec_with_list(client, endpoint).await
+}
+
+/// Deletes the secret at the given path in the cubbyhole of the client's token
+///
+/// See [DeleteSecretRequest][crate::api::kv1::requests::DeleteSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn delete(client: &impl Client, path: &str) -> Result<(), ClientError> {
+    kv1::delete(client, MOUNT, path).await
+}
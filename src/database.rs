@@ -56,7 +56,7 @@ pub mod connection {
             .mount(mount)
             .build()
             .unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Reads a connection
@@ -91,6 +91,9 @@ pub mod connection {
 
     /// Rotates the root account configured in a connection
     ///
+    /// This cannot be undone: the new password is only known to Vault, so the
+    /// previous root credentials can no longer be used by anyone.
+    ///
     /// See [RotateRootRequest]
     #[instrument(skip(client), err)]
     pub async fn rotate(client: &impl Client, mount: &str, name: &str) -> Result<(), ClientError> {
@@ -104,7 +107,6 @@ pub mod connection {
 }
 
 pub mod role {
-    use crate::api;
     use crate::api::database::{
         requests::{
             DeleteRoleRequest, GenerateCredentialsRequest, ListRolesRequest, ReadRoleRequest,
@@ -112,24 +114,28 @@ pub mod role {
         },
         responses::{GenerateCredentialsResponse, ListRolesResponse, ReadRoleResponse},
     };
+    use crate::api::{self, LeasedSecret};
     use crate::client::Client;
     use crate::error::ClientError;
 
     /// Generates credentials from a role
     ///
+    /// The returned credentials include the lease which can be used to renew
+    /// or revoke them.
+    ///
     /// See [GenerateCredentialsRequest]
     #[instrument(skip(client), err)]
     pub async fn creds(
         client: &impl Client,
         mount: &str,
         name: &str,
-    ) -> Result<GenerateCredentialsResponse, ClientError> {
+    ) -> Result<LeasedSecret<GenerateCredentialsResponse>, ClientError> {
         let endpoint = GenerateCredentialsRequest::builder()
             .mount(mount)
             .name(name)
             .build()
             .unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_leased_secret(client, endpoint).await
     }
 
     /// Deletes a role
@@ -151,7 +157,7 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
         let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Reads a role
@@ -249,7 +255,7 @@ pub mod static_role {
             .mount(mount)
             .build()
             .unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Reads a static role
@@ -4,7 +4,30 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum ClientError {
     #[error("The Vault server returned an error (status code {code})")]
-    APIError { code: u16, errors: Vec<String> },
+    APIError {
+        /// The HTTP status code of the response, like 403 for a token which
+        /// lacks permissions on the path
+        code: u16,
+        errors: Vec<String>,
+    },
+    #[error("The check-and-set version doesn't match the current version of the secret")]
+    CheckAndSetFailed,
+    #[error("Error decoding the {key} field as base64")]
+    DecodeError {
+        source: Option<base64::DecodeError>,
+        key: String,
+    },
+    #[error("Error deserializing the response of {path}: {message}")]
+    DeserializationError {
+        path: String,
+        message: String,
+        /// The response body, truncated and with secret fields redacted
+        body: Option<String>,
+    },
+    #[error("No PEM encoded CA certificate found in: {path}")]
+    EmptyCertificateError { path: String },
+    #[error("The endpoint is only available in Vault Enterprise")]
+    EnterpriseOnlyError,
     #[error("Failed to find file: {path}")]
     FileNotFoundError { path: String },
     #[error("Error reading file: {path}")]
@@ -21,11 +44,44 @@ pub enum ClientError {
     InvalidLoginMethodError,
     #[error("Error parsing value into JSON")]
     JsonParseError { source: serde_json::error::Error },
+    #[error("The key doesn't allow deletion, which must be enabled in its configuration")]
+    KeyDeletionNotAllowed,
+    #[error("The key was not created as exportable")]
+    KeyNotExportable,
+    #[error("Listing {path} exceeded the maximum depth of {max_depth}")]
+    ListDepthExceeded { path: String, max_depth: usize },
+    #[error("No engine is mounted at: {path}")]
+    MountNotFoundError { path: String },
+    #[error("The OIDC authorization server returned an error: {error}")]
+    OIDCAuthError {
+        error: String,
+        description: Option<String>,
+    },
+    #[error("Invalid OIDC redirect host: {host}")]
+    OIDCRedirectHostError {
+        source: url::ParseError,
+        host: String,
+    },
+    #[error("Error receiving the OIDC callback")]
+    OIDCServerError { source: std::io::Error },
+    #[error("Timed out waiting for the OIDC callback")]
+    OIDCServerTimeout,
     #[error("Error parsing CA certificate as PEM encoded certificate: {path}")]
     ParseCertificateError {
         source: reqwest::Error,
         path: String,
     },
+    #[error("Error parsing client certificate and key: {path}")]
+    ParseIdentityError {
+        source: reqwest::Error,
+        path: String,
+    },
+    #[error("The SHA-256 checksum doesn't match the binary of plugin: {name}")]
+    PluginChecksumMismatch { name: String },
+    #[error("The request was rate limited by the Vault server")]
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+    },
     #[error("The request returned an empty response")]
     ResponseEmptyError,
     #[error("The result contained an empty data field")]
@@ -39,6 +95,40 @@ pub enum ClientError {
         #[from]
         source: rustify::errors::ClientError,
     },
+    #[error("Error building the runtime of the blocking client")]
+    RuntimeBuildError { source: std::io::Error },
+    #[error("The request to Vault timed out")]
+    Timeout {
+        source: rustify::errors::ClientError,
+    },
+    #[error("The token reached its max TTL and can't be renewed anymore")]
+    TokenMaxTtlReached,
+    #[error("{feature:?} isn't supported by Vault {server_version}")]
+    UnsupportedFeature {
+        feature: crate::sys::Feature,
+        server_version: String,
+    },
+    #[error("Error decoding the response body as UTF-8")]
+    Utf8DecodeError { source: std::string::FromUtf8Error },
     #[error("The wrapped response doesn't exist or is not longer valid")]
     WrapInvalidError,
 }
+
+impl ClientError {
+    /// Returns the HTTP status code of the response which caused the error, if
+    /// the server responded.
+    ///
+    /// Unlike matching on [ClientError::APIError], this also returns the code
+    /// of error responses without a body in the format of Vault, like those of
+    /// a load balancer in front of it.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            ClientError::APIError { code, .. } => Some(*code),
+            ClientError::RateLimited { .. } => Some(429),
+            ClientError::RestClientError {
+                source: rustify::errors::ClientError::ServerResponseError { code, .. },
+            } => Some(*code),
+            _ => None,
+        }
+    }
+}
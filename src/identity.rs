@@ -0,0 +1,649 @@
+pub mod entity {
+    use crate::{
+        api::{
+            self,
+            identity::{
+                requests::{
+                    CreateEntityByNameRequest, CreateEntityByNameRequestBuilder,
+                    CreateEntityRequest, CreateEntityRequestBuilder, DeleteEntityByNameRequest,
+                    DeleteEntityRequest, ListEntitiesByNameRequest, ListEntitiesRequest,
+                    MergeEntitiesRequest, MergeEntitiesRequestBuilder, ReadEntityByNameRequest,
+                    ReadEntityRequest, UpdateEntityRequest, UpdateEntityRequestBuilder,
+                },
+                responses::{CreateEntityResponse, ListEntitiesResponse, ReadEntityResponse},
+            },
+            ListFilter,
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Creates an entity.
+    ///
+    /// See [CreateEntityRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn create(
+        client: &impl Client,
+        opts: Option<&mut CreateEntityRequestBuilder>,
+    ) -> Result<CreateEntityResponse, ClientError> {
+        let mut t = CreateEntityRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Creates or updates an entity with the given name.
+    ///
+    /// See [CreateEntityByNameRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn create_by_name(
+        client: &impl Client,
+        name: &str,
+        opts: Option<&mut CreateEntityByNameRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = CreateEntityByNameRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).name(name).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Deletes an entity and all its aliases.
+    ///
+    /// See [DeleteEntityRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, id: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteEntityRequest::builder().id(id).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Deletes an entity and all its aliases by the entity name.
+    ///
+    /// See [DeleteEntityByNameRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete_by_name(client: &impl Client, name: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteEntityByNameRequest::builder()
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Lists entities by their identifiers.
+    ///
+    /// See [ListEntitiesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client) -> Result<ListEntitiesResponse, ClientError> {
+        let endpoint = ListEntitiesRequest::builder().build().unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Lists entities by their names.
+    ///
+    /// See [ListEntitiesByNameRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list_by_name(client: &impl Client) -> Result<ListEntitiesResponse, ClientError> {
+        let endpoint = ListEntitiesByNameRequest::builder().build().unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Lists the names of the entities which pass the given filter.
+    ///
+    /// An empty list is returned if there are no entities.
+    ///
+    /// See [ListEntitiesByNameRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list_by_name_filtered(
+        client: &impl Client,
+        filter: &ListFilter,
+    ) -> Result<Vec<String>, ClientError> {
+        let endpoint = ListEntitiesByNameRequest::builder().build().unwrap();
+        api::exec_with_filtered_list(client, endpoint, filter).await
+    }
+
+    /// Merges the given entities into the entity with the given identifier.
+    ///
+    /// Merging can't be undone: the entities being merged from are deleted
+    /// and their aliases are moved to the entity being merged into.
+    ///
+    /// See [MergeEntitiesRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn merge(
+        client: &impl Client,
+        from_entity_ids: Vec<String>,
+        to_entity_id: &str,
+        opts: Option<&mut MergeEntitiesRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = MergeEntitiesRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .from_entity_ids(from_entity_ids)
+            .to_entity_id(to_entity_id)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Reads an entity by its identifier.
+    ///
+    /// See [ReadEntityRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(client: &impl Client, id: &str) -> Result<ReadEntityResponse, ClientError> {
+        let endpoint = ReadEntityRequest::builder().id(id).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Reads an entity by its name.
+    ///
+    /// See [ReadEntityByNameRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read_by_name(
+        client: &impl Client,
+        name: &str,
+    ) -> Result<ReadEntityResponse, ClientError> {
+        let endpoint = ReadEntityByNameRequest::builder()
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Updates an entity by its identifier.
+    ///
+    /// See [UpdateEntityRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn update(
+        client: &impl Client,
+        id: &str,
+        opts: Option<&mut UpdateEntityRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = UpdateEntityRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).id(id).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+}
+
+pub mod entity_alias {
+    use crate::{
+        api::{
+            self,
+            identity::{
+                requests::{
+                    CreateEntityAliasRequest, CreateEntityAliasRequestBuilder,
+                    DeleteEntityAliasRequest, ListEntityAliasesRequest, ReadEntityAliasRequest,
+                    UpdateEntityAliasRequest, UpdateEntityAliasRequestBuilder,
+                },
+                responses::{
+                    CreateEntityAliasResponse, ListEntityAliasesResponse, ReadEntityAliasResponse,
+                },
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Creates an alias linking the given name on an auth mount to an entity.
+    ///
+    /// See [CreateEntityAliasRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn create(
+        client: &impl Client,
+        name: &str,
+        canonical_id: &str,
+        mount_accessor: &str,
+        opts: Option<&mut CreateEntityAliasRequestBuilder>,
+    ) -> Result<CreateEntityAliasResponse, ClientError> {
+        let mut t = CreateEntityAliasRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .name(name)
+            .canonical_id(canonical_id)
+            .mount_accessor(mount_accessor)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Deletes an entity alias by its identifier.
+    ///
+    /// See [DeleteEntityAliasRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, id: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteEntityAliasRequest::builder().id(id).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Lists entity aliases by their identifiers.
+    ///
+    /// See [ListEntityAliasesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client) -> Result<ListEntityAliasesResponse, ClientError> {
+        let endpoint = ListEntityAliasesRequest::builder().build().unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Reads an entity alias by its identifier.
+    ///
+    /// See [ReadEntityAliasRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        id: &str,
+    ) -> Result<ReadEntityAliasResponse, ClientError> {
+        let endpoint = ReadEntityAliasRequest::builder().id(id).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Updates an entity alias by its identifier.
+    ///
+    /// See [UpdateEntityAliasRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn update(
+        client: &impl Client,
+        id: &str,
+        opts: Option<&mut UpdateEntityAliasRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = UpdateEntityAliasRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).id(id).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+}
+
+/// Identity groups.
+///
+/// Internal groups have their members set through `member_entity_ids` and
+/// `member_group_ids`, while external groups get their members from an
+/// identity provider through a single [group alias][crate::identity::group_alias].
+/// Vault rejects setting members on an external group.
+pub mod group {
+    use crate::{
+        api::{
+            self,
+            identity::{
+                requests::{
+                    CreateGroupByNameRequest, CreateGroupByNameRequestBuilder, CreateGroupRequest,
+                    CreateGroupRequestBuilder, DeleteGroupByNameRequest, DeleteGroupRequest,
+                    ListGroupsByNameRequest, ListGroupsRequest, ReadGroupByNameRequest,
+                    ReadGroupRequest, UpdateGroupRequest, UpdateGroupRequestBuilder,
+                },
+                responses::{CreateGroupResponse, ListGroupsResponse, ReadGroupResponse},
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Creates a group.
+    ///
+    /// See [CreateGroupRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn create(
+        client: &impl Client,
+        opts: Option<&mut CreateGroupRequestBuilder>,
+    ) -> Result<CreateGroupResponse, ClientError> {
+        let mut t = CreateGroupRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Creates or updates a group with the given name.
+    ///
+    /// See [CreateGroupByNameRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn create_by_name(
+        client: &impl Client,
+        name: &str,
+        opts: Option<&mut CreateGroupByNameRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = CreateGroupByNameRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).name(name).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Deletes a group.
+    ///
+    /// See [DeleteGroupRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, id: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteGroupRequest::builder().id(id).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Deletes a group by its name.
+    ///
+    /// See [DeleteGroupByNameRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete_by_name(client: &impl Client, name: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteGroupByNameRequest::builder()
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Lists groups by their identifiers.
+    ///
+    /// See [ListGroupsRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client) -> Result<ListGroupsResponse, ClientError> {
+        let endpoint = ListGroupsRequest::builder().build().unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Lists groups by their names.
+    ///
+    /// See [ListGroupsByNameRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list_by_name(client: &impl Client) -> Result<ListGroupsResponse, ClientError> {
+        let endpoint = ListGroupsByNameRequest::builder().build().unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Reads a group by its identifier.
+    ///
+    /// See [ReadGroupRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(client: &impl Client, id: &str) -> Result<ReadGroupResponse, ClientError> {
+        let endpoint = ReadGroupRequest::builder().id(id).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Reads a group by its name.
+    ///
+    /// See [ReadGroupByNameRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read_by_name(
+        client: &impl Client,
+        name: &str,
+    ) -> Result<ReadGroupResponse, ClientError> {
+        let endpoint = ReadGroupByNameRequest::builder()
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Updates a group by its identifier.
+    ///
+    /// See [UpdateGroupRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn update(
+        client: &impl Client,
+        id: &str,
+        opts: Option<&mut UpdateGroupRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = UpdateGroupRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).id(id).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+}
+
+/// Group aliases, which map a group from an identity provider to an external
+/// [group][crate::identity::group].
+pub mod group_alias {
+    use crate::{
+        api::{
+            self,
+            identity::{
+                requests::{
+                    CreateGroupAliasRequest, CreateGroupAliasRequestBuilder,
+                    DeleteGroupAliasRequest, ListGroupAliasesRequest, ReadGroupAliasRequest,
+                    UpdateGroupAliasRequest, UpdateGroupAliasRequestBuilder,
+                },
+                responses::{
+                    CreateGroupAliasResponse, ListGroupAliasesResponse, ReadGroupAliasResponse,
+                },
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Creates an alias mapping the given group name on an auth mount to a
+    /// group.
+    ///
+    /// See [CreateGroupAliasRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn create(
+        client: &impl Client,
+        name: &str,
+        mount_accessor: &str,
+        opts: Option<&mut CreateGroupAliasRequestBuilder>,
+    ) -> Result<CreateGroupAliasResponse, ClientError> {
+        let mut t = CreateGroupAliasRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .name(name)
+            .mount_accessor(mount_accessor)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Deletes a group alias by its identifier.
+    ///
+    /// See [DeleteGroupAliasRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, id: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteGroupAliasRequest::builder().id(id).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Lists group aliases by their identifiers.
+    ///
+    /// See [ListGroupAliasesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client) -> Result<ListGroupAliasesResponse, ClientError> {
+        let endpoint = ListGroupAliasesRequest::builder().build().unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Reads a group alias by its identifier.
+    ///
+    /// See [ReadGroupAliasRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        id: &str,
+    ) -> Result<ReadGroupAliasResponse, ClientError> {
+        let endpoint = ReadGroupAliasRequest::builder().id(id).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Updates a group alias by its identifier.
+    ///
+    /// See [UpdateGroupAliasRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn update(
+        client: &impl Client,
+        id: &str,
+        name: &str,
+        mount_accessor: &str,
+        opts: Option<&mut UpdateGroupAliasRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = UpdateGroupAliasRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .id(id)
+            .name(name)
+            .mount_accessor(mount_accessor)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+}
+
+/// Vault as an OIDC provider, issuing identity tokens for entities.
+pub mod oidc {
+    use crate::{
+        api::{
+            self,
+            identity::{
+                requests::{GenerateSignedIdTokenRequest, ReadPublicKeysRequest},
+                responses::{GenerateSignedIdTokenResponse, ReadPublicKeysResponse},
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Generates a signed identity token for the entity of the client token
+    /// using the given role.
+    ///
+    /// See [GenerateSignedIdTokenRequest]
+    #[instrument(skip(client), err)]
+    pub async fn generate_token(
+        client: &impl Client,
+        role: &str,
+    ) -> Result<GenerateSignedIdTokenResponse, ClientError> {
+        let endpoint = GenerateSignedIdTokenRequest::builder()
+            .name(role)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Reads the public keys used to verify identity tokens.
+    ///
+    /// See [ReadPublicKeysRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read_keys(client: &impl Client) -> Result<ReadPublicKeysResponse, ClientError> {
+        let endpoint = ReadPublicKeysRequest::builder().build().unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+
+    pub mod key {
+        use crate::{
+            api::{
+                self,
+                identity::{
+                    requests::{
+                        CreateNamedKeyRequest, CreateNamedKeyRequestBuilder, DeleteNamedKeyRequest,
+                        ListNamedKeysRequest, ReadNamedKeyRequest, RotateNamedKeyRequest,
+                        RotateNamedKeyRequestBuilder,
+                    },
+                    responses::{ListNamedKeysResponse, ReadNamedKeyResponse},
+                },
+            },
+            client::Client,
+            error::ClientError,
+        };
+
+        /// Creates or updates a named key.
+        ///
+        /// See [CreateNamedKeyRequest]
+        #[instrument(skip(client, opts), err)]
+        pub async fn create(
+            client: &impl Client,
+            name: &str,
+            opts: Option<&mut CreateNamedKeyRequestBuilder>,
+        ) -> Result<(), ClientError> {
+            let mut t = CreateNamedKeyRequest::builder();
+            let endpoint = opts.unwrap_or(&mut t).name(name).build().unwrap();
+            api::exec_with_empty(client, endpoint).await
+        }
+
+        /// Deletes a named key.
+        ///
+        /// See [DeleteNamedKeyRequest]
+        #[instrument(skip(client), err)]
+        pub async fn delete(client: &impl Client, name: &str) -> Result<(), ClientError> {
+            let endpoint = DeleteNamedKeyRequest::builder().name(name).build().unwrap();
+            api::exec_with_empty(client, endpoint).await
+        }
+
+        /// Lists all named keys.
+        ///
+        /// See [ListNamedKeysRequest]
+        #[instrument(skip(client), err)]
+        pub async fn list(client: &impl Client) -> Result<ListNamedKeysResponse, ClientError> {
+            let endpoint = ListNamedKeysRequest::builder().build().unwrap();
+            api::exec_with_list_result(client, endpoint).await
+        }
+
+        /// Reads a named key.
+        ///
+        /// See [ReadNamedKeyRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read(
+            client: &impl Client,
+            name: &str,
+        ) -> Result<ReadNamedKeyResponse, ClientError> {
+            let endpoint = ReadNamedKeyRequest::builder().name(name).build().unwrap();
+            api::exec_with_result(client, endpoint).await
+        }
+
+        /// Rotates a named key.
+        ///
+        /// See [RotateNamedKeyRequest]
+        #[instrument(skip(client, opts), err)]
+        pub async fn rotate(
+            client: &impl Client,
+            name: &str,
+            opts: Option<&mut RotateNamedKeyRequestBuilder>,
+        ) -> Result<(), ClientError> {
+            let mut t = RotateNamedKeyRequest::builder();
+            let endpoint = opts.unwrap_or(&mut t).name(name).build().unwrap();
+            api::exec_with_empty(client, endpoint).await
+        }
+    }
+
+    pub mod role {
+        use crate::{
+            api::{
+                self,
+                identity::{
+                    requests::{
+                        CreateRoleRequest, CreateRoleRequestBuilder, DeleteRoleRequest,
+                        ListRolesRequest, ReadRoleRequest,
+                    },
+                    responses::{ListRolesResponse, ReadRoleResponse},
+                },
+            },
+            client::Client,
+            error::ClientError,
+        };
+
+        /// Creates or updates a role which signs tokens with the given key.
+        ///
+        /// See [CreateRoleRequest]
+        #[instrument(skip(client, opts), err)]
+        pub async fn create(
+            client: &impl Client,
+            name: &str,
+            key: &str,
+            opts: Option<&mut CreateRoleRequestBuilder>,
+        ) -> Result<(), ClientError> {
+            let mut t = CreateRoleRequest::builder();
+            let endpoint = opts.unwrap_or(&mut t).name(name).key(key).build().unwrap();
+            api::exec_with_empty(client, endpoint).await
+        }
+
+        /// Deletes a role.
+        ///
+        /// See [DeleteRoleRequest]
+        #[instrument(skip(client), err)]
+        pub async fn delete(client: &impl Client, name: &str) -> Result<(), ClientError> {
+            let endpoint = DeleteRoleRequest::builder().name(name).build().unwrap();
+            api::exec_with_empty(client, endpoint).await
+        }
+
+        /// Lists all roles.
+        ///
+        /// See [ListRolesRequest]
+        #[instrument(skip(client), err)]
+        pub async fn list(client: &impl Client) -> Result<ListRolesResponse, ClientError> {
+            let endpoint = ListRolesRequest::builder().build().unwrap();
+            api::exec_with_list_result(client, endpoint).await
+        }
+
+        /// Reads a role.
+        ///
+        /// See [ReadRoleRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read(
+            client: &impl Client,
+            name: &str,
+        ) -> Result<ReadRoleResponse, ClientError> {
+            let endpoint = ReadRoleRequest::builder().name(name).build().unwrap();
+            api::exec_with_result(client, endpoint).await
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! The KMIP secrets engine is only available in Vault Enterprise.
+//!
+//! Vault OSS can't mount the engine, so requests to it fail with a
+//! [ClientError::APIError][crate::error::ClientError::APIError] with a status
+//! code of 404, like requests to a mount which isn't enabled.
+//!
+//! To avoid this, the functions read the version of the server with
+//! [Client::server_version][crate::client::Client::server_version] before
+//! their first request and fail with a
+//! [ClientError::EnterpriseOnlyError][crate::error::ClientError::EnterpriseOnlyError]
+//! for servers which don't run Vault Enterprise, or with a
+//! [ClientError::UnsupportedFeature][crate::error::ClientError::UnsupportedFeature]
+//! for servers which are too old for the engine. The version is cached by the
+//! client.
+
+pub mod config {
+    use crate::api::kmip::{
+        requests::{
+            ReadConfigurationRequest, SetConfigurationRequest, SetConfigurationRequestBuilder,
+        },
+        responses::ReadConfigurationResponse,
+    };
+    use crate::api::{self, enterprise_only};
+    use crate::sys::Feature;
+    use crate::{client::Client, error::ClientError};
+
+    /// Reads the configuration of the KMIP server.
+    ///
+    /// See [ReadConfigurationRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        mount: &str,
+    ) -> Result<ReadConfigurationResponse, ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = ReadConfigurationRequest::builder()
+            .mount(mount)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Configures the KMIP server.
+    ///
+    /// See [SetConfigurationRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn set(
+        client: &impl Client,
+        mount: &str,
+        opts: Option<&mut SetConfigurationRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let mut t = SetConfigurationRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).mount(mount).build().unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+}
+
+pub mod scope {
+    use crate::api::kmip::{
+        requests::{CreateScopeRequest, DeleteScopeRequest, ListScopesRequest},
+        responses::ListScopesResponse,
+    };
+    use crate::api::{self, enterprise_only};
+    use crate::sys::Feature;
+    use crate::{client::Client, error::ClientError};
+
+    /// Creates a scope.
+    ///
+    /// See [CreateScopeRequest]
+    #[instrument(skip(client), err)]
+    pub async fn create(client: &impl Client, mount: &str, scope: &str) -> Result<(), ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = CreateScopeRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Deletes a scope, which must be `force`d if it still contains managed
+    /// objects.
+    ///
+    /// See [DeleteScopeRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        force: bool,
+    ) -> Result<(), ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = DeleteScopeRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .force(force)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Lists all scopes.
+    ///
+    /// See [ListScopesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(
+        client: &impl Client,
+        mount: &str,
+    ) -> Result<ListScopesResponse, ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = ListScopesRequest::builder().mount(mount).build().unwrap();
+        api::exec_with_list_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+}
+
+pub mod role {
+    use crate::api::kmip::{
+        requests::{
+            DeleteRoleRequest, ListRolesRequest, ReadRoleRequest, SetRoleRequest,
+            SetRoleRequestBuilder,
+        },
+        responses::{ListRolesResponse, ReadRoleResponse},
+    };
+    use crate::api::{self, enterprise_only};
+    use crate::sys::Feature;
+    use crate::{client::Client, error::ClientError};
+
+    /// Deletes a role.
+    ///
+    /// See [DeleteRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        name: &str,
+    ) -> Result<(), ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = DeleteRoleRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Lists the roles of a scope.
+    ///
+    /// See [ListRolesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+    ) -> Result<ListRolesResponse, ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = ListRolesRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .build()
+            .unwrap();
+        api::exec_with_list_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Reads a role.
+    ///
+    /// See [ReadRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        name: &str,
+    ) -> Result<ReadRoleResponse, ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = ReadRoleRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Creates or updates a role and the operations it grants.
+    ///
+    /// See [SetRoleRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn set(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        name: &str,
+        opts: Option<&mut SetRoleRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let mut t = SetRoleRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .mount(mount)
+            .scope(scope)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+}
+
+pub mod credential {
+    use crate::api::kmip::{
+        requests::{
+            GenerateCredentialRequest, ListCredentialsRequest, LookupCredentialRequest,
+            RevokeCredentialRequest,
+        },
+        responses::{
+            GenerateCredentialResponse, ListCredentialsResponse, LookupCredentialResponse,
+        },
+        CredentialFormat,
+    };
+    use crate::api::{self, enterprise_only};
+    use crate::sys::Feature;
+    use crate::{client::Client, error::ClientError};
+
+    /// Generates a client certificate and private key for a role.
+    ///
+    /// See [GenerateCredentialRequest]
+    #[instrument(skip(client), err)]
+    pub async fn generate(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        role: &str,
+        format: CredentialFormat,
+    ) -> Result<GenerateCredentialResponse, ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = GenerateCredentialRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .role(role)
+            .format(format)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Lists the serial numbers of the client certificates of a role.
+    ///
+    /// See [ListCredentialsRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        role: &str,
+    ) -> Result<ListCredentialsResponse, ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = ListCredentialsRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .role(role)
+            .build()
+            .unwrap();
+        api::exec_with_list_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Looks up the client certificate with the given serial number.
+    ///
+    /// See [LookupCredentialRequest]
+    #[instrument(skip(client), err)]
+    pub async fn lookup(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        role: &str,
+        serial_number: &str,
+    ) -> Result<LookupCredentialResponse, ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = LookupCredentialRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .role(role)
+            .serial_number(serial_number)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Revokes the client certificate with the given serial number.
+    ///
+    /// See [RevokeCredentialRequest]
+    #[instrument(skip(client), err)]
+    pub async fn revoke(
+        client: &impl Client,
+        mount: &str,
+        scope: &str,
+        role: &str,
+        serial_number: &str,
+    ) -> Result<(), ClientError> {
+        api::require(client, Feature::Kmip).await?;
+        let endpoint = RevokeCredentialRequest::builder()
+            .mount(mount)
+            .scope(scope)
+            .role(role)
+            .serial_number(serial_number)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+}
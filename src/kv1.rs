@@ -64,6 +64,18 @@ pub async fn get<D: DeserializeOwned>(
     serde_json::value::from_value(res.data).map_err(|e| ClientError::JsonParseError { source: e })
 }
 
+/// Get value of the secret at given path, returning `None` if it doesn't exist
+///
+/// See [get]
+#[instrument(skip(client), err)]
+pub async fn get_optional<D: DeserializeOwned>(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+) -> Result<Option<D>, ClientError> {
+    api::optional(get(client, mount, path).await)
+}
+
 /// Get value of the secret at given path, returning the raw response without deserialization
 /// Additional fields are available on raw response, such as lease_duration
 #[instrument(skip(client), err)]
@@ -81,8 +93,24 @@ pub async fn get_raw(
     api::exec_with_no_result(client, endpoint).await
 }
 
+/// Get the base64 encoded value stored under `key` in the secret at the given
+/// path and return it decoded as bytes.
+/// Returns a [ClientError::DecodeError] if the key is missing or isn't valid base64
+#[instrument(skip(client), err)]
+pub async fn read_bytes(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+    key: &str,
+) -> Result<Vec<u8>, ClientError> {
+    let res = get_raw(client, mount, path).await?;
+    api::decode_field(&res.data, key)
+}
+
 /// List secret keys at given location, returning raw server response
 ///
+/// An empty response is returned if there are no secrets at the given path.
+///
 /// See [ListSecretRequest]
 #[instrument(skip(client), err)]
 pub async fn list(
@@ -96,7 +124,7 @@ pub async fn list(
         .build()
         .unwrap();
 
-    api::exec_with_no_result(client, endpoint).await
+    api::optional(api::exec_with_no_result(client, endpoint).await).map(Option::unwrap_or_default)
 }
 
 /// Delete secret at given location
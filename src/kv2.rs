@@ -5,17 +5,127 @@ use crate::{
             requests::{
                 DeleteLatestSecretVersionRequest, DeleteSecretMetadataRequest,
                 DeleteSecretVersionsRequest, DestroySecretVersionsRequest, ListSecretsRequest,
-                ReadSecretMetadataRequest, ReadSecretRequest, SetSecretMetadataRequest,
+                PatchSecretRequest, ReadSecretMetadataRequest, ReadSecretRequest,
+                ReadSecretSubkeysRequest, SetSecretMetadataRequest,
                 SetSecretMetadataRequestBuilder, SetSecretRequest, SetSecretRequestOptions,
                 UndeleteSecretVersionsRequest,
             },
-            responses::{ReadSecretMetadataResponse, SecretVersionMetadata},
+            responses::{ReadSecretMetadataResponse, ReadSecretResponse, SecretVersionMetadata},
         },
+        EndpointResult, ListFilter,
     },
     client::Client,
     error::ClientError,
+    sys::Feature,
 };
+use futures_util::future::join_all;
 use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use tokio::sync::Semaphore;
+
+/// Copies the latest version of a secret to another path, which may be in
+/// another mount
+///
+/// Only the data of the secret is copied and written as a new version at the
+/// destination.
+///
+/// See [ReadSecretRequest] and [SetSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn copy(
+    client: &impl Client,
+    src_mount: &str,
+    src_path: &str,
+    dst_mount: &str,
+    dst_path: &str,
+) -> Result<SecretVersionMetadata, ClientError> {
+    let data: Value = read(client, src_mount, src_path).await?;
+    set(client, dst_mount, dst_path, &data).await
+}
+
+/// Copies the custom metadata of a secret to another path, which may be in
+/// another mount
+///
+/// The custom metadata at the destination is replaced, and left untouched if
+/// the source secret has none.
+///
+/// See [ReadSecretMetadataRequest] and [SetSecretMetadataRequest]
+#[instrument(skip(client), err)]
+pub async fn copy_custom_metadata(
+    client: &impl Client,
+    src_mount: &str,
+    src_path: &str,
+    dst_mount: &str,
+    dst_path: &str,
+) -> Result<(), ClientError> {
+    let metadata = read_metadata(client, src_mount, src_path).await?;
+    match metadata.custom_metadata {
+        Some(custom_metadata) => {
+            set_metadata(
+                client,
+                dst_mount,
+                dst_path,
+                Some(SetSecretMetadataRequest::builder().custom_metadata(custom_metadata)),
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// The outcome of copying the secrets below a path with [copy_tree]
+#[derive(Debug, Default)]
+pub struct CopyTreeReport {
+    /// The source paths of the secrets which were copied
+    pub copied: Vec<String>,
+    /// The source paths of the secrets which failed to copy, along with the
+    /// error
+    pub failed: Vec<(String, ClientError)>,
+}
+
+impl CopyTreeReport {
+    /// Returns whether all secrets were copied
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Copies all secrets below a path to another path, which may be in another
+/// mount, preserving their structure
+///
+/// The secrets are found with [list_recursive] and copied one by one with
+/// [copy], and with [copy_custom_metadata] if `preserve_metadata` is set. An
+/// error is only returned if listing the secrets fails: a secret which fails
+/// to copy doesn't stop the others from being copied, and is reported in
+/// [CopyTreeReport::failed] instead.
+#[instrument(skip(client), err)]
+pub async fn copy_tree(
+    client: &impl Client,
+    src_mount: &str,
+    src_path: &str,
+    dst_mount: &str,
+    dst_path: &str,
+    preserve_metadata: bool,
+) -> Result<CopyTreeReport, ClientError> {
+    let src_root = dir_path(src_path);
+    let dst_root = dir_path(dst_path);
+
+    let mut report = CopyTreeReport::default();
+    for path in list_recursive(client, src_mount, &src_root).await? {
+        let dst = format!("{}{}", dst_root, &path[src_root.len()..]);
+        let mut res = copy(client, src_mount, &path, dst_mount, &dst)
+            .await
+            .map(|_| ());
+        if res.is_ok() && preserve_metadata {
+            res = copy_custom_metadata(client, src_mount, &path, dst_mount, &dst).await;
+        }
+        match res {
+            Ok(()) => report.copied.push(path),
+            Err(e) => report.failed.push((path, e)),
+        }
+    }
+    Ok(report)
+}
 
 /// Soft-delete the latest version of a secret
 ///
@@ -36,6 +146,10 @@ pub async fn delete_latest(
 
 /// Delete all metadata and versions of a secret
 ///
+/// Unlike [destroy_versions], which permanently removes the data of the given
+/// versions, this removes the secret with its whole version history and
+/// metadata. This cannot be undone.
+///
 /// See [DeleteSecretMetadataRequest]
 #[instrument(skip(client), err)]
 pub async fn delete_metadata(
@@ -53,6 +167,9 @@ pub async fn delete_metadata(
 
 /// Soft-delete specific versions of a secret
 ///
+/// The data of the versions is kept and can be recovered with
+/// [undelete_versions].
+///
 /// See [DeleteSecretVersionsRequest]
 #[instrument(skip(client), err)]
 pub async fn delete_versions(
@@ -72,6 +189,9 @@ pub async fn delete_versions(
 
 /// Permanently delete specific versions of a secret
 ///
+/// This is irreversible, the data of destroyed versions is removed and can't
+/// be recovered with [undelete_versions].
+///
 /// See [DestroySecretVersionsRequest]
 #[instrument(skip(client), err)]
 pub async fn destroy_versions(
@@ -91,6 +211,8 @@ pub async fn destroy_versions(
 
 /// Lists all secret keys at the given path
 ///
+/// An empty list is returned if there are no secrets at the given path.
+///
 /// See [ListSecretsRequest]
 #[instrument(skip(client), err)]
 pub async fn list(
@@ -103,7 +225,110 @@ pub async fn list(
         .path(path)
         .build()
         .unwrap();
-    Ok(api::exec_with_result(client, endpoint).await?.keys)
+    api::exec_with_list(client, endpoint).await
+}
+
+/// Lists the secret keys at the given path which pass the given filter
+///
+/// See [ListSecretsRequest]
+#[instrument(skip(client), err)]
+pub async fn list_filtered(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+    filter: &ListFilter,
+) -> Result<Vec<String>, ClientError> {
+    let endpoint = ListSecretsRequest::builder()
+        .mount(mount)
+        .path(path)
+        .build()
+        .unwrap();
+    api::exec_with_filtered_list(client, endpoint, filter).await
+}
+
+/// The maximum number of nested paths walked by [list_recursive]
+pub const MAX_LIST_DEPTH: usize = 32;
+
+/// Lists the full paths of all secrets below the given path
+///
+/// The paths are walked depth first and each path is only listed once. A
+/// [ClientError::ListDepthExceeded] is returned for paths nested deeper than
+/// [MAX_LIST_DEPTH] below the given path.
+///
+/// See [ListSecretsRequest]
+#[instrument(skip(client), err)]
+pub async fn list_recursive(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+) -> Result<Vec<String>, ClientError> {
+    let root = dir_path(path);
+    let mut visited = HashSet::new();
+    let mut paths = Vec::new();
+    let mut stack = vec![(root, 0)];
+    while let Some((dir, depth)) = stack.pop() {
+        if !visited.insert(dir.clone()) {
+            continue;
+        }
+        if depth > MAX_LIST_DEPTH {
+            return Err(ClientError::ListDepthExceeded {
+                path: dir,
+                max_depth: MAX_LIST_DEPTH,
+            });
+        }
+
+        let mut dirs = Vec::new();
+        for key in list(client, mount, &dir).await? {
+            if key.trim_matches('/').is_empty() {
+                continue;
+            }
+            let full = format!("{}{}", dir, key);
+            if key.ends_with('/') {
+                dirs.push((full, depth + 1));
+            } else {
+                paths.push(full);
+            }
+        }
+        // Pushed in reverse so that the directories are walked in the listed order
+        stack.extend(dirs.into_iter().rev());
+    }
+    Ok(paths)
+}
+
+/// Normalizes a path to the form of the directories returned by [list], with a
+/// trailing slash and no leading one, the root of the mount being empty.
+fn dir_path(path: &str) -> String {
+    match path.trim_matches('/') {
+        "" => String::new(),
+        p => format!("{}/", p),
+    }
+}
+
+/// Patches the secret at the given path, merging the given data into its latest
+/// version
+///
+/// Servers older than Vault 1.9 return a [ClientError::UnsupportedFeature],
+/// see [crate::sys::server_version].
+///
+/// See [PatchSecretRequest]
+#[instrument(skip(client, data), err)]
+pub async fn patch<T: Serialize>(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+    data: &T,
+) -> Result<SecretVersionMetadata, ClientError> {
+    api::require(client, Feature::KvPatch).await?;
+    let data_value = data
+        .serialize(serde_json::value::Serializer)
+        .map_err(|e| ClientError::JsonParseError { source: e })?;
+    let endpoint = PatchSecretRequest::builder()
+        .mount(mount)
+        .path(path)
+        .data(data_value)
+        .build()
+        .unwrap();
+    api::exec_with_result(client, endpoint).await
 }
 
 /// Reads the value of the secret at the given path
@@ -124,8 +349,114 @@ pub async fn read<D: DeserializeOwned>(
     serde_json::value::from_value(res.data).map_err(|e| ClientError::JsonParseError { source: e })
 }
 
+/// Reads the secret at the given path, returning it along with the rest of the
+/// response, like any warnings returned by Vault
+///
+/// See [ReadSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn read_response(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+) -> Result<EndpointResult<ReadSecretResponse>, ClientError> {
+    let endpoint = ReadSecretRequest::builder()
+        .mount(mount)
+        .path(path)
+        .build()
+        .unwrap();
+    api::exec_with_lease(client, endpoint).await
+}
+
+/// Reads the value of the secret at the given path along with the metadata of
+/// its version
+///
+/// The latest version is read if no `version` is given.
+///
+/// See [ReadSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn read_with_metadata<D: DeserializeOwned>(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+    version: Option<u64>,
+) -> Result<(D, SecretVersionMetadata), ClientError> {
+    let endpoint = ReadSecretRequest::builder()
+        .mount(mount)
+        .path(path)
+        .version(version)
+        .build()
+        .unwrap();
+    let res = api::exec_with_result(client, endpoint).await?;
+    let data = serde_json::value::from_value(res.data)
+        .map_err(|e| ClientError::JsonParseError { source: e })?;
+    Ok((data, res.metadata))
+}
+
+/// Reads the value of the secret at the given path, returning `None` if it
+/// doesn't exist
+///
+/// See [ReadSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn read_optional<D: DeserializeOwned>(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+) -> Result<Option<D>, ClientError> {
+    api::optional(read(client, mount, path).await)
+}
+
+/// Reads the secrets at the given paths concurrently
+///
+/// At most `concurrency` reads are in flight at any time, or one if it's
+/// zero. The results are returned in the order of the given paths along with
+/// their path, and a read which fails doesn't affect the others.
+///
+/// See [ReadSecretRequest]
+#[instrument(skip(client))]
+pub async fn read_many<D: DeserializeOwned>(
+    client: &impl Client,
+    mount: &str,
+    paths: Vec<String>,
+    concurrency: usize,
+) -> Vec<(String, Result<D, ClientError>)> {
+    let semaphore = &Semaphore::new(concurrency.max(1));
+    let reads = paths.into_iter().map(|path| async move {
+        // The semaphore is never closed
+        let _permit = semaphore.acquire().await.unwrap();
+        let res = read(client, mount, &path).await;
+        (path, res)
+    });
+    join_all(reads).await
+}
+
+/// Reads the base64 encoded value stored under `key` in the secret at the given
+/// path and returns it decoded as bytes.
+///
+/// Returns a [ClientError::DecodeError] if the key is missing or isn't valid
+/// base64.
+///
+/// See [ReadSecretRequest]
+#[instrument(skip(client), err)]
+pub async fn read_bytes(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+    key: &str,
+) -> Result<Vec<u8>, ClientError> {
+    let endpoint = ReadSecretRequest::builder()
+        .mount(mount)
+        .path(path)
+        .build()
+        .unwrap();
+    let res = api::exec_with_result(client, endpoint).await?;
+    api::decode_field(&res.data, key)
+}
+
 /// Reads the metadata of the secret at the given path
 ///
+/// The metadata includes the history of all versions of the secret, along
+/// with whether each of them was deleted or destroyed.
+///
 /// See [ReadSecretMetadataRequest]
 #[instrument(skip(client), err)]
 pub async fn read_metadata(
@@ -186,6 +517,9 @@ pub async fn set<T: Serialize>(
 /// Sets the value of the secret at the given path
 /// including an argument for [SetSecretRequestOptions]
 ///
+/// If the `cas` option doesn't match the current version of the secret, the
+/// write is rejected with a [ClientError::CheckAndSetFailed].
+///
 /// See [SetSecretRequest]
 #[instrument(skip(client, data), err)]
 pub async fn set_with_options<T: Serialize>(
@@ -205,7 +539,18 @@ pub async fn set_with_options<T: Serialize>(
         .options(options)
         .build()
         .unwrap();
-    api::exec_with_result(client, endpoint).await
+    api::exec_with_result(client, endpoint)
+        .await
+        .map_err(|e| match e {
+            ClientError::APIError { code: 400, errors }
+                if errors
+                    .iter()
+                    .any(|e| e.contains("did not match the current version")) =>
+            {
+                ClientError::CheckAndSetFailed
+            }
+            e => e,
+        })
 }
 
 /// Sets the value of the secret at the given path
@@ -228,6 +573,33 @@ pub async fn set_metadata(
     api::exec_with_empty(client, endpoint).await
 }
 
+/// Reads the structure of the secret at the given path, with the values of all
+/// leaf keys replaced by `null`
+///
+/// A `depth` of zero or `None` returns all nested keys. Servers older than Vault
+/// 1.10 return a [ClientError::UnsupportedFeature], see
+/// [crate::sys::server_version].
+///
+/// See [ReadSecretSubkeysRequest]
+#[instrument(skip(client), err)]
+pub async fn subkeys(
+    client: &impl Client,
+    mount: &str,
+    path: &str,
+    version: Option<u64>,
+    depth: Option<u64>,
+) -> Result<Value, ClientError> {
+    api::require(client, Feature::KvSubkeys).await?;
+    let endpoint = ReadSecretSubkeysRequest::builder()
+        .mount(mount)
+        .path(path)
+        .version(version)
+        .depth(depth)
+        .build()
+        .unwrap();
+    Ok(api::exec_with_result(client, endpoint).await?.subkeys)
+}
+
 /// Undelete specific versions of a secret
 ///
 /// See [UndeleteSecretVersionsRequest]
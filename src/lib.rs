@@ -7,18 +7,35 @@
 //! * Auth
 //!   * [AppleRole](https://www.vaultproject.io/docs/auth/approle)
 //!   * [AWS](https://www.vaultproject.io/docs/auth/aws)
+//!   * [Azure](https://www.vaultproject.io/docs/auth/azure)
+//!   * [TLS Certificates](https://www.vaultproject.io/docs/auth/cert)
+//!   * [GCP](https://www.vaultproject.io/docs/auth/gcp)
 //!   * [JWT/OIDC](https://www.vaultproject.io/api-docs/auth/jwt)
+//!   * [LDAP](https://www.vaultproject.io/docs/auth/ldap)
 //!   * [Token](https://www.vaultproject.io/docs/auth/token)
 //!   * [Userpass](https://www.vaultproject.io/docs/auth/userpass)
+//! * Identity
+//!   * [Entities](https://www.vaultproject.io/api-docs/secret/identity/entity)
+//!   * [Entity Aliases](https://www.vaultproject.io/api-docs/secret/identity/entity-alias)
+//!   * [Groups](https://www.vaultproject.io/api-docs/secret/identity/group)
+//!   * [Group Aliases](https://www.vaultproject.io/api-docs/secret/identity/group-alias)
+//!   * [Identity Tokens](https://developer.hashicorp.com/vault/docs/secrets/identity/identity-token)
 //! * Secrets
+//!   * [AWS](https://www.vaultproject.io/docs/secrets/aws)
+//!   * [Cubbyhole](https://www.vaultproject.io/docs/secrets/cubbyhole)
 //!   * [Databases](https://www.vaultproject.io/api-docs/secret/databases)
 //!   * [KV v2](https://www.vaultproject.io/docs/secrets/kv/kv-v2)
 //!   * [PKI](https://www.vaultproject.io/docs/secrets/pki)
 //!   * [SSH](https://www.vaultproject.io/docs/secrets/ssh)
+//!   * [TOTP](https://www.vaultproject.io/docs/secrets/totp)
+//!   * [Transform](https://developer.hashicorp.com/vault/docs/secrets/transform) (Enterprise)
 //!   * [Transit](https://www.vaultproject.io/api-docs/secret/transit)
 //! * Sys
 //!   * [Health](https://www.vaultproject.io/api-docs/system/health)
+//!   * [Metrics](https://www.vaultproject.io/api-docs/system/metrics)
+//!   * [Plugins](https://www.vaultproject.io/api-docs/system/plugins-catalog)
 //!   * [Policies](https://www.vaultproject.io/api-docs/system/policy)
+//!   * [Raft Storage](https://www.vaultproject.io/api-docs/system/storage/raft)
 //!   * [Sealing](https://www.vaultproject.io/api-docs/system/seal)
 //!   * [Wrapping](https://www.vaultproject.io/docs/concepts/response-wrapping)
 //!
@@ -198,13 +215,21 @@ extern crate tracing;
 
 pub mod api;
 pub mod auth;
+pub mod aws;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod cubbyhole;
 pub mod database;
 pub mod error;
+pub mod identity;
+pub mod kmip;
 pub mod kv1;
 pub mod kv2;
 pub mod pki;
 pub mod ssh;
 pub mod sys;
 pub mod token;
+pub mod totp;
+pub mod transform;
 pub mod transit;
@@ -2,11 +2,14 @@ pub mod cert {
     use crate::api;
     use crate::api::pki::requests::{
         GenerateCertificateRequest, GenerateCertificateRequestBuilder, ListCertificatesRequest,
-        ReadCertificateRequest, RevokeCertificateRequest, TidyRequest,
+        ReadCertificateRequest, RevokeCertificateRequest, RevokeCertificateWithKeyRequest,
+        TidyRequest, TidyRequestBuilder, TidyStatusRequest,
     };
     use crate::api::pki::responses::{
         GenerateCertificateResponse, ReadCertificateResponse, RevokeCertificateResponse,
+        TidyStatusResponse,
     };
+    use crate::api::EndpointResult;
     use crate::client::Client;
     use crate::error::ClientError;
 
@@ -30,7 +33,31 @@ pub mod cert {
         api::exec_with_result(client, endpoint).await
     }
 
-    /// Lists all certificates
+    /// Generates a certificate using the given role and options, returning it
+    /// along with the rest of the response
+    ///
+    /// Vault adds a warning to the response when the requested TTL exceeds the
+    /// maximum TTL of the role and is capped.
+    ///
+    /// See [GenerateCertificateRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn generate_response(
+        client: &impl Client,
+        mount: &str,
+        role: &str,
+        opts: Option<&mut GenerateCertificateRequestBuilder>,
+    ) -> Result<EndpointResult<GenerateCertificateResponse>, ClientError> {
+        let mut t = GenerateCertificateRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .mount(mount)
+            .role(role)
+            .build()
+            .unwrap();
+        api::exec_with_lease(client, endpoint).await
+    }
+
+    /// Lists all certificates, returning an empty list if there are none
     ///
     /// See [ListCertificatesRequest]
     #[instrument(skip(client), err)]
@@ -39,7 +66,7 @@ pub mod cert {
             .mount(mount)
             .build()
             .unwrap();
-        Ok(api::exec_with_result(client, endpoint).await?.keys)
+        api::exec_with_list(client, endpoint).await
     }
 
     /// Read a certificate using its serial
@@ -76,15 +103,58 @@ pub mod cert {
         api::exec_with_result(client, endpoint).await
     }
 
+    /// Revokes a certificate, proving the authorization to do so with its
+    /// private key
+    ///
+    /// Both the certificate and the private key are PEM encoded. This requires
+    /// Vault 1.12 or later.
+    ///
+    /// See [RevokeCertificateWithKeyRequest]
+    #[instrument(skip(client, private_key), err)]
+    pub async fn revoke_with_key(
+        client: &impl Client,
+        mount: &str,
+        certificate: &str,
+        private_key: &str,
+    ) -> Result<RevokeCertificateResponse, ClientError> {
+        let endpoint = RevokeCertificateWithKeyRequest::builder()
+            .mount(mount)
+            .certificate(certificate)
+            .private_key(private_key)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
     /// Tidy's up the certificate backend
     ///
+    /// The operation runs in the background once Vault has accepted it. Use
+    /// [tidy_status] to monitor its progress.
+    ///
     /// See [TidyRequest]
-    #[instrument(skip(client), err)]
-    pub async fn tidy(client: &impl Client, mount: &str) -> Result<(), ClientError> {
-        let endpoint = TidyRequest::builder().mount(mount).build().unwrap();
+    #[instrument(skip(client, opts), err)]
+    pub async fn tidy(
+        client: &impl Client,
+        mount: &str,
+        opts: Option<&mut TidyRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = TidyRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).mount(mount).build().unwrap();
         api::exec_with_empty_result(client, endpoint).await
     }
 
+    /// Reads the status of the current or last tidy operation
+    ///
+    /// See [TidyStatusRequest]
+    #[instrument(skip(client), err)]
+    pub async fn tidy_status(
+        client: &impl Client,
+        mount: &str,
+    ) -> Result<TidyStatusResponse, ClientError> {
+        let endpoint = TidyStatusRequest::builder().mount(mount).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
     pub mod ca {
         use crate::api;
         use crate::api::pki::responses::SignSelfIssuedResponse;
@@ -92,6 +162,7 @@ pub mod cert {
             api::pki::{
                 requests::{
                     DeleteRootRequest, GenerateRootRequest, GenerateRootRequestBuilder,
+                    ReadCACertificatePemRequest, ReadCACertificateRequest, ReadCAChainRequest,
                     SignCertificateRequest, SignCertificateRequestBuilder, SignIntermediateRequest,
                     SignIntermediateRequestBuilder, SignSelfIssuedRequest, SubmitCARequest,
                 },
@@ -132,6 +203,39 @@ pub mod cert {
             api::exec_with_result(client, endpoint).await
         }
 
+        /// Reads the CA certificate as DER encoded bytes
+        ///
+        /// See [ReadCACertificateRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read(client: &impl Client, mount: &str) -> Result<Vec<u8>, ClientError> {
+            let endpoint = ReadCACertificateRequest::builder()
+                .mount(mount)
+                .build()
+                .unwrap();
+            Ok(api::exec_with_raw(client, endpoint).await?.body.to_vec())
+        }
+
+        /// Reads the CA certificate chain, including the CA, in PEM format
+        ///
+        /// See [ReadCAChainRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read_chain(client: &impl Client, mount: &str) -> Result<String, ClientError> {
+            let endpoint = ReadCAChainRequest::builder().mount(mount).build().unwrap();
+            api::exec_with_raw(client, endpoint).await?.text()
+        }
+
+        /// Reads the CA certificate in PEM format
+        ///
+        /// See [ReadCACertificatePemRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read_pem(client: &impl Client, mount: &str) -> Result<String, ClientError> {
+            let endpoint = ReadCACertificatePemRequest::builder()
+                .mount(mount)
+                .build()
+                .unwrap();
+            api::exec_with_raw(client, endpoint).await?.text()
+        }
+
         /// Signs a certificate using the root CA
         ///
         /// See [SignCertificateRequest]
@@ -212,6 +316,11 @@ pub mod cert {
             api::exec_with_empty(client, endpoint).await
         }
 
+        /// Intermediate CAs, which are set up in three steps:
+        ///
+        /// 1. [generate][int::generate] a CSR on the intermediate mount
+        /// 2. Sign it on the parent mount with [sign_intermediate]
+        /// 3. Install the signed certificate with [set_signed][int::set_signed]
         pub mod int {
             use crate::api;
             use crate::{
@@ -270,8 +379,8 @@ pub mod cert {
     pub mod crl {
         use crate::api::pki::{
             requests::{
-                ReadCRLConfigRequest, RotateCRLsRequest, SetCRLConfigRequest,
-                SetCRLConfigRequestBuilder,
+                ReadCRLConfigRequest, ReadCRLPemRequest, ReadCRLRequest, RotateCRLsRequest,
+                SetCRLConfigRequest, SetCRLConfigRequestBuilder,
             },
             responses::{ReadCRLConfigResponse, RotateCRLsResponse},
         };
@@ -291,6 +400,24 @@ pub mod cert {
             api::exec_with_result(client, endpoint).await
         }
 
+        /// Reads the current CRL as DER encoded bytes
+        ///
+        /// See [ReadCRLRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read(client: &impl Client, mount: &str) -> Result<Vec<u8>, ClientError> {
+            let endpoint = ReadCRLRequest::builder().mount(mount).build().unwrap();
+            Ok(api::exec_with_raw(client, endpoint).await?.body.to_vec())
+        }
+
+        /// Reads the current CRL in PEM format
+        ///
+        /// See [ReadCRLPemRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read_pem(client: &impl Client, mount: &str) -> Result<String, ClientError> {
+            let endpoint = ReadCRLPemRequest::builder().mount(mount).build().unwrap();
+            api::exec_with_raw(client, endpoint).await?.text()
+        }
+
         /// Reads the CRL configuration
         ///
         /// See [ReadCRLConfigRequest]
@@ -389,7 +516,7 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
         let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Reads a role
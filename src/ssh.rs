@@ -1,11 +1,16 @@
-use crate::api;
 use crate::api::ssh::requests::{GenerateSSHCredsRequest, VerifySSHOTPRequest};
 use crate::api::ssh::responses::{GenerateSSHCredsResponse, VerifySSHOTPResponse};
+use crate::api::{self, LeasedSecret};
 use crate::client::Client;
 use crate::error::ClientError;
 
 /// Generates SSH credentials for the given role
 ///
+/// For OTP roles the returned `key` is the one-time password. The `ip` must
+/// fall within the role's CIDR list, otherwise Vault rejects the request with
+/// a [ClientError::APIError]. The lease of the credentials is returned along
+/// with them.
+///
 /// See [GenerateSSHCredsRequest]
 #[instrument(skip(client), err)]
 pub async fn generate(
@@ -14,16 +19,13 @@ pub async fn generate(
     name: &str,
     ip: &str,
     username: Option<String>,
-) -> Result<GenerateSSHCredsResponse, ClientError> {
+) -> Result<LeasedSecret<GenerateSSHCredsResponse>, ClientError> {
     let mut endpoint = GenerateSSHCredsRequest::builder();
     if let Some(u) = username {
         endpoint.username(u);
     }
-    api::exec_with_result(
-        client,
-        endpoint.mount(mount).name(name).ip(ip).build().unwrap(),
-    )
-    .await
+    let endpoint = endpoint.mount(mount).name(name).ip(ip).build().unwrap();
+    api::exec_with_leased_secret(client, endpoint).await
 }
 
 /// Verify SSH OTP details
@@ -209,7 +211,7 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
         let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Lists all roles by IP
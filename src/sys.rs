@@ -2,8 +2,16 @@ use crate::{
     api::{
         self,
         sys::{
-            requests::{ReadHealthRequest, SealRequest, UnsealRequest},
-            responses::{ReadHealthResponse, UnsealResponse},
+            requests::{
+                InitRequest, InitRequestBuilder, ReadHealthRequest, ReadInitStatusRequest,
+                ReadLeaderRequest, ReadMetricsRequest, ReadSealStatusRequest, SealRequest,
+                UnsealRequest,
+            },
+            responses::{
+                InitResponse, ReadHealthResponse, ReadLeaderResponse, ReadMetricsResponse,
+                ReadSealStatusResponse, UnsealResponse,
+            },
+            MetricsFormat,
         },
     },
     client::Client,
@@ -22,15 +30,254 @@ pub enum ServerStatus {
     UNKNOWN,
 }
 
+/// The health of a Vault server, as returned by [health_status].
+///
+/// This is meant for readiness and liveness probes: every state the server
+/// reports through the health endpoint is a variant, and only a failure to
+/// read the health at all is [VaultHealth::Unreachable].
+#[derive(Debug)]
+pub enum VaultHealth {
+    /// The server is unsealed and the active node of its cluster
+    Active,
+    /// The server is unsealed and a standby node forwarding requests to the
+    /// active node
+    Standby,
+    /// The server is unsealed and a performance standby node serving reads
+    /// (Enterprise)
+    PerformanceStandby,
+    /// The server is initialized but sealed
+    Sealed,
+    /// The server has not been initialized
+    Uninitialized,
+    /// The server is a disaster recovery secondary (Enterprise)
+    Recovery,
+    /// The health couldn't be read, for example because the server can't be
+    /// connected to or returned an unexpected response
+    Unreachable(ClientError),
+}
+
+impl VaultHealth {
+    fn from_response(health: &ReadHealthResponse) -> Self {
+        if !health.initialized {
+            VaultHealth::Uninitialized
+        } else if health.sealed {
+            VaultHealth::Sealed
+        } else if health.replication_dr_mode.as_deref() == Some("secondary") {
+            VaultHealth::Recovery
+        } else if health.performance_standby {
+            VaultHealth::PerformanceStandby
+        } else if health.standby {
+            VaultHealth::Standby
+        } else {
+            VaultHealth::Active
+        }
+    }
+}
+
+/// A feature which is only available in Vault Enterprise or in recent
+/// versions of Vault.
+///
+/// See [ServerVersion::supports].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feature {
+    /// The KMIP secrets engine (Enterprise)
+    Kmip,
+    /// Partially updating KV v2 secrets with [kv2::patch][crate::kv2::patch]
+    KvPatch,
+    /// Reading the structure of KV v2 secrets with
+    /// [kv2::subkeys][crate::kv2::subkeys]
+    KvSubkeys,
+    /// Role and endpoint governing policies (Enterprise)
+    SentinelPolicies,
+    /// The Transform secrets engine (Enterprise)
+    Transform,
+}
+
+impl Feature {
+    /// Returns whether the feature requires Vault Enterprise and the first
+    /// version of Vault supporting it.
+    fn requirements(&self) -> (bool, (u64, u64, u64)) {
+        match self {
+            Feature::Kmip => (true, (1, 2, 0)),
+            Feature::KvPatch => (false, (1, 9, 0)),
+            Feature::KvSubkeys => (false, (1, 10, 0)),
+            Feature::SentinelPolicies => (true, (0, 0, 0)),
+            Feature::Transform => (true, (1, 4, 0)),
+        }
+    }
+
+    /// Returns whether the feature requires Vault Enterprise.
+    pub(crate) fn requires_enterprise(&self) -> bool {
+        self.requirements().0
+    }
+}
+
+/// The version of a Vault server, as returned by [server_version].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// Whether the server runs Vault Enterprise
+    pub enterprise: bool,
+    /// The version as reported by the server, like `1.10.3+ent`
+    pub raw: String,
+}
+
+impl ServerVersion {
+    /// Parses a version reported by Vault, like `1.10.3`, `1.13.0-rc1` or
+    /// `1.12.2+ent.hsm`.
+    pub fn parse(version: &str) -> Option<Self> {
+        let (release, metadata) = match version.split_once('+') {
+            Some((release, metadata)) => (release, Some(metadata)),
+            None => (version, None),
+        };
+        let release = release.split('-').next()?;
+        let mut parts = release.trim_start_matches('v').splitn(3, '.');
+        let mut next = || parts.next()?.parse().ok();
+        Some(ServerVersion {
+            major: next()?,
+            minor: next()?,
+            patch: next()?,
+            enterprise: metadata.is_some_and(|m| m.split('.').any(|m| m == "ent")),
+            raw: version.to_string(),
+        })
+    }
+
+    /// Returns whether the server supports the given feature.
+    pub fn supports(&self, feature: Feature) -> bool {
+        let (enterprise, min_version) = feature.requirements();
+        (self.enterprise || !enterprise) && (self.major, self.minor, self.patch) >= min_version
+    }
+}
+
+/// The telemetry metrics of the Vault server in the requested format.
+#[derive(Debug)]
+pub enum Metrics {
+    /// The gauges, counters and samples of the current interval
+    Json(ReadMetricsResponse),
+    /// The Prometheus text exposition as returned by the server
+    Prometheus(String),
+}
+
+/// The status codes used by the health endpoint to signal the state of the
+/// Vault server.
+const HEALTH_STATUS_CODES: [u16; 5] = [429, 472, 473, 501, 503];
+
 /// Returns health information about the Vault server.
 ///
+/// The health endpoint signals the state of the server through its status
+/// code (i.e. 429 for a standby node or 503 for a sealed server). These are
+/// not treated as errors, the state is available through the fields of the
+/// returned response instead.
+///
 /// See [ReadHealthRequest]
 #[instrument(skip(client), err)]
 pub async fn health(client: &impl Client) -> Result<ReadHealthResponse, ClientError> {
     let endpoint = ReadHealthRequest::builder().build().unwrap();
+    api::exec_with_status_codes(client, endpoint, &HEALTH_STATUS_CODES).await
+}
+
+/// Returns the health of the Vault server as a [VaultHealth].
+///
+/// Sealed, standby and uninitialized servers are reported as such rather
+/// than as errors. Any error reading the health is returned as
+/// [VaultHealth::Unreachable].
+///
+/// See [ReadHealthRequest]
+#[instrument(skip(client))]
+pub async fn health_status(client: &impl Client) -> VaultHealth {
+    match health(client).await {
+        Ok(health) => VaultHealth::from_response(&health),
+        Err(e) => VaultHealth::Unreachable(e),
+    }
+}
+
+/// Returns the version of the Vault server.
+///
+/// The version is read once and cached by the client, including clients
+/// created from it with [Client::with_headers]. Use [refresh_server_version]
+/// to read it again, for example after the server was upgraded.
+#[instrument(skip(client), err)]
+pub async fn server_version(client: &impl Client) -> Result<ServerVersion, ClientError> {
+    let cached = client.middle().server_version.lock().unwrap().clone();
+    match cached {
+        Some(version) => Ok(version),
+        None => refresh_server_version(client).await,
+    }
+}
+
+/// Reads the version of the Vault server and replaces the one cached by the
+/// client.
+///
+/// The version is read from the health endpoint, falling back to the seal
+/// status for servers which don't allow reading their health.
+#[instrument(skip(client), err)]
+pub async fn refresh_server_version(client: &impl Client) -> Result<ServerVersion, ClientError> {
+    let (path, version) = match health(client).await {
+        Ok(health) => ("sys/health", health.version),
+        Err(_) => ("sys/seal-status", seal_status(client).await?.version),
+    };
+    let version =
+        ServerVersion::parse(&version).ok_or_else(|| ClientError::DeserializationError {
+            path: path.to_string(),
+            message: format!("invalid server version: {}", version),
+            body: None,
+        })?;
+    *client.middle().server_version.lock().unwrap() = Some(version.clone());
+    Ok(version)
+}
+
+/// Initializes a new Vault server, returning the unseal keys and the initial
+/// root token.
+///
+/// The keys are only returned once, so they must be stored securely by the
+/// caller. Initializing a server which is already initialized fails with a
+/// [ClientError::APIError] with a status code of 400.
+///
+/// See [InitRequest]
+#[instrument(skip(client, opts), err)]
+pub async fn init(
+    client: &impl Client,
+    secret_shares: u64,
+    secret_threshold: u64,
+    opts: Option<&mut InitRequestBuilder>,
+) -> Result<InitResponse, ClientError> {
+    let mut t = InitRequest::builder();
+    let endpoint = opts
+        .unwrap_or(&mut t)
+        .secret_shares(secret_shares)
+        .secret_threshold(secret_threshold)
+        .build()
+        .unwrap();
     api::exec_with_no_result(client, endpoint).await
 }
 
+/// Returns whether the Vault server has been initialized.
+///
+/// See [ReadInitStatusRequest]
+#[instrument(skip(client), err)]
+pub async fn init_status(client: &impl Client) -> Result<bool, ClientError> {
+    let endpoint = ReadInitStatusRequest::builder().build().unwrap();
+    let res = api::exec_with_no_result(client, endpoint).await?;
+    Ok(res.initialized)
+}
+
+/// Returns the high availability status and the current leader of the Vault
+/// server, for example to find the active node which handles writes.
+///
+/// Like the seal status, the leader is readable without a valid token, so the
+/// request is sent without one.
+///
+/// See [ReadLeaderRequest]
+#[instrument(skip(client), err)]
+pub async fn leader(client: &impl Client) -> Result<ReadLeaderResponse, ClientError> {
+    let endpoint = ReadLeaderRequest::builder().build().unwrap();
+    let mut client = client.with_headers(http::HeaderMap::new());
+    client.set_token("");
+    api::exec_with_no_result(&client, endpoint).await
+}
+
 /// Seals the Vault server.
 ///
 /// See [SealRequest]
@@ -40,10 +287,48 @@ pub async fn seal(client: &impl Client) -> Result<(), ClientError> {
     api::exec_with_empty(client, endpoint).await
 }
 
-/// Unseals the Vault server.
+/// Returns the telemetry metrics of the Vault server in the given format.
 ///
-/// See [UnsealRequest]
+/// The Prometheus format is returned as is for passing it on to a scraper.
+///
+/// See [ReadMetricsRequest]
+#[instrument(skip(client), err)]
+pub async fn metrics(client: &impl Client, format: MetricsFormat) -> Result<Metrics, ClientError> {
+    let endpoint = ReadMetricsRequest::builder()
+        .format(format)
+        .build()
+        .unwrap();
+    match format {
+        MetricsFormat::Json => Ok(Metrics::Json(
+            api::exec_with_no_result(client, endpoint).await?,
+        )),
+        MetricsFormat::Prometheus => {
+            let res = api::exec_with_raw(client, endpoint).await?;
+            Ok(Metrics::Prometheus(res.text()?))
+        }
+    }
+}
+
+/// Returns the seal status of the Vault server.
+///
+/// The seal status is readable without a valid token, so the request is sent
+/// without one. This allows checking the status of a server which was just
+/// started or whose token was revoked.
+///
+/// See [ReadSealStatusRequest]
 #[instrument(skip(client), err)]
+pub async fn seal_status(client: &impl Client) -> Result<ReadSealStatusResponse, ClientError> {
+    let endpoint = ReadSealStatusRequest::builder().build().unwrap();
+    let mut client = client.with_headers(http::HeaderMap::new());
+    client.set_token("");
+    api::exec_with_no_result(&client, endpoint).await
+}
+
+/// Submits a single unseal key share, returning the progress of unsealing the
+/// Vault server. Passing `reset` discards the key shares submitted so far.
+///
+/// See [UnsealRequest]
+#[instrument(skip(client, key), err)]
 pub async fn unseal(
     client: &impl Client,
     key: Option<String>,
@@ -64,35 +349,89 @@ pub async fn unseal(
 /// See [ReadHealthRequest]
 #[instrument(skip(client), err)]
 pub async fn status(client: &impl Client) -> Result<ServerStatus, ClientError> {
-    let result = health(client).await;
-    match result {
-        Ok(_) => Ok(ServerStatus::OK),
-        Err(e) => match e {
-            ClientError::RestClientError { source } => match source {
-                rustify::errors::ClientError::ServerResponseError {
-                    code: 429,
-                    content: _,
-                } => Ok(ServerStatus::STANDBY),
-                rustify::errors::ClientError::ServerResponseError {
-                    code: 472,
-                    content: _,
-                } => Ok(ServerStatus::RECOVERY),
-                rustify::errors::ClientError::ServerResponseError {
-                    code: 473,
-                    content: _,
-                } => Ok(ServerStatus::PERFSTANDBY),
-                rustify::errors::ClientError::ServerResponseError {
-                    code: 501,
-                    content: _,
-                } => Ok(ServerStatus::UNINITIALIZED),
-                rustify::errors::ClientError::ServerResponseError {
-                    code: 503,
-                    content: _,
-                } => Ok(ServerStatus::SEALED),
-                _ => Err(ClientError::RestClientError { source }),
-            },
-            _ => Err(e),
-        },
+    let health = health(client).await?;
+    Ok(match VaultHealth::from_response(&health) {
+        VaultHealth::Active => ServerStatus::OK,
+        VaultHealth::Standby => ServerStatus::STANDBY,
+        VaultHealth::PerformanceStandby => ServerStatus::PERFSTANDBY,
+        VaultHealth::Sealed => ServerStatus::SEALED,
+        VaultHealth::Uninitialized => ServerStatus::UNINITIALIZED,
+        VaultHealth::Recovery => ServerStatus::RECOVERY,
+        VaultHealth::Unreachable(_) => ServerStatus::UNKNOWN,
+    })
+}
+
+pub mod audit {
+    use std::collections::HashMap;
+
+    use crate::api;
+    use crate::api::sys::requests::{
+        AuditHashRequest, DisableAuditRequest, EnableAuditRequest, EnableAuditRequestBuilder,
+        ListAuditsRequest,
+    };
+    use crate::api::sys::responses::AuditDeviceResponse;
+    use crate::client::Client;
+    use crate::error::ClientError;
+
+    /// Disables the audit device at the given path
+    ///
+    /// See [DisableAuditRequest]
+    #[instrument(skip(client), err)]
+    pub async fn disable(client: &impl Client, path: &str) -> Result<(), ClientError> {
+        let endpoint = DisableAuditRequest::builder().path(path).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Enables an audit device at the given path
+    ///
+    /// The options supported depend on the type of device, for example a
+    /// `file` device requires a `file_path` option.
+    ///
+    /// See [EnableAuditRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn enable(
+        client: &impl Client,
+        path: &str,
+        device_type: &str,
+        opts: Option<&mut EnableAuditRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = EnableAuditRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .path(path)
+            .device_type(device_type)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Hashes the given input using the salt of the audit device at the given
+    /// path, as it would appear in the audit log
+    ///
+    /// See [AuditHashRequest]
+    #[instrument(skip(client, input), err)]
+    pub async fn hash(
+        client: &impl Client,
+        path: &str,
+        input: &str,
+    ) -> Result<String, ClientError> {
+        let endpoint = AuditHashRequest::builder()
+            .path(path)
+            .input(input)
+            .build()
+            .unwrap();
+        Ok(api::exec_with_result(client, endpoint).await?.hash)
+    }
+
+    /// Lists all enabled audit devices
+    ///
+    /// See [ListAuditsRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(
+        client: &impl Client,
+    ) -> Result<HashMap<String, AuditDeviceResponse>, ClientError> {
+        let endpoint = ListAuditsRequest::builder().build().unwrap();
+        api::exec_with_result(client, endpoint).await
     }
 }
 
@@ -101,12 +440,44 @@ pub mod auth {
 
     use crate::api;
     use crate::api::sys::requests::{
-        EnableAuthRequest, EnableAuthRequestBuilder, ListAuthsRequest,
+        DisableAuthRequest, EnableAuthRequest, EnableAuthRequestBuilder, ListAuthsRequest,
+        ReadAuthTuneRequest, TuneAuthRequest, TuneAuthRequestBuilder,
     };
-    use crate::api::sys::responses::AuthResponse;
+    use crate::api::sys::responses::{AuthResponse, ReadAuthTuneResponse};
     use crate::client::Client;
     use crate::error::ClientError;
 
+    /// Returns the accessor of the auth engine mounted at the given path, like
+    /// `auth_approle_1a2b3c`, which is used by entity aliases and templated
+    /// policies to reference the engine.
+    ///
+    /// Returns a [ClientError::MountNotFoundError] if no auth engine is
+    /// mounted at the path.
+    ///
+    /// See [ListAuthsRequest]
+    #[instrument(skip(client), err)]
+    pub async fn accessor(client: &impl Client, path: &str) -> Result<String, ClientError> {
+        let key = format!("{}/", path.trim_matches('/'));
+        list(client)
+            .await?
+            .remove(&key)
+            .map(|a| a.accessor)
+            .ok_or_else(|| ClientError::MountNotFoundError {
+                path: path.to_string(),
+            })
+    }
+
+    /// Disables the auth engine at the given path
+    ///
+    /// All tokens issued by the auth engine are revoked.
+    ///
+    /// See [DisableAuthRequest]
+    #[instrument(skip(client), err)]
+    pub async fn disable(client: &impl Client, path: &str) -> Result<(), ClientError> {
+        let endpoint = DisableAuthRequest::builder().path(path).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
     /// Enables an auth engine at the given path
     ///
     /// See [EnableAuthRequest]
@@ -135,6 +506,280 @@ pub mod auth {
         let endpoint = ListAuthsRequest::builder().build().unwrap();
         api::exec_with_result(client, endpoint).await
     }
+
+    /// Reads the configuration of the auth engine at the given path
+    ///
+    /// See [ReadAuthTuneRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read_tune(
+        client: &impl Client,
+        path: &str,
+    ) -> Result<ReadAuthTuneResponse, ClientError> {
+        let endpoint = ReadAuthTuneRequest::builder().path(path).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Tunes the configuration of the auth engine at the given path
+    ///
+    /// See [TuneAuthRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn tune(
+        client: &impl Client,
+        path: &str,
+        opts: Option<&mut TuneAuthRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = TuneAuthRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).path(path).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+}
+
+pub mod capabilities {
+    use std::collections::HashMap;
+
+    use crate::{
+        api::{
+            self,
+            sys::requests::{CapabilitiesRequest, CapabilitiesSelfRequest},
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Returns the capabilities of the given token on each of the given paths.
+    ///
+    /// See [CapabilitiesRequest]
+    #[instrument(skip(client, token), err)]
+    pub async fn lookup(
+        client: &impl Client,
+        token: &str,
+        paths: Vec<String>,
+    ) -> Result<HashMap<String, Vec<String>>, ClientError> {
+        let endpoint = CapabilitiesRequest::builder()
+            .token(token)
+            .paths(paths.clone())
+            .build()
+            .unwrap();
+        let res = api::exec_with_result(client, endpoint).await?;
+        Ok(filter(res.capabilities, &paths))
+    }
+
+    /// Returns the capabilities of the client token on each of the given
+    /// paths.
+    ///
+    /// See [CapabilitiesSelfRequest]
+    #[instrument(skip(client), err)]
+    pub async fn self_(
+        client: &impl Client,
+        paths: Vec<String>,
+    ) -> Result<HashMap<String, Vec<String>>, ClientError> {
+        let endpoint = CapabilitiesSelfRequest::builder()
+            .paths(paths.clone())
+            .build()
+            .unwrap();
+        let res = api::exec_with_result(client, endpoint).await?;
+        Ok(filter(res.capabilities, &paths))
+    }
+
+    /// Removes the capabilities returned for paths which weren't requested.
+    fn filter(
+        mut capabilities: HashMap<String, Vec<String>>,
+        paths: &[String],
+    ) -> HashMap<String, Vec<String>> {
+        capabilities.retain(|k, _| paths.contains(k));
+        capabilities
+    }
+}
+
+/// Generating a new root token from a quorum of unseal key shares.
+///
+/// A generation attempt is [started][generate_root::start], which returns the
+/// `nonce` of the attempt and the OTP the new token is encoded with. Each key
+/// holder then submits their share with the nonce through
+/// [update][generate_root::update] until the `required` number of shares is
+/// reached, at which point the attempt is `complete` and the `encoded_token`
+/// is returned. It is decoded with the OTP using
+/// [decode_token][generate_root::decode_token]. The attempt can be
+/// [canceled][generate_root::cancel] at any time, which discards the submitted
+/// shares.
+pub mod generate_root {
+    use crate::{
+        api::{
+            self,
+            sys::{
+                requests::{
+                    CancelGenerateRootRequest, ReadGenerateRootStatusRequest,
+                    StartGenerateRootRequest, StartGenerateRootRequestBuilder,
+                    UpdateGenerateRootRequest,
+                },
+                responses::GenerateRootStatusResponse,
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Cancels the current root generation attempt.
+    ///
+    /// See [CancelGenerateRootRequest]
+    #[instrument(skip(client), err)]
+    pub async fn cancel(client: &impl Client) -> Result<(), ClientError> {
+        let endpoint = CancelGenerateRootRequest::builder().build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Decodes the `encoded_token` of a completed attempt using the OTP which
+    /// was returned when [starting][start] the attempt.
+    ///
+    /// Returns a [ClientError::DecodeError] if the token isn't valid base64
+    /// or wasn't encoded with the given OTP. Tokens encoded with a PGP key
+    /// must be decrypted with the private key instead.
+    pub fn decode_token(encoded_token: &str, otp: &str) -> Result<String, ClientError> {
+        let err = |source| ClientError::DecodeError {
+            source,
+            key: "encoded_token".to_string(),
+        };
+        let encoded =
+            base64::decode_config(encoded_token.trim_end_matches('='), base64::STANDARD_NO_PAD)
+                .map_err(|e| err(Some(e)))?;
+        if encoded.len() != otp.len() {
+            return Err(err(None));
+        }
+        let token = encoded
+            .iter()
+            .zip(otp.as_bytes())
+            .map(|(a, b)| a ^ b)
+            .collect();
+        String::from_utf8(token).map_err(|_| err(None))
+    }
+
+    /// Reads the progress of the current root generation attempt.
+    ///
+    /// See [ReadGenerateRootStatusRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(client: &impl Client) -> Result<GenerateRootStatusResponse, ClientError> {
+        let endpoint = ReadGenerateRootStatusRequest::builder().build().unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+
+    /// Starts a new root generation attempt, returning its `nonce` and the
+    /// OTP which the new token is encoded with. A PGP key for encrypting the
+    /// token instead can be passed through the options.
+    ///
+    /// See [StartGenerateRootRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn start(
+        client: &impl Client,
+        opts: Option<&mut StartGenerateRootRequestBuilder>,
+    ) -> Result<GenerateRootStatusResponse, ClientError> {
+        let mut t = StartGenerateRootRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).build().unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+
+    /// Submits a single unseal key share for the attempt with the given
+    /// nonce, returning the progress of the attempt.
+    ///
+    /// See [UpdateGenerateRootRequest]
+    #[instrument(skip(client, key), err)]
+    pub async fn update(
+        client: &impl Client,
+        key: &str,
+        nonce: &str,
+    ) -> Result<GenerateRootStatusResponse, ClientError> {
+        let endpoint = UpdateGenerateRootRequest::builder()
+            .key(key)
+            .nonce(nonce)
+            .build()
+            .unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+}
+
+pub mod leases {
+    use crate::{
+        api::{
+            self,
+            sys::{
+                requests::{
+                    ListLeasesRequest, ReadLeaseRequest, RenewLeaseRequest, RevokeLeaseRequest,
+                    RevokePrefixRequest,
+                },
+                responses::{ReadLeaseResponse, RenewLeaseResponse},
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Lists the lease ids under the given prefix, returning an empty list if
+    /// there are none.
+    ///
+    /// See [ListLeasesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client, prefix: &str) -> Result<Vec<String>, ClientError> {
+        let endpoint = ListLeasesRequest::builder().prefix(prefix).build().unwrap();
+        api::exec_with_list(client, endpoint).await
+    }
+
+    /// Reads the metadata of the given lease.
+    ///
+    /// See [ReadLeaseRequest]
+    #[instrument(skip(client), err)]
+    pub async fn lookup(
+        client: &impl Client,
+        lease_id: &str,
+    ) -> Result<ReadLeaseResponse, ClientError> {
+        let endpoint = ReadLeaseRequest::builder()
+            .lease_id(lease_id)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Renews the given lease, optionally requesting a new TTL.
+    ///
+    /// See [RenewLeaseRequest]
+    #[instrument(skip(client), err)]
+    pub async fn renew(
+        client: &impl Client,
+        lease_id: &str,
+        increment: Option<&str>,
+    ) -> Result<RenewLeaseResponse, ClientError> {
+        let mut endpoint = RenewLeaseRequest::builder();
+        if let Some(inc) = increment {
+            endpoint.increment(inc);
+        }
+        api::exec_with_no_result(client, endpoint.lease_id(lease_id).build().unwrap()).await
+    }
+
+    /// Revokes the given lease immediately.
+    ///
+    /// See [RevokeLeaseRequest]
+    #[instrument(skip(client), err)]
+    pub async fn revoke(client: &impl Client, lease_id: &str) -> Result<(), ClientError> {
+        let endpoint = RevokeLeaseRequest::builder()
+            .lease_id(lease_id)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Revokes all leases under the given prefix immediately.
+    ///
+    /// **Warning**: this revokes every secret and token generated under the
+    /// prefix, for example all credentials issued by a secrets engine when
+    /// given its mount path. Revoked leases can't be restored.
+    ///
+    /// See [RevokePrefixRequest]
+    #[instrument(skip(client), err)]
+    pub async fn revoke_prefix(client: &impl Client, prefix: &str) -> Result<(), ClientError> {
+        let endpoint = RevokePrefixRequest::builder()
+            .prefix(prefix)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
 }
 
 pub mod mount {
@@ -142,14 +787,18 @@ pub mod mount {
 
     use crate::api;
     use crate::api::sys::requests::{
-        EnableEngineRequest, EnableEngineRequestBuilder, ListMountsRequest,
+        EnableEngineRequest, EnableEngineRequestBuilder, ListMountsRequest, ReadMountTuneRequest,
+        TuneMountRequest, TuneMountRequestBuilder,
     };
-    use crate::api::sys::responses::MountResponse;
+    use crate::api::sys::responses::{MountResponse, ReadMountTuneResponse};
     use crate::client::Client;
     use crate::error::ClientError;
 
     /// Enables a secret engine at the given path
     ///
+    /// Engine specific options are passed through the `options` of the
+    /// request, like `"version" => "2"` for mounting a KV v2 engine.
+    ///
     /// See [EnableEngineRequest]
     #[instrument(skip(client, opts), err)]
     pub async fn enable(
@@ -176,6 +825,327 @@ pub mod mount {
         let endpoint = ListMountsRequest::builder().build().unwrap();
         api::exec_with_result(client, endpoint).await
     }
+
+    /// Reads the configuration of the secret engine at the given path
+    ///
+    /// See [ReadMountTuneRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read_tune(
+        client: &impl Client,
+        path: &str,
+    ) -> Result<ReadMountTuneResponse, ClientError> {
+        let endpoint = ReadMountTuneRequest::builder().path(path).build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Tunes the configuration of the secret engine at the given path
+    ///
+    /// See [TuneMountRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn tune(
+        client: &impl Client,
+        path: &str,
+        opts: Option<&mut TuneMountRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = TuneMountRequest::builder();
+        let endpoint = opts.unwrap_or(&mut t).path(path).build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+}
+
+pub mod plugins {
+    use crate::api;
+    use crate::api::sys::requests::{
+        DeregisterPluginRequest, ListPluginsRequest, ReadPluginRequest, RegisterPluginRequest,
+        RegisterPluginRequestBuilder, ReloadPluginsRequest,
+    };
+    use crate::api::sys::responses::{ListPluginsResponse, ReadPluginResponse};
+    use crate::api::sys::{PluginReloadTarget, PluginType};
+    use crate::client::Client;
+    use crate::error::ClientError;
+
+    /// Removes the plugin with the given type and name from the catalog
+    ///
+    /// See [DeregisterPluginRequest]
+    #[instrument(skip(client), err)]
+    pub async fn deregister(
+        client: &impl Client,
+        plugin_type: PluginType,
+        name: &str,
+    ) -> Result<(), ClientError> {
+        let endpoint = DeregisterPluginRequest::builder()
+            .plugin_type(plugin_type)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Lists the plugins in the catalog by type
+    ///
+    /// See [ListPluginsRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client) -> Result<ListPluginsResponse, ClientError> {
+        let endpoint = ListPluginsRequest::builder().build().unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Reads the plugin with the given type and name from the catalog
+    ///
+    /// See [ReadPluginRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        plugin_type: PluginType,
+        name: &str,
+    ) -> Result<ReadPluginResponse, ClientError> {
+        let endpoint = ReadPluginRequest::builder()
+            .plugin_type(plugin_type)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Registers a plugin in the catalog, where `command` is the name of its
+    /// binary in the plugin directory of the server. The arguments and
+    /// environment of the command are passed through the options.
+    ///
+    /// Vault refuses to register the plugin if the SHA-256 checksum of the
+    /// binary doesn't match the given `sha256`, which is returned as a
+    /// [ClientError::PluginChecksumMismatch].
+    ///
+    /// See [RegisterPluginRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn register(
+        client: &impl Client,
+        plugin_type: PluginType,
+        name: &str,
+        sha256: &str,
+        command: &str,
+        opts: Option<&mut RegisterPluginRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = RegisterPluginRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .plugin_type(plugin_type)
+            .name(name)
+            .sha256(sha256)
+            .command(command)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(|e| match e {
+                ClientError::APIError { code: 400, errors }
+                    if errors.iter().any(|e| e.contains("checksums did not match")) =>
+                {
+                    ClientError::PluginChecksumMismatch {
+                        name: name.to_string(),
+                    }
+                }
+                e => e,
+            })
+    }
+
+    /// Reloads all mounts using the given plugin or the given mounts
+    ///
+    /// See [ReloadPluginsRequest]
+    #[instrument(skip(client), err)]
+    pub async fn reload(
+        client: &impl Client,
+        target: PluginReloadTarget,
+    ) -> Result<(), ClientError> {
+        let mut endpoint = ReloadPluginsRequest::builder();
+        match target {
+            PluginReloadTarget::Plugin(plugin) => endpoint.plugin(plugin),
+            PluginReloadTarget::Mounts(mounts) => endpoint.mounts(mounts),
+        };
+        api::exec_with_empty(client, endpoint.build().unwrap()).await
+    }
+}
+
+/// Sentinel policies, which are only available in Vault Enterprise.
+///
+/// Vault OSS doesn't know these endpoints, so the functions return a
+/// [ClientError::EnterpriseOnlyError] when used against it. If the version of
+/// the server, as read and cached by [server_version] before the first
+/// request, shows that it isn't Vault Enterprise, they fail the same way
+/// without sending the request.
+pub mod policies {
+    pub mod egp {
+        use crate::{
+            api::{
+                self,
+                sys::{
+                    requests::{
+                        CreateEGPPolicyRequest, DeleteEGPPolicyRequest, ListEGPPoliciesRequest,
+                        ReadEGPPolicyRequest,
+                    },
+                    responses::{ListEGPPoliciesResponse, ReadEGPPolicyResponse},
+                    EnforcementLevel,
+                },
+            },
+            client::Client,
+            error::ClientError,
+        };
+
+        use crate::api::enterprise_only;
+        use crate::sys::Feature;
+
+        /// Deletes the given EGP policy.
+        ///
+        /// See [DeleteEGPPolicyRequest]
+        #[instrument(skip(client), err)]
+        pub async fn delete(client: &impl Client, name: &str) -> Result<(), ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = DeleteEGPPolicyRequest::builder()
+                .name(name)
+                .build()
+                .unwrap();
+            api::exec_with_empty(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+
+        /// Lists all configured EGP policies.
+        ///
+        /// See [ListEGPPoliciesRequest]
+        #[instrument(skip(client), err)]
+        pub async fn list(client: &impl Client) -> Result<ListEGPPoliciesResponse, ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = ListEGPPoliciesRequest::builder().build().unwrap();
+            api::exec_with_list_result(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+
+        /// Reads the given EGP policy.
+        ///
+        /// See [ReadEGPPolicyRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read(
+            client: &impl Client,
+            name: &str,
+        ) -> Result<ReadEGPPolicyResponse, ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = ReadEGPPolicyRequest::builder().name(name).build().unwrap();
+            api::exec_with_result(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+
+        /// Creates or updates the given EGP policy from its Sentinel policy
+        /// document, enforcement level and the paths it applies to.
+        ///
+        /// See [CreateEGPPolicyRequest]
+        #[instrument(skip(client, policy), err)]
+        pub async fn set(
+            client: &impl Client,
+            name: &str,
+            policy: &str,
+            enforcement_level: EnforcementLevel,
+            paths: Vec<String>,
+        ) -> Result<(), ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = CreateEGPPolicyRequest::builder()
+                .name(name)
+                .policy(policy)
+                .enforcement_level(enforcement_level)
+                .paths(paths)
+                .build()
+                .unwrap();
+            api::exec_with_empty(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+    }
+
+    pub mod rgp {
+        use crate::{
+            api::{
+                self,
+                sys::{
+                    requests::{
+                        CreateRGPPolicyRequest, DeleteRGPPolicyRequest, ListRGPPoliciesRequest,
+                        ReadRGPPolicyRequest,
+                    },
+                    responses::{ListRGPPoliciesResponse, ReadRGPPolicyResponse},
+                    EnforcementLevel,
+                },
+            },
+            client::Client,
+            error::ClientError,
+        };
+
+        use crate::api::enterprise_only;
+        use crate::sys::Feature;
+
+        /// Deletes the given RGP policy.
+        ///
+        /// See [DeleteRGPPolicyRequest]
+        #[instrument(skip(client), err)]
+        pub async fn delete(client: &impl Client, name: &str) -> Result<(), ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = DeleteRGPPolicyRequest::builder()
+                .name(name)
+                .build()
+                .unwrap();
+            api::exec_with_empty(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+
+        /// Lists all configured RGP policies.
+        ///
+        /// See [ListRGPPoliciesRequest]
+        #[instrument(skip(client), err)]
+        pub async fn list(client: &impl Client) -> Result<ListRGPPoliciesResponse, ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = ListRGPPoliciesRequest::builder().build().unwrap();
+            api::exec_with_list_result(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+
+        /// Reads the given RGP policy.
+        ///
+        /// See [ReadRGPPolicyRequest]
+        #[instrument(skip(client), err)]
+        pub async fn read(
+            client: &impl Client,
+            name: &str,
+        ) -> Result<ReadRGPPolicyResponse, ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = ReadRGPPolicyRequest::builder().name(name).build().unwrap();
+            api::exec_with_result(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+
+        /// Creates or updates the given RGP policy from its Sentinel policy
+        /// document, enforcement level.
+        ///
+        /// See [CreateRGPPolicyRequest]
+        #[instrument(skip(client, policy), err)]
+        pub async fn set(
+            client: &impl Client,
+            name: &str,
+            policy: &str,
+            enforcement_level: EnforcementLevel,
+        ) -> Result<(), ClientError> {
+            api::require(client, Feature::SentinelPolicies).await?;
+            let endpoint = CreateRGPPolicyRequest::builder()
+                .name(name)
+                .policy(policy)
+                .enforcement_level(enforcement_level)
+                .build()
+                .unwrap();
+            api::exec_with_empty(client, endpoint)
+                .await
+                .map_err(enterprise_only)
+        }
+    }
 }
 
 pub mod policy {
@@ -194,7 +1164,7 @@ pub mod policy {
         error::ClientError,
     };
 
-    /// Deletes the given policy.
+    /// Deletes the given ACL policy.
     ///
     /// See [DeletePolicyRequest]
     #[instrument(skip(client), err)]
@@ -203,16 +1173,17 @@ pub mod policy {
         api::exec_with_empty(client, endpoint).await
     }
 
-    /// Lists all configured policies.
+    /// Lists all configured ACL policies.
     ///
     /// See [ListPoliciesRequest]
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client) -> Result<ListPoliciesResponse, ClientError> {
         let endpoint = ListPoliciesRequest::builder().build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
-    /// Reads the given policy.
+    /// Reads the given ACL policy. The raw policy document is returned in
+    /// the `rules` field.
     ///
     /// See [ReadPolicyRequest]
     #[instrument(skip(client), err)]
@@ -221,7 +1192,8 @@ pub mod policy {
         api::exec_with_result(client, endpoint).await
     }
 
-    /// Sets the given policy.
+    /// Creates or updates the given ACL policy from an HCL or JSON policy
+    /// document.
     ///
     /// See [CreatePolicyRequest]
     #[instrument(skip(client), err)]
@@ -235,16 +1207,268 @@ pub mod policy {
     }
 }
 
+/// Rekeying the unseal key shares of the Vault server.
+///
+/// A rekey is [initialized][rekey::init] with the number of new shares and
+/// their threshold, which returns the `nonce` of the rekey. Each holder of a
+/// current key share then submits it with the nonce through
+/// [update][rekey::update] until the `required` number of shares is reached,
+/// at which point the rekey is `complete` and the new key shares are returned.
+///
+/// If verification is required, the new shares only take effect once the
+/// threshold of them has been submitted through [verify][rekey::verify] with
+/// the `verification_nonce`, which ensures they were received correctly.
+/// Until then the old shares remain valid. The rekey can be
+/// [canceled][rekey::cancel] at any time before it takes effect.
+pub mod rekey {
+    use crate::{
+        api::{
+            self,
+            sys::{
+                requests::{
+                    CancelRekeyRequest, ReadRekeyStatusRequest, StartRekeyRequest,
+                    StartRekeyRequestBuilder, UpdateRekeyRequest, VerifyRekeyRequest,
+                },
+                responses::{RekeyStatusResponse, RekeyUpdateResponse, RekeyVerifyResponse},
+            },
+        },
+        client::Client,
+        error::ClientError,
+    };
+
+    /// Cancels the current rekey.
+    ///
+    /// See [CancelRekeyRequest]
+    #[instrument(skip(client), err)]
+    pub async fn cancel(client: &impl Client) -> Result<(), ClientError> {
+        let endpoint = CancelRekeyRequest::builder().build().unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Initializes a new rekey which splits the new key into the given number
+    /// of shares, of which `secret_threshold` are needed for unsealing.
+    ///
+    /// See [StartRekeyRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn init(
+        client: &impl Client,
+        secret_shares: u64,
+        secret_threshold: u64,
+        opts: Option<&mut StartRekeyRequestBuilder>,
+    ) -> Result<RekeyStatusResponse, ClientError> {
+        let mut t = StartRekeyRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .secret_shares(secret_shares)
+            .secret_threshold(secret_threshold)
+            .build()
+            .unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+
+    /// Reads the progress of the current rekey.
+    ///
+    /// See [ReadRekeyStatusRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(client: &impl Client) -> Result<RekeyStatusResponse, ClientError> {
+        let endpoint = ReadRekeyStatusRequest::builder().build().unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+
+    /// Submits a single current unseal key share for the rekey with the
+    /// given nonce, returning the new key shares once complete.
+    ///
+    /// See [UpdateRekeyRequest]
+    #[instrument(skip(client, key), err)]
+    pub async fn update(
+        client: &impl Client,
+        key: &str,
+        nonce: &str,
+    ) -> Result<RekeyUpdateResponse, ClientError> {
+        let endpoint = UpdateRekeyRequest::builder()
+            .key(key)
+            .nonce(nonce)
+            .build()
+            .unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+
+    /// Submits a single new unseal key share for verifying the rekey with the
+    /// given verification nonce.
+    ///
+    /// See [VerifyRekeyRequest]
+    #[instrument(skip(client, key), err)]
+    pub async fn verify(
+        client: &impl Client,
+        key: &str,
+        nonce: &str,
+    ) -> Result<RekeyVerifyResponse, ClientError> {
+        let endpoint = VerifyRekeyRequest::builder()
+            .key(key)
+            .nonce(nonce)
+            .build()
+            .unwrap();
+        api::exec_with_no_result(client, endpoint).await
+    }
+}
+
+pub mod storage {
+    /// Operations on the integrated storage (Raft) of a Vault cluster
+    pub mod raft {
+        use crate::{
+            api::{
+                self,
+                sys::{
+                    requests::{
+                        ReadRaftAutopilotConfigurationRequest, ReadRaftAutopilotStateRequest,
+                        ReadRaftConfigurationRequest, RemoveRaftPeerRequest,
+                        SetRaftAutopilotConfigurationRequest,
+                        SetRaftAutopilotConfigurationRequestBuilder,
+                    },
+                    responses::{
+                        ReadRaftAutopilotConfigurationResponse, ReadRaftAutopilotStateResponse,
+                        ReadRaftConfigurationResponse,
+                    },
+                },
+            },
+            client::Client,
+            error::ClientError,
+        };
+
+        /// Reads the configuration of autopilot.
+        ///
+        /// See [ReadRaftAutopilotConfigurationRequest]
+        #[instrument(skip(client), err)]
+        pub async fn autopilot_configuration(
+            client: &impl Client,
+        ) -> Result<ReadRaftAutopilotConfigurationResponse, ClientError> {
+            let endpoint = ReadRaftAutopilotConfigurationRequest::builder()
+                .build()
+                .unwrap();
+            api::exec_with_result(client, endpoint).await
+        }
+
+        /// Reads the state of the cluster as seen by autopilot, including the
+        /// health of each server.
+        ///
+        /// See [ReadRaftAutopilotStateRequest]
+        #[instrument(skip(client), err)]
+        pub async fn autopilot_state(
+            client: &impl Client,
+        ) -> Result<ReadRaftAutopilotStateResponse, ClientError> {
+            let endpoint = ReadRaftAutopilotStateRequest::builder().build().unwrap();
+            api::exec_with_result(client, endpoint).await
+        }
+
+        /// Lists the peers of the cluster.
+        ///
+        /// See [ReadRaftConfigurationRequest]
+        #[instrument(skip(client), err)]
+        pub async fn configuration(
+            client: &impl Client,
+        ) -> Result<ReadRaftConfigurationResponse, ClientError> {
+            let endpoint = ReadRaftConfigurationRequest::builder().build().unwrap();
+            api::exec_with_result(client, endpoint).await
+        }
+
+        /// Removes the peer with the given node ID from the cluster.
+        ///
+        /// See [RemoveRaftPeerRequest]
+        #[instrument(skip(client), err)]
+        pub async fn remove_peer(client: &impl Client, server_id: &str) -> Result<(), ClientError> {
+            let endpoint = RemoveRaftPeerRequest::builder()
+                .server_id(server_id)
+                .build()
+                .unwrap();
+            api::exec_with_empty(client, endpoint).await
+        }
+
+        /// Updates the configuration of autopilot.
+        ///
+        /// See [SetRaftAutopilotConfigurationRequest]
+        #[instrument(skip(client, opts), err)]
+        pub async fn set_autopilot_configuration(
+            client: &impl Client,
+            opts: Option<&mut SetRaftAutopilotConfigurationRequestBuilder>,
+        ) -> Result<(), ClientError> {
+            let mut t = SetRaftAutopilotConfigurationRequest::builder();
+            let endpoint = opts.unwrap_or(&mut t).build().unwrap();
+            api::exec_with_empty(client, endpoint).await
+        }
+
+        /// Saving and restoring snapshots of the cluster. Snapshots are gzip
+        /// compressed archives which are held in memory as a whole.
+        pub mod snapshot {
+            use crate::{
+                api::{
+                    self,
+                    sys::requests::{
+                        RestoreRaftSnapshotForceRequest, RestoreRaftSnapshotRequest,
+                        SaveRaftSnapshotRequest,
+                    },
+                },
+                client::Client,
+                error::ClientError,
+            };
+
+            /// Restores the cluster from a snapshot returned by [save].
+            ///
+            /// Passing `force` installs the snapshot even if it was taken on a
+            /// cluster with a different keyring, like when restoring it on a
+            /// new cluster.
+            ///
+            /// The request isn't streamed: the whole snapshot is held in memory,
+            /// and copied once more while it's sent.
+            ///
+            /// See [RestoreRaftSnapshotRequest] and
+            /// [RestoreRaftSnapshotForceRequest]
+            #[instrument(skip(client, snapshot), err)]
+            pub async fn restore(
+                client: &impl Client,
+                snapshot: &[u8],
+                force: bool,
+            ) -> Result<(), ClientError> {
+                if force {
+                    let endpoint = RestoreRaftSnapshotForceRequest::builder()
+                        .snapshot(snapshot.to_vec())
+                        .build()
+                        .unwrap();
+                    api::exec_with_empty(client, endpoint).await
+                } else {
+                    let endpoint = RestoreRaftSnapshotRequest::builder()
+                        .snapshot(snapshot.to_vec())
+                        .build()
+                        .unwrap();
+                    api::exec_with_empty(client, endpoint).await
+                }
+            }
+
+            /// Takes a snapshot of the cluster.
+            ///
+            /// The response isn't streamed: the whole snapshot is held in memory
+            /// before it's returned.
+            ///
+            /// See [SaveRaftSnapshotRequest]
+            #[instrument(skip(client), err)]
+            pub async fn save(client: &impl Client) -> Result<Vec<u8>, ClientError> {
+                let endpoint = SaveRaftSnapshotRequest::builder().build().unwrap();
+                Ok(api::exec_with_raw(client, endpoint).await?.body.to_vec())
+            }
+        }
+    }
+}
+
 pub mod wrapping {
-    use serde::de::DeserializeOwned;
+    use serde::{de::DeserializeOwned, Serialize};
 
     use crate::{
         api::{
             self,
             sys::{
-                requests::{UnwrapRequest, WrappingLookupRequest},
+                requests::{RewrapRequest, UnwrapRequest, WrapRequest, WrappingLookupRequest},
                 responses::WrappingLookupResponse,
             },
+            WrapInfo,
         },
         client::Client,
         error::ClientError,
@@ -253,7 +1477,7 @@ pub mod wrapping {
     /// Looks up information about a token wrapping response
     ///
     /// See [WrappingLookupResponse]
-    #[instrument(skip(client), err)]
+    #[instrument(skip(client, token), err)]
     pub async fn lookup(
         client: &impl Client,
         token: &str,
@@ -265,10 +1489,19 @@ pub mod wrapping {
         api::exec_with_result(client, endpoint).await
     }
 
+    /// Rewraps a token wrapped response, invalidating the given token
+    ///
+    /// See [RewrapRequest]
+    #[instrument(skip(client, token), err)]
+    pub async fn rewrap(client: &impl Client, token: &str) -> Result<WrapInfo, ClientError> {
+        let endpoint = RewrapRequest::builder().token(token).build().unwrap();
+        api::exec_with_wrap_info(client, endpoint).await
+    }
+
     /// Unwraps a token wrapped response
     ///
     /// See [UnwrapRequest]
-    #[instrument(skip(client), err)]
+    #[instrument(skip(client, token), err)]
     pub async fn unwrap<D: DeserializeOwned>(
         client: &impl Client,
         token: Option<&str>,
@@ -279,4 +1512,20 @@ pub mod wrapping {
         let res = api::exec_with_result(client, endpoint).await?;
         serde_json::value::from_value(res).map_err(|e| ClientError::JsonParseError { source: e })
     }
+
+    /// Wraps the given data in a token wrapped response which is valid for the
+    /// given TTL
+    ///
+    /// See [WrapRequest]
+    #[instrument(skip(client, data), err)]
+    pub async fn wrap<T: Serialize>(
+        client: &impl Client,
+        data: &T,
+        ttl: &str,
+    ) -> Result<WrapInfo, ClientError> {
+        let data =
+            serde_json::to_vec(data).map_err(|e| ClientError::JsonParseError { source: e })?;
+        let endpoint = WrapRequest::builder().data(data).build().unwrap();
+        Ok(api::wrap_with_ttl(client, endpoint, ttl).await?.info)
+    }
 }
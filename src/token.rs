@@ -5,10 +5,10 @@ use crate::{
             requests::{
                 CreateOrphanTokenRequest, CreateOrphanTokenRequestBuilder, CreateRoleTokenRequest,
                 CreateRoleTokenRequestBuilder, CreateTokenRequest, CreateTokenRequestBuilder,
-                LookupTokenAccessorRequest, LookupTokenRequest, LookupTokenSelfRequest,
-                RenewTokenAccessorRequest, RenewTokenRequest, RenewTokenSelfRequest,
-                RevokeTokenAccessorRequest, RevokeTokenOrphanRequest, RevokeTokenRequest,
-                RevokeTokenSelfRequest, TidyRequest,
+                ListAccessorRequest, LookupTokenAccessorRequest, LookupTokenRequest,
+                LookupTokenSelfRequest, RenewTokenAccessorRequest, RenewTokenRequest,
+                RenewTokenSelfRequest, RevokeTokenAccessorRequest, RevokeTokenOrphanRequest,
+                RevokeTokenRequest, RevokeTokenSelfRequest, TidyRequest,
             },
             responses::LookupTokenResponse,
         },
@@ -18,10 +18,23 @@ use crate::{
     error::ClientError,
 };
 
+/// Lists the accessors of all tokens
+///
+/// Together with [lookup_accessor] this allows building an inventory of the
+/// tokens, for example for auditing or bulk revocation. It requires a token
+/// with `sudo` capability on `auth/token/accessors`, like a root token.
+///
+/// See [ListAccessorRequest]
+#[instrument(skip(client), err)]
+pub async fn list_accessors(client: &impl Client) -> Result<Vec<String>, ClientError> {
+    let endpoint = ListAccessorRequest::builder().build().unwrap();
+    api::exec_with_list(client, endpoint).await
+}
+
 /// Looks up a token
 ///
 /// See [LookupTokenResponse]
-#[instrument(skip(client), err)]
+#[instrument(skip(client, token), err)]
 pub async fn lookup(client: &impl Client, token: &str) -> Result<LookupTokenResponse, ClientError> {
     let endpoint = LookupTokenRequest::builder().token(token).build().unwrap();
     api::exec_with_result(client, endpoint).await
@@ -94,7 +107,7 @@ pub async fn new_role(
 /// Renews a token
 ///
 /// See [RenewTokenRequest]
-#[instrument(skip(client), err)]
+#[instrument(skip(client, token), err)]
 pub async fn renew(
     client: &impl Client,
     token: &str,
@@ -141,7 +154,7 @@ pub async fn renew_self(
 /// Revokes a token
 ///
 /// See [RevokeTokenRequest]
-#[instrument(skip(client), err)]
+#[instrument(skip(client, token), err)]
 pub async fn revoke(client: &impl Client, token: &str) -> Result<(), ClientError> {
     let endpoint = RevokeTokenRequest::builder().token(token).build().unwrap();
     api::exec_with_empty(client, endpoint).await
@@ -162,7 +175,7 @@ pub async fn revoke_accessor(client: &impl Client, accessor: &str) -> Result<(),
 /// Revokes a token excluding any child tokens
 ///
 /// See [RevokeTokenOrphanRequest]
-#[instrument(skip(client), err)]
+#[instrument(skip(client, token), err)]
 pub async fn revoke_orphan(client: &impl Client, token: &str) -> Result<(), ClientError> {
     let endpoint = RevokeTokenOrphanRequest::builder()
         .token(token)
@@ -223,7 +236,7 @@ pub mod role {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client) -> Result<ListTokenRolesResponse, ClientError> {
         let endpoint = ListTokenRolesRequest::builder().build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Read a token role
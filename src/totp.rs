@@ -0,0 +1,142 @@
+pub mod key {
+    use crate::api::totp::{
+        requests::{
+            CreateKeyRequest, CreateKeyRequestBuilder, DeleteKeyRequest, GenerateKeyRequest,
+            GenerateKeyRequestBuilder, ListKeysRequest, ReadKeyRequest,
+        },
+        responses::{GenerateKeyResponse, ListKeysResponse, ReadKeyResponse},
+    };
+    use crate::{api, client::Client, error::ClientError};
+
+    /// Imports an existing key from its `url` or base32 encoded `key`.
+    ///
+    /// See [CreateKeyRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn create(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        opts: Option<&mut CreateKeyRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut t = CreateKeyRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Deletes a key.
+    ///
+    /// See [DeleteKeyRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, mount: &str, name: &str) -> Result<(), ClientError> {
+        let endpoint = DeleteKeyRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Generates a new key, returning the URL and QR code used to enroll it
+    /// into an authenticator app.
+    ///
+    /// See [GenerateKeyRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn generate(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        issuer: &str,
+        account_name: &str,
+        opts: Option<&mut GenerateKeyRequestBuilder>,
+    ) -> Result<GenerateKeyResponse, ClientError> {
+        let mut t = GenerateKeyRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .mount(mount)
+            .name(name)
+            .generate(true)
+            .issuer(issuer)
+            .account_name(account_name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Lists all keys.
+    ///
+    /// See [ListKeysRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client, mount: &str) -> Result<ListKeysResponse, ClientError> {
+        let endpoint = ListKeysRequest::builder().mount(mount).build().unwrap();
+        api::exec_with_list_result(client, endpoint).await
+    }
+
+    /// Reads the definition of a key.
+    ///
+    /// See [ReadKeyRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+    ) -> Result<ReadKeyResponse, ClientError> {
+        let endpoint = ReadKeyRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+}
+
+pub mod code {
+    use crate::api::totp::{
+        requests::{GenerateCodeRequest, ValidateCodeRequest},
+        responses::GenerateCodeResponse,
+    };
+    use crate::{api, client::Client, error::ClientError};
+
+    /// Generates a code for the current period using the named key.
+    ///
+    /// See [GenerateCodeRequest]
+    #[instrument(skip(client), err)]
+    pub async fn generate(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+    ) -> Result<GenerateCodeResponse, ClientError> {
+        let endpoint = GenerateCodeRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint).await
+    }
+
+    /// Returns whether the code is valid for the named key.
+    ///
+    /// Vault only accepts each code once, so validating the same code again
+    /// returns a [ClientError::APIError].
+    ///
+    /// See [ValidateCodeRequest]
+    #[instrument(skip(client, code), err)]
+    pub async fn validate(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        code: &str,
+    ) -> Result<bool, ClientError> {
+        let endpoint = ValidateCodeRequest::builder()
+            .mount(mount)
+            .name(name)
+            .code(code)
+            .build()
+            .unwrap();
+        Ok(api::exec_with_result(client, endpoint).await?.valid)
+    }
+}
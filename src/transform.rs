@@ -0,0 +1,305 @@
+//! The Transform secrets engine is only available in Vault Enterprise.
+//!
+//! Vault OSS can't mount the engine, so requests to it fail with a
+//! [ClientError::APIError][crate::error::ClientError::APIError] with a status
+//! code of 404, like requests to a mount which isn't enabled.
+//!
+//! To avoid this, the functions read the version of the server with
+//! [Client::server_version][crate::client::Client::server_version] before
+//! their first request and fail with a
+//! [ClientError::EnterpriseOnlyError][crate::error::ClientError::EnterpriseOnlyError]
+//! for servers which don't run Vault Enterprise, or with a
+//! [ClientError::UnsupportedFeature][crate::error::ClientError::UnsupportedFeature]
+//! for servers which are too old for the engine. The version is cached by the
+//! client.
+
+use crate::api::transform::{
+    requests::{
+        DecodeBatchRequest, DecodeRequest, EncodeBatchRequest, EncodeRequest, TransformBatchItem,
+    },
+    responses::{DecodeResponse, EncodeResponse},
+};
+use crate::api::{self, enterprise_only};
+use crate::sys::Feature;
+use crate::{client::Client, error::ClientError};
+
+/// Encodes a value using the named role.
+///
+/// The `transformation` is required if the role has more than one and the
+/// `tweak` if the transformation expects it to be supplied.
+///
+/// See [EncodeRequest]
+#[instrument(skip(client, value, tweak), err)]
+pub async fn encode(
+    client: &impl Client,
+    mount: &str,
+    role: &str,
+    value: &str,
+    transformation: Option<&str>,
+    tweak: Option<&str>,
+) -> Result<EncodeResponse, ClientError> {
+    api::require(client, Feature::Transform).await?;
+    let mut endpoint = EncodeRequest::builder();
+    endpoint.mount(mount).role(role).value(value);
+    if let Some(t) = transformation {
+        endpoint.transformation(t);
+    }
+    if let Some(t) = tweak {
+        endpoint.tweak(t);
+    }
+    api::exec_with_result(client, endpoint.build().unwrap())
+        .await
+        .map_err(enterprise_only)
+}
+
+/// Encodes a batch of values using the named role.
+///
+/// The results are returned in the same order as the items.
+///
+/// See [EncodeBatchRequest]
+#[instrument(skip(client, items), err)]
+pub async fn encode_batch(
+    client: &impl Client,
+    mount: &str,
+    role: &str,
+    items: Vec<TransformBatchItem>,
+) -> Result<Vec<EncodeResponse>, ClientError> {
+    api::require(client, Feature::Transform).await?;
+    let endpoint = EncodeBatchRequest::builder()
+        .mount(mount)
+        .role(role)
+        .batch_input(items)
+        .build()
+        .unwrap();
+    Ok(api::exec_with_result(client, endpoint)
+        .await
+        .map_err(enterprise_only)?
+        .batch_results)
+}
+
+/// Decodes a value using the named role.
+///
+/// The `transformation` is required if the role has more than one and the
+/// `tweak` if one was supplied or returned when encoding.
+///
+/// See [DecodeRequest]
+#[instrument(skip(client, value, tweak), err)]
+pub async fn decode(
+    client: &impl Client,
+    mount: &str,
+    role: &str,
+    value: &str,
+    transformation: Option<&str>,
+    tweak: Option<&str>,
+) -> Result<DecodeResponse, ClientError> {
+    api::require(client, Feature::Transform).await?;
+    let mut endpoint = DecodeRequest::builder();
+    endpoint.mount(mount).role(role).value(value);
+    if let Some(t) = transformation {
+        endpoint.transformation(t);
+    }
+    if let Some(t) = tweak {
+        endpoint.tweak(t);
+    }
+    api::exec_with_result(client, endpoint.build().unwrap())
+        .await
+        .map_err(enterprise_only)
+}
+
+/// Decodes a batch of values using the named role.
+///
+/// The results are returned in the same order as the items.
+///
+/// See [DecodeBatchRequest]
+#[instrument(skip(client, items), err)]
+pub async fn decode_batch(
+    client: &impl Client,
+    mount: &str,
+    role: &str,
+    items: Vec<TransformBatchItem>,
+) -> Result<Vec<DecodeResponse>, ClientError> {
+    api::require(client, Feature::Transform).await?;
+    let endpoint = DecodeBatchRequest::builder()
+        .mount(mount)
+        .role(role)
+        .batch_input(items)
+        .build()
+        .unwrap();
+    Ok(api::exec_with_result(client, endpoint)
+        .await
+        .map_err(enterprise_only)?
+        .batch_results)
+}
+
+pub mod role {
+    use crate::api::transform::{
+        requests::{DeleteRoleRequest, ListRolesRequest, ReadRoleRequest, SetRoleRequest},
+        responses::{ListRolesResponse, ReadRoleResponse},
+    };
+    use crate::api::{self, enterprise_only};
+    use crate::sys::Feature;
+    use crate::{client::Client, error::ClientError};
+
+    /// Deletes a role.
+    ///
+    /// See [DeleteRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, mount: &str, name: &str) -> Result<(), ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let endpoint = DeleteRoleRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Lists all roles.
+    ///
+    /// See [ListRolesRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(client: &impl Client, mount: &str) -> Result<ListRolesResponse, ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let endpoint = ListRolesRequest::builder().mount(mount).build().unwrap();
+        api::exec_with_list_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Reads a role.
+    ///
+    /// See [ReadRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+    ) -> Result<ReadRoleResponse, ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let endpoint = ReadRoleRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Creates or updates a role with the transformations it may use.
+    ///
+    /// See [SetRoleRequest]
+    #[instrument(skip(client), err)]
+    pub async fn set(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        transformations: Vec<String>,
+    ) -> Result<(), ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let endpoint = SetRoleRequest::builder()
+            .mount(mount)
+            .name(name)
+            .transformations(transformations)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+}
+
+pub mod transformation {
+    use crate::api::transform::{
+        requests::{
+            DeleteTransformationRequest, ListTransformationsRequest, ReadTransformationRequest,
+            SetTransformationRequest, SetTransformationRequestBuilder,
+        },
+        responses::{ListTransformationsResponse, ReadTransformationResponse},
+        TransformationType,
+    };
+    use crate::api::{self, enterprise_only};
+    use crate::sys::Feature;
+    use crate::{client::Client, error::ClientError};
+
+    /// Deletes a transformation.
+    ///
+    /// See [DeleteTransformationRequest]
+    #[instrument(skip(client), err)]
+    pub async fn delete(client: &impl Client, mount: &str, name: &str) -> Result<(), ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let endpoint = DeleteTransformationRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Lists all transformations.
+    ///
+    /// See [ListTransformationsRequest]
+    #[instrument(skip(client), err)]
+    pub async fn list(
+        client: &impl Client,
+        mount: &str,
+    ) -> Result<ListTransformationsResponse, ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let endpoint = ListTransformationsRequest::builder()
+            .mount(mount)
+            .build()
+            .unwrap();
+        api::exec_with_list_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Reads a transformation.
+    ///
+    /// See [ReadTransformationRequest]
+    #[instrument(skip(client), err)]
+    pub async fn read(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+    ) -> Result<ReadTransformationResponse, ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let endpoint = ReadTransformationRequest::builder()
+            .mount(mount)
+            .name(name)
+            .build()
+            .unwrap();
+        api::exec_with_result(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+
+    /// Creates or updates a transformation of the given type.
+    ///
+    /// See [SetTransformationRequest]
+    #[instrument(skip(client, opts), err)]
+    pub async fn set(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        transformation_type: TransformationType,
+        opts: Option<&mut SetTransformationRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        api::require(client, Feature::Transform).await?;
+        let mut t = SetTransformationRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut t)
+            .mount(mount)
+            .name(name)
+            .transformation_type(transformation_type)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(enterprise_only)
+    }
+}
@@ -1,12 +1,34 @@
+use crate::api::transit::{requests::ReadWrappingKeyRequest, responses::ReadWrappingKeyResponse};
+use crate::{api, client::Client, error::ClientError};
+
+/// Read the public key used for wrapping keys to import with
+/// [key::import].
+///
+/// See [ReadWrappingKeyRequest]
+#[instrument(skip(client), err)]
+pub async fn wrapping_key(
+    client: &impl Client,
+    mount: &str,
+) -> Result<ReadWrappingKeyResponse, ClientError> {
+    let endpoint = ReadWrappingKeyRequest::builder()
+        .mount(mount)
+        .build()
+        .unwrap();
+    api::exec_with_result(client, endpoint).await
+}
+
 pub mod key {
     use crate::api::transit::{
         requests::{
             BackupKeyRequest, CreateKeyRequest, CreateKeyRequestBuilder, DeleteKeyRequest,
-            ExportKeyRequest, ExportKeyType, ExportVersion, ListKeysRequest, ReadKeyRequest,
-            RestoreKeyRequest, RestoreKeyRequestBuilder, RotateKeyRequest, TrimKeyRequest,
-            UpdateKeyConfigurationRequest, UpdateKeyConfigurationRequestBuilder,
+            ExportKeyRequest, ExportKeyType, ExportVersion, ImportKeyRequest,
+            ImportKeyRequestBuilder, ImportKeyVersionRequest, ImportKeyVersionRequestBuilder,
+            ListKeysRequest, ReadKeyRequest, RestoreKeyRequest, RestoreKeyRequestBuilder,
+            RotateKeyRequest, TrimKeyRequest, UpdateKeyConfigurationRequest,
+            UpdateKeyConfigurationRequestBuilder,
         },
         responses::{BackupKeyResponse, ExportKeyResponse, ListKeysResponse, ReadKeyResponse},
+        KeyType,
     };
     use crate::{api, client::Client, error::ClientError};
 
@@ -53,7 +75,7 @@ pub mod key {
     #[instrument(skip(client), err)]
     pub async fn list(client: &impl Client, mount: &str) -> Result<ListKeysResponse, ClientError> {
         let endpoint = ListKeysRequest::builder().mount(mount).build().unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_list_result(client, endpoint).await
     }
 
     /// Update a key's configuration.
@@ -78,6 +100,9 @@ pub mod key {
 
     /// Delete a named encryption key.
     ///
+    /// Keys can only be deleted once `deletion_allowed` was enabled with
+    /// [update], others return a [ClientError::KeyDeletionNotAllowed].
+    ///
     /// See [DeleteKeyRequest]
     #[instrument(skip(client), err)]
     pub async fn delete(client: &impl Client, mount: &str, name: &str) -> Result<(), ClientError> {
@@ -86,7 +111,16 @@ pub mod key {
             .name(name)
             .build()
             .unwrap();
-        api::exec_with_empty(client, endpoint).await
+        api::exec_with_empty(client, endpoint)
+            .await
+            .map_err(|e| match e {
+                ClientError::APIError { code: 400, errors }
+                    if errors.iter().any(|e| e.contains("deletion is not allowed")) =>
+                {
+                    ClientError::KeyDeletionNotAllowed
+                }
+                e => e,
+            })
     }
 
     /// Rotate the version of a named key.
@@ -104,6 +138,9 @@ pub mod key {
 
     /// Export a named key.
     ///
+    /// Only keys created as `exportable` can be exported, others return a
+    /// [ClientError::KeyNotExportable].
+    ///
     /// See [ExportKeyRequest]
     #[instrument(skip(client), err)]
     pub async fn export(
@@ -120,7 +157,16 @@ pub mod key {
             .version(version)
             .build()
             .unwrap();
-        api::exec_with_result(client, endpoint).await
+        api::exec_with_result(client, endpoint)
+            .await
+            .map_err(|e| match e {
+                ClientError::APIError { code: 400, errors }
+                    if errors.iter().any(|e| e.contains("is not exportable")) =>
+                {
+                    ClientError::KeyNotExportable
+                }
+                e => e,
+            })
     }
 
     /// Return a plaintext backup of a named key.
@@ -178,19 +224,94 @@ pub mod key {
             .unwrap();
         api::exec_with_empty(client, endpoint).await
     }
+
+    /// Import existing key material as a new key.
+    ///
+    /// The `ciphertext` contains the key material wrapped with the wrapping
+    /// key of the engine, see [import_ciphertext].
+    ///
+    /// See [ImportKeyRequest]
+    #[instrument(skip(client, ciphertext, opts), err)]
+    pub async fn import(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        ciphertext: &str,
+        key_type: KeyType,
+        opts: Option<&mut ImportKeyRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut builder = ImportKeyRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut builder)
+            .mount(mount)
+            .name(name)
+            .ciphertext(ciphertext)
+            .key_type(key_type)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Import new key material as the latest version of an imported key.
+    ///
+    /// See [ImportKeyVersionRequest]
+    #[instrument(skip(client, ciphertext, opts), err)]
+    pub async fn import_version(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        ciphertext: &str,
+        opts: Option<&mut ImportKeyVersionRequestBuilder>,
+    ) -> Result<(), ClientError> {
+        let mut builder = ImportKeyVersionRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut builder)
+            .mount(mount)
+            .name(name)
+            .ciphertext(ciphertext)
+            .build()
+            .unwrap();
+        api::exec_with_empty(client, endpoint).await
+    }
+
+    /// Formats the `ciphertext` of key material to [import].
+    ///
+    /// Wrapping the key material takes the following steps, which are done
+    /// with a cryptography library before calling this function:
+    ///
+    /// 1. Read the public key of the engine with
+    ///    [wrapping_key][crate::transit::wrapping_key].
+    /// 2. Generate an ephemeral 256-bit AES key.
+    /// 3. Wrap the key material with the ephemeral key using AES-KWP
+    ///    ([RFC 5649](https://www.rfc-editor.org/rfc/rfc5649)). Symmetric keys
+    ///    are wrapped as raw bytes and asymmetric keys as PKCS#8 DER.
+    /// 4. Encrypt the ephemeral key with the public key using RSA-OAEP, with
+    ///    the hash function given as `hash_function` on import (SHA256 by
+    ///    default).
+    ///
+    /// The ciphertext is the encrypted ephemeral key followed by the wrapped
+    /// key material, encoded as base64.
+    pub fn import_ciphertext(wrapped_aes_key: &[u8], wrapped_key: &[u8]) -> String {
+        base64::encode([wrapped_aes_key, wrapped_key].concat())
+    }
 }
 
 pub mod data {
     use crate::api::transit::{
         requests::{
-            DecryptDataRequest, DecryptDataRequestBuilder, EncryptDataRequest,
-            EncryptDataRequestBuilder, RewrapDataRequest, RewrapDataRequestBuilder,
-            SignDataRequest, SignDataRequestBuilder, VerifySignedDataRequest,
+            DecryptBatchItem, DecryptDataBatchRequest, DecryptDataBatchRequestBuilder,
+            DecryptDataRequest, DecryptDataRequestBuilder, EncryptBatchItem,
+            EncryptDataBatchRequest, EncryptDataBatchRequestBuilder, EncryptDataRequest,
+            EncryptDataRequestBuilder, RewrapBatchItem, RewrapDataBatchRequest,
+            RewrapDataBatchRequestBuilder, RewrapDataRequest, RewrapDataRequestBuilder,
+            SignBatchItem, SignDataBatchRequest, SignDataBatchRequestBuilder, SignDataRequest,
+            SignDataRequestBuilder, VerifyBatchItem, VerifySignedDataBatchRequest,
+            VerifySignedDataBatchRequestBuilder, VerifySignedDataRequest,
             VerifySignedDataRequestBuilder,
         },
         responses::{
             DecryptDataResponse, EncryptDataResponse, RewrapDataResponse, SignDataResponse,
-            VerifySignedDataResponse,
+            TransitBatchResult, TransitItemError, VerifySignedDataResponse,
         },
     };
     use crate::{api, client::Client, error::ClientError};
@@ -217,6 +338,37 @@ pub mod data {
         api::exec_with_result(client, endpoint).await
     }
 
+    /// Encrypt a batch of base64-encoded plaintexts using the named key.
+    ///
+    /// The results are returned in the same order as the items. A failure of
+    /// an individual item is returned as its [TransitItemError] and does not
+    /// fail the whole batch.
+    ///
+    /// See [EncryptDataBatchRequest]
+    #[instrument(skip(client, items, opts), err)]
+    pub async fn encrypt_batch(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        items: Vec<EncryptBatchItem>,
+        opts: Option<&mut EncryptDataBatchRequestBuilder>,
+    ) -> Result<Vec<Result<TransitBatchResult, TransitItemError>>, ClientError> {
+        let mut builder = EncryptDataBatchRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut builder)
+            .mount(mount)
+            .name(name)
+            .batch_input(items)
+            .build()
+            .unwrap();
+        let resp = api::exec_with_batch_result(client, endpoint).await?;
+        Ok(resp
+            .batch_results
+            .into_iter()
+            .map(|r| r.into_result())
+            .collect())
+    }
+
     /// Decrypt the provided ciphertext using the named key.
     ///
     /// See [DecryptDataRequest]
@@ -239,9 +391,43 @@ pub mod data {
         api::exec_with_result(client, endpoint).await
     }
 
+    /// Decrypt a batch of ciphertexts using the named key.
+    ///
+    /// The results are returned in the same order as the items. A failure of
+    /// an individual item is returned as its [TransitItemError] and does not
+    /// fail the whole batch.
+    ///
+    /// See [DecryptDataBatchRequest]
+    #[instrument(skip(client, items, opts), err)]
+    pub async fn decrypt_batch(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        items: Vec<DecryptBatchItem>,
+        opts: Option<&mut DecryptDataBatchRequestBuilder>,
+    ) -> Result<Vec<Result<TransitBatchResult, TransitItemError>>, ClientError> {
+        let mut builder = DecryptDataBatchRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut builder)
+            .mount(mount)
+            .name(name)
+            .batch_input(items)
+            .build()
+            .unwrap();
+        let resp = api::exec_with_batch_result(client, endpoint).await?;
+        Ok(resp
+            .batch_results
+            .into_iter()
+            .map(|r| r.into_result())
+            .collect())
+    }
+
     /// Rewrap the provided ciphertext using the latest version of the named
     /// key.
     ///
+    /// The ciphertext is decrypted and re-encrypted within Vault, so the
+    /// plaintext is never returned to the client.
+    ///
     /// See [RewrapDataRequest]
     #[instrument(skip(client, opts), err)]
     pub async fn rewrap(
@@ -262,6 +448,39 @@ pub mod data {
         api::exec_with_result(client, endpoint).await
     }
 
+    /// Rewrap a batch of ciphertexts using the latest version of the named
+    /// key.
+    ///
+    /// The ciphertexts are decrypted and re-encrypted within Vault, so the
+    /// plaintexts are never returned to the client. The results are returned in
+    /// the same order as the items. A failure of an individual item is returned
+    /// as its [TransitItemError] and does not fail the whole batch.
+    ///
+    /// See [RewrapDataBatchRequest]
+    #[instrument(skip(client, items, opts), err)]
+    pub async fn rewrap_batch(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        items: Vec<RewrapBatchItem>,
+        opts: Option<&mut RewrapDataBatchRequestBuilder>,
+    ) -> Result<Vec<Result<TransitBatchResult, TransitItemError>>, ClientError> {
+        let mut builder = RewrapDataBatchRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut builder)
+            .mount(mount)
+            .name(name)
+            .batch_input(items)
+            .build()
+            .unwrap();
+        let resp = api::exec_with_batch_result(client, endpoint).await?;
+        Ok(resp
+            .batch_results
+            .into_iter()
+            .map(|r| r.into_result())
+            .collect())
+    }
+
     /// Return the cryptographic signature of the base64-encoded input data.
     ///
     /// See [SignDataRequest]
@@ -284,10 +503,41 @@ pub mod data {
         api::exec_with_result(client, endpoint).await
     }
 
+    /// Return the cryptographic signatures of a batch of base64-encoded inputs.
+    ///
+    /// The results are returned in the same order as the items. A failure of
+    /// an individual item is returned as its [TransitItemError] and does not
+    /// fail the whole batch.
+    ///
+    /// See [SignDataBatchRequest]
+    #[instrument(skip(client, items, opts), err)]
+    pub async fn sign_batch(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        items: Vec<SignBatchItem>,
+        opts: Option<&mut SignDataBatchRequestBuilder>,
+    ) -> Result<Vec<Result<TransitBatchResult, TransitItemError>>, ClientError> {
+        let mut builder = SignDataBatchRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut builder)
+            .mount(mount)
+            .name(name)
+            .batch_input(items)
+            .build()
+            .unwrap();
+        let resp = api::exec_with_batch_result(client, endpoint).await?;
+        Ok(resp
+            .batch_results
+            .into_iter()
+            .map(|r| r.into_result())
+            .collect())
+    }
+
     /// Return whether the provided signature is valid for the base64-encoded
     /// input data.
     ///
-    /// See [SignDataRequest]
+    /// See [VerifySignedDataRequest]
     #[instrument(skip(client, opts), err)]
     pub async fn verify(
         client: &impl Client,
@@ -306,6 +556,39 @@ pub mod data {
             .unwrap();
         api::exec_with_result(client, endpoint).await
     }
+
+    /// Return whether each signature of a batch is valid for its
+    /// base64-encoded input.
+    ///
+    /// The results are returned in the same order as the items. An invalid
+    /// signature is returned as a result with `valid` set to false, while a
+    /// failure to verify an individual item is returned as its
+    /// [TransitItemError] and does not fail the whole batch.
+    ///
+    /// See [VerifySignedDataBatchRequest]
+    #[instrument(skip(client, items, opts), err)]
+    pub async fn verify_batch(
+        client: &impl Client,
+        mount: &str,
+        name: &str,
+        items: Vec<VerifyBatchItem>,
+        opts: Option<&mut VerifySignedDataBatchRequestBuilder>,
+    ) -> Result<Vec<Result<TransitBatchResult, TransitItemError>>, ClientError> {
+        let mut builder = VerifySignedDataBatchRequest::builder();
+        let endpoint = opts
+            .unwrap_or(&mut builder)
+            .mount(mount)
+            .name(name)
+            .batch_input(items)
+            .build()
+            .unwrap();
+        let resp = api::exec_with_batch_result(client, endpoint).await?;
+        Ok(resp
+            .batch_results
+            .into_iter()
+            .map(|r| r.into_result())
+            .collect())
+    }
 }
 
 pub mod generate {
@@ -28,7 +28,7 @@ fn test() {
         let (id, accessor) = crate::role::secret::test_generate(&client, &endpoint).await;
         crate::role::secret::test_read(&client, &endpoint, id.as_str()).await;
         crate::role::secret::test_read_accessor(&client, &endpoint, accessor.as_str()).await;
-        crate::role::secret::test_list(&client, &endpoint).await;
+        crate::role::secret::test_list(&client, &endpoint, accessor.as_str()).await;
         crate::role::secret::test_delete_accessor(&client, &endpoint, accessor.as_str()).await;
         crate::role::secret::test_custom(&client, &endpoint).await;
         crate::role::secret::test_delete(&client, &endpoint, "test").await;
@@ -75,16 +75,30 @@ mod role {
     pub async fn test_delete(client: &impl Client, endpoint: &AppRoleEndpoint) {
         let res = role::delete(client, endpoint.path.as_str(), endpoint.role_name.as_str()).await;
         assert!(res.is_ok());
+
+        let res = role::read(client, endpoint.path.as_str(), endpoint.role_name.as_str()).await;
+        assert!(res.is_err());
     }
 
     pub async fn test_list(client: &impl Client, endpoint: &AppRoleEndpoint) {
         let res = role::list(client, endpoint.path.as_str()).await;
         assert!(res.is_ok());
+        assert!(res.unwrap().keys.contains(&endpoint.role_name));
     }
 
     pub async fn test_read(client: &impl Client, endpoint: &AppRoleEndpoint) {
         let res = role::read(client, endpoint.path.as_str(), endpoint.role_name.as_str()).await;
         assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert!(res.bind_secret_id);
+        assert!(!res.local_secret_ids);
+        assert_eq!(res.secret_id_num_uses, 10);
+        assert_eq!(res.secret_id_ttl, 3600);
+        assert_eq!(res.token_ttl, 600);
+        assert_eq!(res.token_max_ttl, 1800);
+        assert_eq!(res.token_num_uses, 5);
+        assert_eq!(res.token_policies, vec!["test".to_string()]);
     }
 
     pub async fn test_set(client: &impl Client, endpoint: &AppRoleEndpoint) {
@@ -92,7 +106,15 @@ mod role {
             client,
             endpoint.path.as_str(),
             endpoint.role_name.as_str(),
-            Some(&mut SetAppRoleRequest::builder().token_ttl("10m")),
+            Some(
+                &mut SetAppRoleRequest::builder()
+                    .secret_id_num_uses(10u64)
+                    .secret_id_ttl("1h")
+                    .token_ttl("10m")
+                    .token_max_ttl("30m")
+                    .token_num_uses(5u64)
+                    .token_policies(vec!["test".to_string()]),
+            ),
         )
         .await;
         assert!(res.is_ok());
@@ -157,6 +179,16 @@ mod role {
             )
             .await;
             assert!(res.is_ok());
+
+            // The secret ID can no longer be looked up once destroyed
+            let res = secret::read_accessor(
+                client,
+                endpoint.path.as_str(),
+                endpoint.role_name.as_str(),
+                accessor,
+            )
+            .await;
+            assert!(res.is_err());
         }
 
         pub async fn test_generate(
@@ -169,20 +201,24 @@ mod role {
                 endpoint.role_name.as_str(),
                 Some(
                     &mut GenerateNewSecretIDRequest::builder()
-                        .metadata("{ \"tag1\": \"production\" }"),
+                        .metadata("{ \"tag1\": \"production\" }")
+                        .cidr_list(vec!["127.0.0.1/32".to_string()]),
                 ),
             )
             .await;
             assert!(res.is_ok());
 
             let id = res.unwrap();
+            assert!(!id.secret_id.is_empty());
+            assert!(!id.secret_id_accessor.is_empty());
             (id.secret_id, id.secret_id_accessor)
         }
 
-        pub async fn test_list(client: &impl Client, endpoint: &AppRoleEndpoint) {
+        pub async fn test_list(client: &impl Client, endpoint: &AppRoleEndpoint, accessor: &str) {
             let res =
                 secret::list(client, endpoint.path.as_str(), endpoint.role_name.as_str()).await;
             assert!(res.is_ok());
+            assert!(res.unwrap().keys.contains(&accessor.to_string()));
         }
 
         pub async fn test_read(client: &impl Client, endpoint: &AppRoleEndpoint, id: &str) {
@@ -194,6 +230,13 @@ mod role {
             )
             .await;
             assert!(res.is_ok());
+
+            let res = res.unwrap();
+            assert_eq!(res.cidr_list, vec!["127.0.0.1/32".to_string()]);
+            assert_eq!(
+                res.metadata.unwrap().get("tag1").map(String::as_str),
+                Some("production")
+            );
         }
 
         pub async fn test_read_accessor(
@@ -209,6 +252,7 @@ mod role {
             )
             .await;
             assert!(res.is_ok());
+            assert_eq!(res.unwrap().secret_id_accessor, accessor);
         }
     }
 }
@@ -232,3 +276,24 @@ async fn setup(server: &VaultServer, client: &impl Client) -> Result<AppRoleEndp
         role_name: role_name.to_string(),
     })
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::auth::approle;
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty; 2]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(approle::role::list(&client, "approle"));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(approle::role::secret::list(&client, "approle", "app"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/auth/approle/role HTTP/1.1"));
+    }
+}
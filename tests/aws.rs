@@ -258,7 +258,7 @@ mod config {
 
                 let res = res.unwrap();
                 assert_eq!(res.safety_buffer, 86400);
-                assert_eq!(res.disable_periodic_tidy, true);
+                assert!(res.disable_periodic_tidy);
             }
             pub async fn test_delete(client: &impl Client, endpoint: &AwsAuthEndpoint) {
                 let res =
@@ -295,7 +295,7 @@ mod config {
 
                 let res = res.unwrap();
                 assert_eq!(res.safety_buffer, 86400);
-                assert_eq!(res.disable_periodic_tidy, false);
+                assert!(!res.disable_periodic_tidy);
             }
 
             pub async fn test_delete(client: &impl Client, endpoint: &AwsAuthEndpoint) {
@@ -402,16 +402,13 @@ mod role {
 mod identity_access_list {
     use vaultrs::{api::auth::aws::requests::TidyIdentityAccessListEntriesRequest, auth::aws};
 
-    use crate::{AwsAuthEndpoint, Client, ClientError};
+    use crate::{AwsAuthEndpoint, Client};
 
     pub async fn test_list(client: &impl Client, endpoint: &AwsAuthEndpoint) {
+        // vault returns 404 instead of empty list, which is returned as one
+        // https://github.com/hashicorp/vault/issues/1365
         let res = aws::identity_access_list::list(client, &endpoint.path).await;
-        assert!(match res {
-            // vault returns 404 instead of empty list
-            // https://github.com/hashicorp/vault/issues/1365
-            Err(ClientError::APIError { code, errors: _ }) => code == 404,
-            _ => false,
-        })
+        assert!(res.unwrap().keys.is_empty());
     }
 
     pub async fn test_tidy(client: &impl Client, endpoint: &AwsAuthEndpoint) {
@@ -484,3 +481,92 @@ mod role_tag_deny_list {
         assert!(res.is_ok())
     }
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::api::aws::requests::GenerateCredentialsRequest;
+    use vaultrs::auth;
+    use vaultrs::aws;
+
+    #[test]
+    fn test_generate_credentials() {
+        let iam_user = r#"{"request_id":"1","lease_id":"aws/creds/deploy/abc","lease_duration":3600,"renewable":true,"data":{"access_key":"AKIA","secret_key":"secret","security_token":null},"auth":null,"warnings":null,"wrap_info":null}"#;
+        let assumed_role = r#"{"request_id":"2","lease_id":"aws/creds/assumed/def","lease_duration":900,"renewable":false,"data":{"access_key":"ASIA","secret_key":"secret","security_token":"session","arn":"arn:aws:sts::123456789012:assumed-role/deploy/vault"},"auth":null,"warnings":null,"wrap_info":null}"#;
+        let (address, requests) = mock_server(vec![
+            response("200 OK", "", iam_user),
+            response("200 OK", "", assumed_role),
+        ]);
+        let client = build_retry_client(&address);
+
+        // IAM users don't come with a session token
+        let res = tokio_test::block_on(aws::generate_credentials(&client, "aws", "deploy", None));
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.data.access_key, "AKIA");
+        assert!(res.data.security_token.is_none());
+        assert_eq!(res.lease_id, "aws/creds/deploy/abc");
+        assert_eq!(res.lease_duration, 3600);
+        assert!(res.renewable);
+
+        // Assumed roles are STS credentials
+        let res = tokio_test::block_on(aws::generate_credentials(
+            &client,
+            "aws",
+            "assumed",
+            Some(
+                GenerateCredentialsRequest::builder()
+                    .role_arn("arn:aws:iam::123456789012:role/deploy")
+                    .role_session_name("vault")
+                    .ttl("15m"),
+            ),
+        ));
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.data.security_token.as_deref(), Some("session"));
+        assert!(res
+            .data
+            .arn
+            .as_ref()
+            .unwrap()
+            .ends_with("assumed-role/deploy/vault"));
+        assert!(!format!("{:?}", res).contains("session"));
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /v1/aws/creds/deploy "));
+        assert!(requests[1].starts_with("POST /v1/aws/creds/assumed "));
+        let body: serde_json::Value =
+            serde_json::from_str(requests[1].split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "role_arn": "arn:aws:iam::123456789012:role/deploy",
+                "role_session_name": "vault",
+                "ttl": "15m",
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty; 5]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(auth::aws::config::certificate::list(&client, "aws"));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(auth::aws::config::sts::list(&client, "aws"));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(auth::aws::role::list(&client, "aws"));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(auth::aws::role_tag_deny_list::list(&client, "aws"));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(auth::aws::identity_access_list::list(&client, "aws"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/auth/aws/config/certificates HTTP/1.1"));
+    }
+}
@@ -0,0 +1,110 @@
+#[macro_use]
+extern crate tracing;
+
+mod common;
+
+use common::{VaultServer, VaultServerHelper};
+use test_log::test;
+use vaultrs::client::Client;
+use vaultrs::error::ClientError;
+
+#[test]
+fn test() {
+    let test = common::new_test();
+    test.run(|instance| async move {
+        let server: VaultServer = instance.server();
+        let client = server.client();
+        let endpoint = setup(&server, &client).await.unwrap();
+
+        // Test CA certificate roles
+        ca_cert_role::test_set(&client, &endpoint).await;
+        ca_cert_role::test_read(&client, &endpoint).await;
+        ca_cert_role::test_list(&client, &endpoint).await;
+        ca_cert_role::test_delete(&client, &endpoint).await;
+    });
+}
+
+pub mod ca_cert_role {
+    use super::{CertEndpoint, Client, ClientError};
+    use vaultrs::{
+        api::auth::cert::requests::CreateCaCertificateRoleRequest, auth::cert::ca_cert_role,
+    };
+
+    pub async fn test_delete(client: &impl Client, endpoint: &CertEndpoint) {
+        let res =
+            ca_cert_role::delete(client, endpoint.path.as_str(), endpoint.name.as_str()).await;
+        assert!(res.is_ok());
+
+        let res = ca_cert_role::read(client, endpoint.path.as_str(), endpoint.name.as_str()).await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+    }
+
+    pub async fn test_list(client: &impl Client, endpoint: &CertEndpoint) {
+        let res = ca_cert_role::list(client, endpoint.path.as_str()).await;
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().keys, vec![endpoint.name.clone()]);
+    }
+
+    pub async fn test_read(client: &impl Client, endpoint: &CertEndpoint) {
+        let res = ca_cert_role::read(client, endpoint.path.as_str(), endpoint.name.as_str()).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.certificate.trim(), endpoint.certificate.trim());
+        assert_eq!(res.token_policies, vec!["default"]);
+    }
+
+    pub async fn test_set(client: &impl Client, endpoint: &CertEndpoint) {
+        let res = ca_cert_role::set(
+            client,
+            endpoint.path.as_str(),
+            endpoint.name.as_str(),
+            endpoint.certificate.as_str(),
+            Some(CreateCaCertificateRoleRequest::builder().token_policies(vec!["default".into()])),
+        )
+        .await;
+        assert!(res.is_ok());
+    }
+}
+
+#[derive(Debug)]
+pub struct CertEndpoint {
+    pub path: String,
+    pub name: String,
+    pub certificate: String,
+}
+
+async fn setup(server: &VaultServer, client: &impl Client) -> Result<CertEndpoint, ClientError> {
+    debug!("setting up cert auth engine");
+
+    let path = "cert_test";
+    let name = "web";
+
+    // Mount the cert auth engine
+    server.mount_auth(client, path, "cert").await?;
+
+    Ok(CertEndpoint {
+        path: path.to_string(),
+        name: name.to_string(),
+        certificate: include_str!("files/client.crt").to_string(),
+    })
+}
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::auth::cert;
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(cert::ca_cert_role::list(&client, "cert"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/auth/cert/certs HTTP/1.1"));
+    }
+}
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
+#[allow(unused_imports)]
 pub use dockertest_server::servers::cloud::localstack::{LocalStackServer, LocalStackServerConfig};
+#[allow(unused_imports)]
 pub use dockertest_server::servers::database::postgres::{PostgresServer, PostgresServerConfig};
 pub use dockertest_server::servers::hashi::{VaultServer, VaultServerConfig};
 use dockertest_server::servers::webserver::nginx::{
@@ -7,6 +9,10 @@ use dockertest_server::servers::webserver::nginx::{
 };
 use dockertest_server::Test;
 use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tracing::trace;
 use vaultrs::{
     api::sys::requests::{
@@ -14,7 +20,7 @@ use vaultrs::{
     },
     client::{Client, VaultClient, VaultClientSettingsBuilder},
     error::ClientError,
-    sys::{auth, mount},
+    sys::{auth, mount, ServerVersion},
 };
 //use vaultrs_test::VaultServer;
 
@@ -23,6 +29,7 @@ pub const VERSION: &str = "1.10.3";
 pub const NGINX_PORT: u32 = 8888;
 pub const NGINX_VERSION: &str = "1.21";
 
+#[allow(dead_code)]
 #[async_trait]
 pub trait VaultServerHelper {
     /// Mounts a new instance of the requested secret engine at the given path.
@@ -138,6 +145,109 @@ impl VaultServerHelper for VaultServer {
     }
 }
 
+#[allow(dead_code)]
+pub fn build_retry_client(address: &str) -> VaultClient {
+    VaultClient::new(
+        VaultClientSettingsBuilder::default()
+            .address(address)
+            .token("token")
+            .max_retries(3u32)
+            .retry_base_delay(Duration::from_millis(10))
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+/// Builds a client which knows that it's connected to Vault Enterprise, so that
+/// it doesn't read the version of the server before Enterprise-only requests.
+#[allow(dead_code)]
+pub fn build_enterprise_client(address: &str) -> VaultClient {
+    let client = build_retry_client(address);
+    *client.middle.server_version.lock().unwrap() = ServerVersion::parse("1.12.0+ent");
+    client
+}
+
+#[allow(dead_code)]
+pub fn build_timeout_client(address: &str, max_retries: u32) -> VaultClient {
+    VaultClient::new(
+        VaultClientSettingsBuilder::default()
+            .address(address)
+            .token("token")
+            .timeout(Some(Duration::from_millis(200)))
+            .connect_timeout(Some(Duration::from_millis(200)))
+            .max_retries(max_retries)
+            .retry_base_delay(Duration::from_millis(10))
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+#[allow(dead_code)]
+pub fn response(status: &str, headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        headers,
+        body.len(),
+        body
+    )
+}
+
+/// Starts a server which answers each connection with the next response and
+/// records the requests it receives
+#[allow(dead_code)]
+pub fn mock_server(responses: Vec<String>) -> (String, Arc<Mutex<Vec<String>>>) {
+    mock_slow_server(responses.into_iter().map(|r| (Duration::ZERO, r)).collect())
+}
+
+/// Starts a server like [mock_server] which waits for the given delay before
+/// sending each response
+#[allow(dead_code)]
+pub fn mock_slow_server(responses: Vec<(Duration, String)>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorder = requests.clone();
+    std::thread::spawn(move || {
+        for (stream, (delay, response)) in listener.incoming().zip(responses) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str("\r\n");
+            request.push_str(&String::from_utf8(body).unwrap());
+            recorder.lock().unwrap().push(request);
+            if delay.is_zero() {
+                stream.write_all(response.as_bytes()).unwrap();
+            } else {
+                // The client may have given up and closed the connection
+                std::thread::spawn(move || {
+                    std::thread::sleep(delay);
+                    let _ = stream.write_all(response.as_bytes());
+                });
+            }
+        }
+    });
+    (address, requests)
+}
+
 // Sets up a new test.
 #[allow(dead_code)]
 pub fn new_test() -> Test {
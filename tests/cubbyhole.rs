@@ -0,0 +1,42 @@
+mod common;
+
+use common::{VaultServer, VaultServerHelper};
+use std::collections::HashMap;
+use test_log::test;
+use vaultrs::client::Client;
+use vaultrs::error::ClientError;
+use vaultrs::{cubbyhole, token};
+
+#[test]
+fn test() {
+    let test = common::new_test();
+    test.run(|instance| async move {
+        let server: VaultServer = instance.server();
+        let client = server.client();
+
+        // Write a secret into the cubbyhole of the root token
+        let secret = HashMap::from([("key", "value")]);
+        let res = cubbyhole::set(&client, "app/secret", &secret).await;
+        assert!(res.is_ok());
+
+        // Read it back with the same token
+        let res: Result<HashMap<String, String>, _> = cubbyhole::read(&client, "app/secret").await;
+        assert_eq!(res.unwrap()["key"], "value");
+
+        let res = cubbyhole::list(&client, "app").await;
+        assert_eq!(res.unwrap(), vec!["secret"]);
+
+        // Other tokens have their own cubbyhole
+        let child = token::new(&client, None).await.unwrap().client_token;
+        let mut other = server.client();
+        other.set_token(child.as_str());
+        let res: Result<HashMap<String, String>, _> = cubbyhole::read(&other, "app/secret").await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+
+        let res = cubbyhole::delete(&client, "app/secret").await;
+        assert!(res.is_ok());
+
+        let res: Result<HashMap<String, String>, _> = cubbyhole::read(&client, "app/secret").await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+    });
+}
@@ -26,6 +26,7 @@ fn test() {
         crate::role::test_set(&client, &endpoint).await;
         crate::role::test_read(&client, &endpoint).await;
         crate::role::test_creds(&client, &endpoint).await;
+        crate::leases::test_leases(&client, &endpoint).await;
         crate::role::test_list(&client, &endpoint).await;
         crate::role::test_delete(&client, &endpoint).await;
 
@@ -45,7 +46,7 @@ fn test() {
 }
 
 mod connection {
-    use super::{Client, DatabaseEndpoint};
+    use super::{Client, ClientError, DatabaseEndpoint};
     use vaultrs::database::connection;
 
     pub async fn test_delete(client: &impl Client, endpoint: &DatabaseEndpoint) {
@@ -76,6 +77,9 @@ mod connection {
         let res =
             connection::rotate(client, endpoint.path.as_str(), endpoint.connection.as_str()).await;
         assert!(res.is_ok());
+
+        let res = connection::rotate(client, endpoint.path.as_str(), "missing").await;
+        assert!(matches!(res, Err(ClientError::APIError { errors, .. }) if !errors.is_empty()));
     }
 }
 
@@ -86,6 +90,12 @@ mod role {
     pub async fn test_creds(client: &impl Client, endpoint: &DatabaseEndpoint) {
         let res = role::creds(client, endpoint.path.as_str(), endpoint.role.as_str()).await;
         assert!(res.is_ok());
+
+        let creds = res.unwrap();
+        assert!(!creds.data.username.is_empty());
+        assert!(!creds.data.password.is_empty());
+        assert!(creds.lease_id.starts_with(endpoint.path.as_str()));
+        assert!(creds.lease_duration > 0);
     }
 
     pub async fn test_delete(client: &impl Client, endpoint: &DatabaseEndpoint) {
@@ -121,8 +131,59 @@ mod role {
     }
 }
 
+mod leases {
+    use super::{Client, ClientError, DatabaseEndpoint};
+    use vaultrs::database::role;
+    use vaultrs::sys::leases;
+
+    pub async fn test_leases(client: &impl Client, endpoint: &DatabaseEndpoint) {
+        let prefix = format!("{}/creds/{}/", endpoint.path, endpoint.role);
+        let creds = role::creds(client, endpoint.path.as_str(), endpoint.role.as_str())
+            .await
+            .unwrap();
+
+        // List
+        let res = leases::list(client, prefix.as_str()).await;
+        assert!(res.is_ok());
+        let id = creds
+            .lease_id
+            .trim_start_matches(prefix.as_str())
+            .to_string();
+        assert!(res.unwrap().contains(&id));
+
+        // Lookup
+        let res = leases::lookup(client, creds.lease_id.as_str()).await;
+        assert!(res.is_ok());
+        let lease = res.unwrap();
+        assert_eq!(lease.id, creds.lease_id);
+        assert!(lease.renewable);
+        assert!(lease.ttl > 0);
+        assert!(!lease.issue_time.is_empty());
+
+        // Renew
+        let res = leases::renew(client, creds.lease_id.as_str(), Some("1h")).await;
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().lease_id, creds.lease_id);
+
+        // Revoke
+        let res = leases::revoke(client, creds.lease_id.as_str()).await;
+        assert!(res.is_ok());
+        let res = leases::lookup(client, creds.lease_id.as_str()).await;
+        assert!(matches!(res, Err(ClientError::APIError { .. })));
+
+        // Revoke prefix
+        role::creds(client, endpoint.path.as_str(), endpoint.role.as_str())
+            .await
+            .unwrap();
+        let res = leases::revoke_prefix(client, prefix.as_str()).await;
+        assert!(res.is_ok());
+        let res = leases::list(client, prefix.as_str()).await;
+        assert!(res.unwrap().is_empty());
+    }
+}
+
 mod static_role {
-    use super::{Client, DatabaseEndpoint};
+    use super::{Client, ClientError, DatabaseEndpoint};
     use vaultrs::{api::database::requests::SetStaticRoleRequest, database::static_role};
 
     pub async fn test_creds(client: &impl Client, endpoint: &DatabaseEndpoint) {
@@ -185,6 +246,9 @@ mod static_role {
         )
         .await;
         assert!(res.is_ok());
+
+        let res = static_role::rotate(client, endpoint.path.as_str(), "missing").await;
+        assert!(matches!(res, Err(ClientError::APIError { errors, .. }) if !errors.is_empty()));
     }
 }
 
@@ -241,3 +305,26 @@ async fn setup(
         username: db_server.username.clone(),
     })
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::database;
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty; 3]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(database::connection::list(&client, "database"));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(database::role::list(&client, "database"));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(database::static_role::list(&client, "database"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/database/config HTTP/1.1"));
+    }
+}
@@ -0,0 +1,525 @@
+#[macro_use]
+extern crate tracing;
+
+mod common;
+
+use common::{VaultServer, VaultServerHelper};
+use test_log::test;
+use vaultrs::client::Client;
+use vaultrs::error::ClientError;
+use vaultrs::sys::auth;
+
+#[test]
+fn test() {
+    let test = common::new_test();
+    test.run(|instance| async move {
+        let server: VaultServer = instance.server();
+        let client = server.client();
+        let accessor = setup(&server, &client).await.unwrap();
+
+        // Test entity
+        let id = entity::test_create(&client).await;
+        entity::test_read(&client, id.as_str()).await;
+        entity::test_list(&client, id.as_str()).await;
+        entity::test_update(&client, id.as_str()).await;
+
+        // Test entity alias
+        let alias_id = entity_alias::test_create(&client, id.as_str(), accessor.as_str()).await;
+        entity_alias::test_read(&client, alias_id.as_str(), id.as_str()).await;
+        entity_alias::test_list(&client, alias_id.as_str()).await;
+        entity_alias::test_update(&client, alias_id.as_str()).await;
+
+        // Test group
+        let group_id = group::test_create(&client, id.as_str()).await;
+        group::test_read(&client, group_id.as_str(), id.as_str()).await;
+        group::test_list(&client, group_id.as_str()).await;
+        group::test_update(&client, group_id.as_str()).await;
+        group::test_delete(&client, group_id.as_str()).await;
+
+        // Test group alias
+        let group_id = group_alias::test_create_external_group(&client).await;
+        let group_alias_id =
+            group_alias::test_create(&client, group_id.as_str(), accessor.as_str()).await;
+        group_alias::test_read(&client, group_alias_id.as_str(), group_id.as_str()).await;
+        group_alias::test_list(&client, group_alias_id.as_str()).await;
+        group_alias::test_update(&client, group_alias_id.as_str(), accessor.as_str()).await;
+        group_alias::test_delete(&client, group_alias_id.as_str()).await;
+
+        // Test OIDC provider
+        oidc::test_token(&server, &client).await;
+
+        // Test merge and delete
+        entity::test_merge(&client, id.as_str(), alias_id.as_str()).await;
+        entity_alias::test_delete(&client, alias_id.as_str()).await;
+        entity::test_delete(&client, id.as_str()).await;
+    });
+}
+
+mod entity {
+    use super::{Client, ClientError};
+    use std::collections::HashMap;
+    use vaultrs::{
+        api::identity::requests::{CreateEntityRequest, UpdateEntityRequest},
+        identity::entity,
+    };
+
+    pub async fn test_create(client: &impl Client) -> String {
+        let mut metadata = HashMap::new();
+        metadata.insert("team".to_string(), "ops".to_string());
+
+        let res = entity::create(
+            client,
+            Some(
+                CreateEntityRequest::builder()
+                    .name("test")
+                    .policies(vec!["default".to_string()])
+                    .metadata(metadata),
+            ),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.name, "test");
+        res.id
+    }
+
+    pub async fn test_delete(client: &impl Client, id: &str) {
+        let res = entity::delete(client, id).await;
+        assert!(res.is_ok());
+
+        let res = entity::read(client, id).await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+    }
+
+    pub async fn test_list(client: &impl Client, id: &str) {
+        let res = entity::list(client).await;
+        assert!(res.is_ok());
+        assert!(res.unwrap().keys.contains(&id.to_string()));
+
+        let res = entity::list_by_name(client).await;
+        assert!(res.is_ok());
+        assert!(res.unwrap().keys.contains(&"test".to_string()));
+    }
+
+    pub async fn test_merge(client: &impl Client, id: &str, alias_id: &str) {
+        let res = entity::create_by_name(client, "other", None).await;
+        assert!(res.is_ok());
+        let other = entity::read_by_name(client, "other").await.unwrap();
+
+        let res = entity::merge(client, vec![id.to_string()], other.id.as_str(), None).await;
+        assert!(res.is_ok());
+
+        // The merged entity is gone and its aliases moved over
+        let res = entity::read(client, id).await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+
+        let res = entity::read(client, other.id.as_str()).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert!(res.merged_entity_ids.unwrap().contains(&id.to_string()));
+        assert!(res.aliases.iter().any(|a| a.id == alias_id));
+
+        let res = entity::delete_by_name(client, "other").await;
+        assert!(res.is_ok());
+    }
+
+    pub async fn test_read(client: &impl Client, id: &str) {
+        let res = entity::read(client, id).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.id, id);
+        assert_eq!(res.name, "test");
+        assert_eq!(res.policies.unwrap(), vec!["default"]);
+        assert_eq!(res.metadata.unwrap()["team"], "ops");
+
+        let res = entity::read_by_name(client, "test").await;
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().id, id);
+    }
+
+    pub async fn test_update(client: &impl Client, id: &str) {
+        let res = entity::update(
+            client,
+            id,
+            Some(UpdateEntityRequest::builder().policies(vec!["test".to_string()])),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = entity::read(client, id).await;
+        assert_eq!(res.unwrap().policies.unwrap(), vec!["test"]);
+    }
+}
+
+mod entity_alias {
+    use super::{Client, ClientError};
+    use vaultrs::{api::identity::requests::UpdateEntityAliasRequest, identity::entity_alias};
+
+    pub async fn test_create(client: &impl Client, id: &str, accessor: &str) -> String {
+        let res = entity_alias::create(client, "test", id, accessor, None).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.canonical_id, id);
+        res.id
+    }
+
+    pub async fn test_delete(client: &impl Client, alias_id: &str) {
+        let res = entity_alias::delete(client, alias_id).await;
+        assert!(res.is_ok());
+
+        let res = entity_alias::read(client, alias_id).await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+    }
+
+    pub async fn test_list(client: &impl Client, alias_id: &str) {
+        let res = entity_alias::list(client).await;
+        assert!(res.is_ok());
+        assert!(res.unwrap().keys.contains(&alias_id.to_string()));
+    }
+
+    pub async fn test_read(client: &impl Client, alias_id: &str, id: &str) {
+        let res = entity_alias::read(client, alias_id).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.name, "test");
+        assert_eq!(res.canonical_id, id);
+        assert_eq!(res.mount_type, "userpass");
+    }
+
+    pub async fn test_update(client: &impl Client, alias_id: &str) {
+        let res = entity_alias::update(
+            client,
+            alias_id,
+            Some(UpdateEntityAliasRequest::builder().name("renamed")),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = entity_alias::read(client, alias_id).await;
+        assert_eq!(res.unwrap().name, "renamed");
+    }
+}
+
+mod group {
+    use super::{Client, ClientError};
+    use vaultrs::{
+        api::identity::{
+            requests::{CreateGroupRequest, UpdateGroupRequest},
+            GroupType,
+        },
+        identity::group,
+    };
+
+    pub async fn test_create(client: &impl Client, id: &str) -> String {
+        let res = group::create(
+            client,
+            Some(
+                CreateGroupRequest::builder()
+                    .name("test")
+                    .group_type(GroupType::Internal)
+                    .policies(vec!["default".to_string()])
+                    .member_entity_ids(vec![id.to_string()]),
+            ),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.name, "test");
+        res.id
+    }
+
+    pub async fn test_delete(client: &impl Client, group_id: &str) {
+        let res = group::delete(client, group_id).await;
+        assert!(res.is_ok());
+
+        let res = group::read(client, group_id).await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+    }
+
+    pub async fn test_list(client: &impl Client, group_id: &str) {
+        let res = group::list(client).await;
+        assert!(res.is_ok());
+        assert!(res.unwrap().keys.contains(&group_id.to_string()));
+
+        let res = group::list_by_name(client).await;
+        assert!(res.is_ok());
+        assert!(res.unwrap().keys.contains(&"test".to_string()));
+    }
+
+    pub async fn test_read(client: &impl Client, group_id: &str, id: &str) {
+        let res = group::read(client, group_id).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.name, "test");
+        assert_eq!(res.group_type, GroupType::Internal);
+        assert_eq!(res.member_entity_ids.unwrap(), vec![id]);
+        assert_eq!(res.policies.unwrap(), vec!["default"]);
+
+        let res = group::read_by_name(client, "test").await;
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().id, group_id);
+    }
+
+    pub async fn test_update(client: &impl Client, group_id: &str) {
+        let res = group::update(
+            client,
+            group_id,
+            Some(UpdateGroupRequest::builder().member_entity_ids(Vec::<String>::new())),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = group::read(client, group_id).await;
+        assert!(res
+            .unwrap()
+            .member_entity_ids
+            .unwrap_or_default()
+            .is_empty());
+    }
+}
+
+mod group_alias {
+    use super::{Client, ClientError};
+    use vaultrs::{
+        api::identity::{
+            requests::{CreateGroupAliasRequest, CreateGroupByNameRequest},
+            GroupType,
+        },
+        identity::{group, group_alias},
+    };
+
+    pub async fn test_create(client: &impl Client, group_id: &str, accessor: &str) -> String {
+        let res = group_alias::create(
+            client,
+            "idp-admins",
+            accessor,
+            Some(CreateGroupAliasRequest::builder().canonical_id(group_id)),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.canonical_id, group_id);
+        res.id
+    }
+
+    pub async fn test_create_external_group(client: &impl Client) -> String {
+        let res = group::create_by_name(
+            client,
+            "external",
+            Some(CreateGroupByNameRequest::builder().group_type(GroupType::External)),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = group::read_by_name(client, "external").await.unwrap();
+        assert_eq!(res.group_type, GroupType::External);
+        res.id
+    }
+
+    pub async fn test_delete(client: &impl Client, group_alias_id: &str) {
+        let res = group_alias::delete(client, group_alias_id).await;
+        assert!(res.is_ok());
+
+        let res = group_alias::read(client, group_alias_id).await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+
+        let res = group::delete_by_name(client, "external").await;
+        assert!(res.is_ok());
+    }
+
+    pub async fn test_list(client: &impl Client, group_alias_id: &str) {
+        let res = group_alias::list(client).await;
+        assert!(res.is_ok());
+        assert!(res.unwrap().keys.contains(&group_alias_id.to_string()));
+    }
+
+    pub async fn test_read(client: &impl Client, group_alias_id: &str, group_id: &str) {
+        let res = group_alias::read(client, group_alias_id).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.name, "idp-admins");
+        assert_eq!(res.canonical_id, group_id);
+
+        // The alias is returned alongside the external group
+        let res = group::read(client, group_id).await;
+        assert_eq!(res.unwrap().alias.unwrap().id, group_alias_id);
+    }
+
+    pub async fn test_update(client: &impl Client, group_alias_id: &str, accessor: &str) {
+        let res = group_alias::update(client, group_alias_id, "idp-ops", accessor, None).await;
+        assert!(res.is_ok());
+
+        let res = group_alias::read(client, group_alias_id).await;
+        assert_eq!(res.unwrap().name, "idp-ops");
+    }
+}
+
+mod oidc {
+    use super::{Client, VaultServer, VaultServerHelper};
+    use vaultrs::api::auth::userpass::requests::CreateUserRequest;
+    use vaultrs::api::identity::requests::{CreateNamedKeyRequest, CreateRoleRequest};
+    use vaultrs::auth::userpass;
+    use vaultrs::identity::oidc::{self, key, role};
+    use vaultrs::sys::policy;
+
+    pub async fn test_token(server: &VaultServer, client: &impl Client) {
+        let res = key::create(
+            client,
+            "test",
+            Some(
+                CreateNamedKeyRequest::builder()
+                    .allowed_client_ids(vec!["*".to_string()])
+                    .verification_ttl("1h"),
+            ),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = key::read(client, "test").await.unwrap();
+        assert_eq!(res.algorithm, "RS256");
+        assert_eq!(res.allowed_client_ids, vec!["*".to_string()]);
+        assert_eq!(res.verification_ttl, 3600);
+
+        let res = key::list(client).await;
+        assert!(res.unwrap().keys.contains(&"test".to_string()));
+
+        let res = role::create(
+            client,
+            "test",
+            "test",
+            Some(CreateRoleRequest::builder().ttl("30m")),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = role::read(client, "test").await.unwrap();
+        assert_eq!(res.key, "test");
+        assert_eq!(res.ttl, 1800);
+        let client_id = res.client_id;
+
+        let res = role::list(client).await;
+        assert!(res.unwrap().keys.contains(&"test".to_string()));
+
+        // Tokens are issued for the entity of the client token, which the
+        // root token doesn't have
+        let res = policy::set(
+            client,
+            "oidc-token",
+            r#"path "identity/oidc/token/test" { capabilities = ["read"] }"#,
+        )
+        .await;
+        assert!(res.is_ok());
+        let res = userpass::user::set(
+            client,
+            "userpass_test",
+            "oidc",
+            "password",
+            Some(CreateUserRequest::builder().token_policies(vec!["oidc-token".to_string()])),
+        )
+        .await;
+        assert!(res.is_ok());
+        let auth = userpass::login(client, "userpass_test", "oidc", "password")
+            .await
+            .unwrap();
+        let mut entity_client = server.client();
+        entity_client.set_token(auth.client_token.as_str());
+
+        let res = oidc::generate_token(&entity_client, "test").await.unwrap();
+        assert_eq!(res.client_id, client_id);
+        assert_eq!(res.ttl, 1800);
+
+        // The token is signed by one of the published keys
+        let header = res.token.split('.').next().unwrap();
+        let header = base64::decode_config(header, base64::URL_SAFE_NO_PAD).unwrap();
+        let header: serde_json::Value = serde_json::from_slice(&header).unwrap();
+        let jwks = oidc::read_keys(client).await.unwrap();
+        assert!(jwks.keys.iter().any(|k| k.kid == header["kid"]));
+
+        // The previous key stays published for verification after a rotation
+        let res = key::rotate(client, "test", None).await;
+        assert!(res.is_ok());
+        let res = oidc::read_keys(client).await.unwrap();
+        assert!(res.keys.len() > jwks.keys.len());
+        assert!(res.keys.iter().any(|k| k.kid == header["kid"]));
+
+        let res = role::delete(client, "test").await;
+        assert!(res.is_ok());
+        let res = role::read(client, "test").await;
+        assert!(res.is_err());
+
+        let res = key::delete(client, "test").await;
+        assert!(res.is_ok());
+    }
+}
+
+async fn setup(server: &VaultServer, client: &impl Client) -> Result<String, ClientError> {
+    debug!("setting up UserPass auth engine for entity aliases");
+
+    let path = "userpass_test";
+
+    // Mount the UserPass auth engine
+    server.mount_auth(client, path, "userpass").await?;
+
+    // Aliases are linked to the auth engine by its accessor
+    auth::accessor(client, path).await
+}
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::identity;
+
+    #[test]
+    fn test_oidc_read_keys() {
+        // The key set isn't wrapped in the usual API response
+        let jwks = r#"{"keys":[{"use":"sig","kty":"RSA","kid":"0b4d6a1c","alg":"RS256","n":"qqvR","e":"AQAB"}]}"#;
+        let (address, requests) = mock_server(vec![response("200 OK", "", jwks)]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(identity::oidc::read_keys(&client));
+        let res = res.unwrap();
+        assert_eq!(res.keys.len(), 1);
+        assert_eq!(res.keys[0].kid, "0b4d6a1c");
+        assert_eq!(res.keys[0].key_use.as_deref(), Some("sig"));
+        assert_eq!(res.keys[0].e.as_deref(), Some("AQAB"));
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /v1/identity/oidc/.well-known/keys "));
+    }
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty; 8]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(identity::entity::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(identity::entity::list_by_name(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(identity::entity_alias::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(identity::group::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(identity::group::list_by_name(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(identity::group_alias::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(identity::oidc::key::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(identity::oidc::role::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/identity/entity/id HTTP/1.1"));
+    }
+}
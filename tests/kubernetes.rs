@@ -41,7 +41,7 @@ pub async fn test_configure(client: &impl Client, endpoint: &KubernetesRoleEndpo
         &endpoint.kubernetes_host,
         Some(
             &mut ConfigureKubernetesAuthRequest::builder()
-                .kubernetes_host(&format!("https://{}", &endpoint.kubernetes_host))
+                .kubernetes_host(format!("https://{}", &endpoint.kubernetes_host))
                 .kubernetes_ca_cert(include_str!("files/kubernetes/ca.crt"))
                 .issuer(&endpoint.jtw_issuer),
         ),
@@ -164,3 +164,22 @@ async fn setup(
         kubernetes_namespace: "testns".to_string(),
     })
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::auth::kubernetes;
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(kubernetes::role::list(&client, "kubernetes"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/auth/kubernetes/role HTTP/1.1"));
+    }
+}
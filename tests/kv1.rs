@@ -24,13 +24,13 @@ fn test_kv1() {
 
         // Create test secrets
         let expected_secret = HashMap::from([("key1", "value1"), ("key2", "value2")]);
-        kv1::set(&client, mount, &secret_path, &expected_secret)
+        kv1::set(&client, mount, secret_path, &expected_secret)
             .await
             .unwrap();
 
         // Read it
         let read_secret: HashMap<String, String> =
-            kv1::get(&client, &mount, &secret_path).await.unwrap();
+            kv1::get(&client, mount, secret_path).await.unwrap();
 
         println!("{:?}", read_secret);
 
@@ -45,7 +45,7 @@ fn test_kv1() {
 
         // Read it as raw value
         let read_secret_raw: GetSecretResponse =
-            kv1::get_raw(&client, &mount, &secret_path).await.unwrap();
+            kv1::get_raw(&client, mount, secret_path).await.unwrap();
 
         println!("{:?}", read_secret_raw);
 
@@ -59,16 +59,16 @@ fn test_kv1() {
         );
 
         // List secret keys
-        let list_secret = kv1::list(&client, &mount, "mysecret").await.unwrap();
+        let list_secret = kv1::list(&client, mount, "mysecret").await.unwrap();
 
         println!("{:?}", list_secret);
 
         assert_eq!(list_secret.data.keys, vec!["foo"]);
 
         // Delete secret and read again and expect 404 to check deletion
-        kv1::delete(&client, &mount, &secret_path).await.unwrap();
+        kv1::delete(&client, mount, secret_path).await.unwrap();
 
-        let r = kv1::get_raw(&client, &mount, &secret_path).await;
+        let r = kv1::get_raw(&client, mount, secret_path).await;
 
         match r.expect_err(&format!(
             "Expected error when reading {} after delete.",
@@ -82,6 +82,11 @@ fn test_kv1() {
             }
         };
 
+        let r: Option<HashMap<String, String>> = kv1::get_optional(&client, mount, secret_path)
+            .await
+            .unwrap();
+        assert!(r.is_none(), "Expected no secret after delete");
+
         let my_secrets = HashMap::from([("key1", "value1"), ("key2", "value2")]);
 
         kv1::set(&client, mount, "my/secrets", &my_secrets)
@@ -89,14 +94,58 @@ fn test_kv1() {
             .unwrap();
 
         let read_secrets: HashMap<String, String> =
-            kv1::get(&client, &mount, "my/secrets").await.unwrap();
+            kv1::get(&client, mount, "my/secrets").await.unwrap();
 
         println!("{:}", read_secrets.get("key1").unwrap()); // value1
 
-        let list_secret = kv1::list(&client, &mount, "my").await.unwrap();
+        let list_secret = kv1::list(&client, mount, "my").await.unwrap();
 
         println!("{:?}", list_secret.data.keys); // [ "secrets" ]
 
-        kv1::delete(&client, &mount, "my/secrets").await.unwrap();
+        kv1::delete(&client, mount, "my/secrets").await.unwrap();
+
+        // Read a base64 encoded value as bytes
+        let encoded = base64::encode([0u8, 159, 146, 150]);
+        let blobs = HashMap::from([("blob", encoded.as_str()), ("bad", "not base64!")]);
+        kv1::set(&client, mount, "my/blobs", &blobs).await.unwrap();
+
+        let bytes = kv1::read_bytes(&client, mount, "my/blobs", "blob")
+            .await
+            .unwrap();
+        assert_eq!(bytes, vec![0u8, 159, 146, 150]);
+
+        let res = kv1::read_bytes(&client, mount, "my/blobs", "missing").await;
+        assert!(matches!(
+            res,
+            Err(ClientError::DecodeError { source: None, .. })
+        ));
+
+        let res = kv1::read_bytes(&client, mount, "my/blobs", "bad").await;
+        assert!(matches!(
+            res,
+            Err(ClientError::DecodeError {
+                source: Some(_),
+                ..
+            })
+        ));
     });
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::kv1;
+
+    #[test]
+    fn test_list_empty() {
+        let (address, requests) =
+            mock_server(vec![response("404 Not Found", "", r#"{"errors":[]}"#)]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(kv1::list(&client, "secret", "missing"));
+        assert!(res.unwrap().data.keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/secret/missing HTTP/1.1"));
+    }
+}
@@ -5,6 +5,7 @@ mod common;
 
 use common::{VaultServer, VaultServerHelper};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use test_log::test;
 use vaultrs::api::kv2::requests::{SetSecretMetadataRequest, SetSecretRequestOptions};
 use vaultrs::client::Client;
@@ -22,11 +23,16 @@ fn test() {
         // Test set / read
         test_list(&client, &endpoint).await;
         test_read(&client, &endpoint).await;
+        test_read_bytes(&client, &endpoint).await;
         test_read_metadata(&client, &endpoint).await;
         test_read_version(&client, &endpoint).await;
+        test_read_with_metadata(&client, &endpoint).await;
         test_set(&client, &endpoint).await;
         test_set_with_compare_and_swap(&client, &endpoint).await;
         test_set_metadata(&client, &endpoint).await;
+        test_set_metadata_max_versions(&client, &endpoint).await;
+        test_patch(&client, &endpoint).await;
+        test_subkeys(&client, &endpoint).await;
 
         // Test delete
         test_delete_latest(&client, &endpoint).await;
@@ -41,6 +47,8 @@ fn test() {
         test_delete_metadata(&client, &endpoint).await;
         create(&client, &endpoint).await.unwrap();
 
+        test_version_lifecycle(&client, &endpoint).await;
+
         // Test config
         crate::config::test_set(&client, &endpoint).await;
         crate::config::test_read(&client, &endpoint).await;
@@ -53,8 +61,15 @@ async fn test_delete_latest(client: &impl Client, endpoint: &SecretEndpoint) {
 }
 
 async fn test_delete_metadata(client: &impl Client, endpoint: &SecretEndpoint) {
+    create(client, endpoint).await.unwrap();
     let res = kv2::delete_metadata(client, endpoint.path.as_str(), endpoint.name.as_str()).await;
     assert!(res.is_ok());
+
+    let res: Result<TestSecret, _> =
+        kv2::read(client, endpoint.path.as_str(), endpoint.name.as_str()).await;
+    assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+    let res = kv2::read_metadata(client, endpoint.path.as_str(), endpoint.name.as_str()).await;
+    assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
 }
 
 async fn test_delete_versions(client: &impl Client, endpoint: &SecretEndpoint) {
@@ -79,16 +94,135 @@ async fn test_destroy_versions(client: &impl Client, endpoint: &SecretEndpoint)
     assert!(res.is_ok());
 }
 
+async fn test_version_lifecycle(client: &impl Client, endpoint: &SecretEndpoint) {
+    let path = endpoint.path.as_str();
+    for value in ["one", "two", "three"] {
+        let secret = HashMap::from([("key", value)]);
+        kv2::set(client, path, "test-lifecycle", &secret)
+            .await
+            .unwrap();
+    }
+
+    // Soft-deleted versions can be recovered
+    let res = kv2::delete_versions(client, path, "test-lifecycle", vec![2]).await;
+    assert!(res.is_ok());
+    let res: Result<HashMap<String, String>, _> =
+        kv2::read_version(client, path, "test-lifecycle", 2).await;
+    assert!(res.is_err());
+    let meta = kv2::read_metadata(client, path, "test-lifecycle")
+        .await
+        .unwrap();
+    assert!(!meta.versions["2"].deletion_time.is_empty());
+    assert!(!meta.versions["2"].destroyed);
+
+    let res = kv2::undelete_versions(client, path, "test-lifecycle", vec![2]).await;
+    assert!(res.is_ok());
+    let res: HashMap<String, String> = kv2::read_version(client, path, "test-lifecycle", 2)
+        .await
+        .unwrap();
+    assert_eq!(res["key"], "two");
+
+    // Destroyed versions are gone for good
+    let res = kv2::destroy_versions(client, path, "test-lifecycle", vec![1]).await;
+    assert!(res.is_ok());
+    let res = kv2::undelete_versions(client, path, "test-lifecycle", vec![1]).await;
+    assert!(res.is_ok());
+    let res: Result<HashMap<String, String>, _> =
+        kv2::read_version(client, path, "test-lifecycle", 1).await;
+    assert!(res.is_err());
+    let meta = kv2::read_metadata(client, path, "test-lifecycle")
+        .await
+        .unwrap();
+    assert!(meta.versions["1"].destroyed);
+    assert!(!meta.versions["3"].destroyed);
+}
+
 async fn test_list(client: &impl Client, endpoint: &SecretEndpoint) {
     let res = kv2::list(client, endpoint.path.as_str(), "").await;
     assert!(res.is_ok());
     assert!(!res.unwrap().is_empty());
+
+    // Listing a path without any secrets returns an empty list
+    let res = kv2::list(client, endpoint.path.as_str(), "empty/").await;
+    assert!(res.unwrap().is_empty());
+}
+
+async fn test_patch(client: &impl Client, endpoint: &SecretEndpoint) {
+    let path = endpoint.path.as_str();
+    kv2::set(client, path, "test-patch", &endpoint.secret)
+        .await
+        .unwrap();
+
+    // Only the patched field changes
+    let res = kv2::patch(
+        client,
+        path,
+        "test-patch",
+        &serde_json::json!({"password": "patched"}),
+    )
+    .await;
+    assert!(res.is_ok());
+    let secret: TestSecret = kv2::read(client, path, "test-patch").await.unwrap();
+    assert_eq!(secret.key, endpoint.secret.key);
+    assert_eq!(secret.password, "patched");
+
+    // Null values remove keys
+    let res = kv2::patch(
+        client,
+        path,
+        "test-patch",
+        &serde_json::json!({"password": null}),
+    )
+    .await;
+    assert!(res.is_ok());
+    let secret: serde_json::Value = kv2::read(client, path, "test-patch").await.unwrap();
+    assert_eq!(secret, serde_json::json!({"key": endpoint.secret.key}));
+
+    // Patching a missing secret does not create it
+    let res = kv2::patch(client, path, "test-patch-missing", &endpoint.secret).await;
+    assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
 }
 
 async fn test_read(client: &impl Client, endpoint: &SecretEndpoint) {
     let res: Result<TestSecret, _> = kv2::read(client, endpoint.path.as_str(), "test").await;
     assert!(res.is_ok());
     assert_eq!(res.unwrap().key, endpoint.secret.key);
+
+    let res: Option<TestSecret> = kv2::read_optional(client, endpoint.path.as_str(), "test")
+        .await
+        .unwrap();
+    assert_eq!(res.unwrap().key, endpoint.secret.key);
+
+    let res: Option<TestSecret> = kv2::read_optional(client, endpoint.path.as_str(), "missing")
+        .await
+        .unwrap();
+    assert!(res.is_none());
+}
+
+async fn test_read_bytes(client: &impl Client, endpoint: &SecretEndpoint) {
+    let encoded = base64::encode([0u8, 159, 146, 150]);
+    let secret = HashMap::from([("blob", encoded.as_str()), ("bad", "not base64!")]);
+    kv2::set(client, endpoint.path.as_str(), "test-bytes", &secret)
+        .await
+        .unwrap();
+
+    let res = kv2::read_bytes(client, endpoint.path.as_str(), "test-bytes", "blob").await;
+    assert_eq!(res.unwrap(), vec![0u8, 159, 146, 150]);
+
+    let res = kv2::read_bytes(client, endpoint.path.as_str(), "test-bytes", "missing").await;
+    assert!(matches!(
+        res,
+        Err(ClientError::DecodeError { source: None, .. })
+    ));
+
+    let res = kv2::read_bytes(client, endpoint.path.as_str(), "test-bytes", "bad").await;
+    assert!(matches!(
+        res,
+        Err(ClientError::DecodeError {
+            source: Some(_),
+            ..
+        })
+    ));
 }
 
 async fn test_read_metadata(client: &impl Client, endpoint: &SecretEndpoint) {
@@ -104,6 +238,32 @@ async fn test_read_version(client: &impl Client, endpoint: &SecretEndpoint) {
     assert_eq!(res.unwrap().key, endpoint.secret.key);
 }
 
+async fn test_read_with_metadata(client: &impl Client, endpoint: &SecretEndpoint) {
+    let path = endpoint.path.as_str();
+    for value in ["one", "two", "three"] {
+        let secret = HashMap::from([("key", value)]);
+        kv2::set(client, path, "test-versions", &secret)
+            .await
+            .unwrap();
+    }
+
+    let res: Result<(HashMap<String, String>, _), _> =
+        kv2::read_with_metadata(client, path, "test-versions", Some(2)).await;
+    assert!(res.is_ok());
+    let (secret, metadata) = res.unwrap();
+    assert_eq!(secret["key"], "two");
+    assert_eq!(metadata.version, 2);
+    assert!(!metadata.destroyed);
+
+    // The latest version is read by default
+    let (secret, metadata): (HashMap<String, String>, _) =
+        kv2::read_with_metadata(client, path, "test-versions", None)
+            .await
+            .unwrap();
+    assert_eq!(secret["key"], "three");
+    assert_eq!(metadata.version, 3);
+}
+
 async fn test_set(client: &impl Client, endpoint: &SecretEndpoint) {
     let res = kv2::set(client, endpoint.path.as_str(), "test", &endpoint.secret).await;
     assert!(res.is_ok());
@@ -127,7 +287,18 @@ async fn test_set_with_compare_and_swap(client: &impl Client, endpoint: &SecretE
         SetSecretRequestOptions { cas: 0 },
     )
     .await;
-    assert!(res.is_err());
+    assert!(matches!(res, Err(ClientError::CheckAndSetFailed)));
+
+    // The write succeeds once the current version is given
+    let res = kv2::set_with_options(
+        client,
+        endpoint.path.as_str(),
+        "test-compare-and-swap",
+        &endpoint.secret,
+        SetSecretRequestOptions { cas: 1 },
+    )
+    .await;
+    assert_eq!(res.unwrap().version, 2);
 }
 
 async fn test_set_metadata(client: &impl Client, endpoint: &SecretEndpoint) {
@@ -139,6 +310,82 @@ async fn test_set_metadata(client: &impl Client, endpoint: &SecretEndpoint) {
     )
     .await;
     assert!(res.is_ok());
+
+    let res = kv2::read_metadata(client, endpoint.path.as_str(), endpoint.name.as_str()).await;
+    assert_eq!(res.unwrap().delete_version_after, "1h0m0s");
+}
+
+async fn test_set_metadata_max_versions(client: &impl Client, endpoint: &SecretEndpoint) {
+    let path = endpoint.path.as_str();
+    kv2::set(client, path, "test-max-versions", &endpoint.secret)
+        .await
+        .unwrap();
+
+    let custom_metadata = HashMap::from([("owner".to_string(), "ops".to_string())]);
+    let res = kv2::set_metadata(
+        client,
+        path,
+        "test-max-versions",
+        Some(
+            SetSecretMetadataRequest::builder()
+                .max_versions(2u64)
+                .custom_metadata(custom_metadata),
+        ),
+    )
+    .await;
+    assert!(res.is_ok());
+
+    // Older versions are pruned on subsequent writes
+    for _ in 0..2 {
+        kv2::set(client, path, "test-max-versions", &endpoint.secret)
+            .await
+            .unwrap();
+    }
+
+    let res = kv2::read_metadata(client, path, "test-max-versions")
+        .await
+        .unwrap();
+    assert_eq!(res.max_versions, 2);
+    assert_eq!(res.current_version, 3);
+    assert_eq!(res.oldest_version, 2);
+    assert_eq!(res.versions.len(), 2);
+    assert!(!res.versions.contains_key("1"));
+    assert_eq!(res.custom_metadata.unwrap()["owner"], "ops");
+
+    let res: Result<TestSecret, _> = kv2::read_version(client, path, "test-max-versions", 1).await;
+    assert!(res.is_err());
+}
+
+async fn test_subkeys(client: &impl Client, endpoint: &SecretEndpoint) {
+    let path = endpoint.path.as_str();
+    let secret = serde_json::json!({"nested": {"key": "value"}, "key": "value"});
+    kv2::set(client, path, "test-subkeys", &secret)
+        .await
+        .unwrap();
+    kv2::set(client, path, "test-subkeys", &endpoint.secret)
+        .await
+        .unwrap();
+
+    let res = kv2::subkeys(client, path, "test-subkeys", Some(1), None).await;
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        serde_json::json!({"nested": {"key": null}, "key": null})
+    );
+
+    let res = kv2::subkeys(client, path, "test-subkeys", Some(1), Some(1)).await;
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        serde_json::json!({"nested": null, "key": null})
+    );
+
+    let res = kv2::subkeys(client, path, "test-subkeys", None, None).await;
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        serde_json::json!({"key": null, "password": null})
+    );
 }
 
 async fn test_undelete_versions(client: &impl Client, endpoint: &SecretEndpoint) {
@@ -160,6 +407,10 @@ mod config {
         let resp = config::read(client, endpoint.path.as_str()).await;
 
         assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.max_versions, 100);
+        assert_eq!(resp.delete_version_after, "768h0m0s");
     }
 
     pub async fn test_set(client: &impl Client, endpoint: &SecretEndpoint) {
@@ -226,3 +477,316 @@ async fn setup(server: &VaultServer, client: &impl Client) -> Result<SecretEndpo
 
     Ok(endpoint)
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use vaultrs::api::kv2::requests::SetSecretRequestOptions;
+    use vaultrs::error::ClientError;
+    use vaultrs::kv2;
+
+    #[test]
+    fn test_list_recursive() {
+        let keys = |keys: &str| {
+            response(
+                "200 OK",
+                "",
+                &format!(
+                    r#"{{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{{"keys":{}}},"auth":null,"warnings":null,"wrap_info":null}}"#,
+                    keys
+                ),
+            )
+        };
+        let (address, requests) = mock_server(vec![
+            keys(r#"["app/","top"]"#),
+            keys(r#"["db/","config","web/"]"#),
+            keys(r#"["password"]"#),
+            response("404 Not Found", "", r#"{"errors":[]}"#),
+        ]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(kv2::list_recursive(&client, "secret", "")).unwrap();
+        assert_eq!(res, vec!["top", "app/config", "app/db/password"]);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[0].starts_with("LIST /v1/secret/metadata/ "));
+        assert!(requests[1].starts_with("LIST /v1/secret/metadata/app/ "));
+        assert!(requests[2].starts_with("LIST /v1/secret/metadata/app/db/ "));
+        assert!(requests[3].starts_with("LIST /v1/secret/metadata/app/web/ "));
+    }
+
+    #[test]
+    fn test_list_recursive_max_depth() {
+        // A tree which keeps nesting is only walked up to the maximum depth
+        let nested = response(
+            "200 OK",
+            "",
+            r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"keys":["a/"]},"auth":null,"warnings":null,"wrap_info":null}"#,
+        );
+        let (address, requests) = mock_server(vec![nested; kv2::MAX_LIST_DEPTH + 1]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(kv2::list_recursive(&client, "secret", "root"));
+        assert!(matches!(
+            res,
+            Err(ClientError::ListDepthExceeded { max_depth, .. }) if max_depth == kv2::MAX_LIST_DEPTH
+        ));
+        assert_eq!(requests.lock().unwrap().len(), kv2::MAX_LIST_DEPTH + 1);
+    }
+
+    #[test]
+    fn test_copy_tree() {
+        let envelope = |data: &str| {
+            format!(
+                r#"{{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{},"auth":null,"warnings":null,"wrap_info":null}}"#,
+                data
+            )
+        };
+        let metadata = r#"{"created_time":"2022-01-01T00:00:00Z","deletion_time":"","destroyed":false,"version":1}"#;
+        let secret = |data: &str| {
+            response(
+                "200 OK",
+                "",
+                &envelope(&format!(r#"{{"data":{},"metadata":{}}}"#, data, metadata)),
+            )
+        };
+        let (address, requests) = mock_server(vec![
+            response("200 OK", "", &envelope(r#"{"keys":["db/","config"]}"#)),
+            response("200 OK", "", &envelope(r#"{"keys":["password","user"]}"#)),
+            secret(r#"{"url":"https://example.com"}"#),
+            response("200 OK", "", &envelope(metadata)),
+            response(
+                "200 OK",
+                "",
+                &envelope(
+                    r#"{"cas_required":false,"created_time":"2022-01-01T00:00:00Z","current_version":1,"custom_metadata":{"owner":"ops"},"delete_version_after":"0s","max_versions":0,"oldest_version":0,"updated_time":"2022-01-01T00:00:00Z","versions":{}}"#,
+                ),
+            ),
+            response("204 No Content", "", ""),
+            response("403 Forbidden", "", r#"{"errors":["permission denied"]}"#),
+            secret(r#"{"name":"admin"}"#),
+            response("200 OK", "", &envelope(metadata)),
+            response(
+                "200 OK",
+                "",
+                &envelope(
+                    r#"{"cas_required":false,"created_time":"2022-01-01T00:00:00Z","current_version":1,"custom_metadata":null,"delete_version_after":"0s","max_versions":0,"oldest_version":0,"updated_time":"2022-01-01T00:00:00Z","versions":{}}"#,
+                ),
+            ),
+        ]);
+        let client = build_retry_client(&address);
+
+        let report = tokio_test::block_on(kv2::copy_tree(
+            &client, "secret", "app", "backup", "old/app", true,
+        ))
+        .unwrap();
+        assert!(!report.is_complete());
+        assert_eq!(report.copied, vec!["app/config", "app/db/user"]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "app/db/password");
+        assert!(matches!(
+            report.failed[0].1,
+            ClientError::APIError { code: 403, .. }
+        ));
+
+        let requests = requests.lock().unwrap();
+        let body = |i: usize| -> serde_json::Value {
+            serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        };
+        assert_eq!(requests.len(), 10);
+        assert!(requests[0].starts_with("LIST /v1/secret/metadata/app/ "));
+        assert!(requests[1].starts_with("LIST /v1/secret/metadata/app/db/ "));
+        assert!(requests[2].starts_with("GET /v1/secret/data/app/config?"));
+        assert!(requests[3].starts_with("POST /v1/backup/data/old/app/config "));
+        assert_eq!(
+            body(3)["data"],
+            serde_json::json!({"url": "https://example.com"})
+        );
+        assert!(requests[4].starts_with("GET /v1/secret/metadata/app/config "));
+        assert!(requests[5].starts_with("POST /v1/backup/metadata/old/app/config "));
+        assert_eq!(
+            body(5),
+            serde_json::json!({"custom_metadata": {"owner": "ops"}})
+        );
+        // A failed secret doesn't stop the others from being copied
+        assert!(requests[6].starts_with("GET /v1/secret/data/app/db/password?"));
+        assert!(requests[7].starts_with("GET /v1/secret/data/app/db/user?"));
+        assert!(requests[8].starts_with("POST /v1/backup/data/old/app/db/user "));
+        assert_eq!(body(8)["data"], serde_json::json!({"name": "admin"}));
+        // Secrets without custom metadata leave the destination untouched
+        assert!(requests[9].starts_with("GET /v1/secret/metadata/app/db/user "));
+    }
+
+    #[test]
+    fn test_read_many() {
+        // Answers each read with its path after a delay, while tracking how many
+        // reads are in flight
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let (current, max) = (in_flight.clone(), max_in_flight.clone());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(10) {
+                let (current, max) = (current.clone(), max.clone());
+                std::thread::spawn(move || {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut head = String::new();
+                    reader.read_line(&mut head).unwrap();
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let n = current.fetch_add(1, Ordering::SeqCst) + 1;
+                    max.fetch_max(n, Ordering::SeqCst);
+                    std::thread::sleep(Duration::from_millis(50));
+                    current.fetch_sub(1, Ordering::SeqCst);
+
+                    let path = head.split(' ').nth(1).unwrap();
+                    let path = path.trim_start_matches("/v1/secret/data/");
+                    let path = path.split('?').next().unwrap();
+                    let res = if path == "missing" {
+                        response("404 Not Found", "", r#"{"errors":[]}"#)
+                    } else {
+                        response(
+                            "200 OK",
+                            "",
+                            &format!(
+                                r#"{{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{{"data":{{"path":"{}"}},"metadata":{{"created_time":"","deletion_time":"","destroyed":false,"version":1}}}},"auth":null,"warnings":null,"wrap_info":null}}"#,
+                                path
+                            ),
+                        )
+                    };
+                    stream.write_all(res.as_bytes()).unwrap();
+                });
+            }
+        });
+        let client = build_retry_client(&address);
+
+        let mut paths: Vec<String> = (0..9).map(|i| format!("app/{}", i)).collect();
+        paths.insert(4, "missing".to_string());
+        let res = tokio_test::block_on(kv2::read_many::<HashMap<String, String>>(
+            &client,
+            "secret",
+            paths.clone(),
+            3,
+        ));
+
+        // The results are in the order of the paths and the missing secret
+        // doesn't abort the others
+        assert_eq!(res.len(), 10);
+        for ((path, secret), expected) in res.iter().zip(&paths) {
+            assert_eq!(path, expected);
+            if path == "missing" {
+                assert!(matches!(
+                    secret,
+                    Err(ClientError::APIError { code: 404, .. })
+                ));
+            } else {
+                assert_eq!(secret.as_ref().unwrap()["path"], *path);
+            }
+        }
+        let max = max_in_flight.load(Ordering::SeqCst);
+        assert!(max > 1 && max <= 3, "{} reads were in flight", max);
+    }
+
+    #[test]
+    fn test_read_with_metadata() {
+        let (address, requests) = mock_server(vec![response(
+            "200 OK",
+            "",
+            r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"data":{"key":"two"},"metadata":{"created_time":"2022-01-01T00:00:00Z","deletion_time":"","destroyed":false,"version":2}},"auth":null,"warnings":null,"wrap_info":null}"#,
+        )]);
+        let client = build_retry_client(&address);
+
+        let (secret, metadata): (HashMap<String, String>, _) =
+            tokio_test::block_on(kv2::read_with_metadata(&client, "secret", "app", Some(2)))
+                .unwrap();
+        assert_eq!(secret["key"], "two");
+        assert_eq!(metadata.version, 2);
+        assert_eq!(metadata.created_time, "2022-01-01T00:00:00Z");
+        assert!(requests.lock().unwrap()[0].starts_with("GET /v1/secret/data/app?version=2 "));
+    }
+
+    #[test]
+    fn test_read_metadata_versions() {
+        let (address, requests) = mock_server(vec![response(
+            "200 OK",
+            "",
+            r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"cas_required":false,"created_time":"2022-01-01T00:00:00Z","current_version":3,"custom_metadata":null,"delete_version_after":"0s","max_versions":0,"oldest_version":1,"updated_time":"2022-01-03T00:00:00Z","versions":{"1":{"created_time":"2022-01-01T00:00:00Z","deletion_time":"","destroyed":true},"2":{"created_time":"2022-01-02T00:00:00Z","deletion_time":"2022-01-04T00:00:00Z","destroyed":false},"3":{"created_time":"2022-01-03T00:00:00Z","deletion_time":"","destroyed":false}}},"auth":null,"warnings":null,"wrap_info":null}"#,
+        )]);
+        let client = build_retry_client(&address);
+
+        let meta = tokio_test::block_on(kv2::read_metadata(&client, "secret", "app")).unwrap();
+        assert_eq!(meta.current_version, 3);
+        assert_eq!(meta.oldest_version, 1);
+        assert_eq!(meta.versions.len(), 3);
+        assert!(meta.versions["1"].destroyed);
+        assert_eq!(meta.versions["2"].deletion_time, "2022-01-04T00:00:00Z");
+        assert!(!meta.versions["2"].destroyed);
+        assert!(meta.versions["3"].deletion_time.is_empty());
+        assert!(requests.lock().unwrap()[0].starts_with("GET /v1/secret/metadata/app "));
+    }
+
+    #[test]
+    fn test_delete_metadata() {
+        let not_found = r#"{"errors":[]}"#;
+        let (address, requests) = mock_server(vec![
+            response("204 No Content", "", ""),
+            response("404 Not Found", "", not_found),
+            response("404 Not Found", "", not_found),
+        ]);
+        let client = build_retry_client(&address);
+
+        tokio_test::block_on(kv2::delete_metadata(&client, "secret", "app")).unwrap();
+        let res: Result<HashMap<String, String>, _> =
+            tokio_test::block_on(kv2::read(&client, "secret", "app"));
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+        let res = tokio_test::block_on(kv2::read_metadata(&client, "secret", "app"));
+        assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("DELETE /v1/secret/metadata/app "));
+        assert!(requests[2].starts_with("GET /v1/secret/metadata/app "));
+    }
+
+    #[test]
+    fn test_check_and_set_failed() {
+        let body = r#"{"errors":["check-and-set parameter did not match the current version"]}"#;
+        let (address, _) = mock_server(vec![
+            response("400 Bad Request", "", body),
+            response("400 Bad Request", "", r#"{"errors":["other"]}"#),
+        ]);
+        let client = build_retry_client(&address);
+        let data = HashMap::from([("key", "value")]);
+
+        let res = tokio_test::block_on(kv2::set_with_options(
+            &client,
+            "secret",
+            "app",
+            &data,
+            SetSecretRequestOptions { cas: 0 },
+        ));
+        assert!(matches!(res, Err(ClientError::CheckAndSetFailed)));
+
+        // Other errors are returned as is
+        let res = tokio_test::block_on(kv2::set_with_options(
+            &client,
+            "secret",
+            "app",
+            &data,
+            SetSecretRequestOptions { cas: 0 },
+        ));
+        assert!(matches!(res, Err(ClientError::APIError { code: 400, .. })));
+    }
+}
@@ -0,0 +1,82 @@
+mod common;
+
+// Logging in requires an LDAP server, so the group and user mappings are
+// tested against a mock server instead of the Vault container.
+
+use common::{build_retry_client, mock_server, response};
+use vaultrs::auth;
+
+#[test]
+fn test_ldap() {
+    let envelope = |data: &str| {
+        format!(
+            r#"{{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{},"auth":null,"warnings":null,"wrap_info":null}}"#,
+            data
+        )
+    };
+    let (address, requests) = mock_server(vec![
+        response("204 No Content", "", ""),
+        response("200 OK", "", &envelope(r#"{"policies":["dev","ops"]}"#)),
+        response("204 No Content", "", ""),
+        response(
+            "200 OK",
+            "",
+            &envelope(r#"{"groups":"engineering,admins","policies":["personal"]}"#),
+        ),
+    ]);
+    let client = build_retry_client(&address);
+
+    tokio_test::block_on(auth::ldap::set_group(
+        &client,
+        "ldap",
+        "engineering",
+        vec!["dev".to_string(), "ops".to_string()],
+    ))
+    .unwrap();
+    let group =
+        tokio_test::block_on(auth::ldap::read_group(&client, "ldap", "engineering")).unwrap();
+    assert_eq!(group.policies, vec!["dev", "ops"]);
+
+    tokio_test::block_on(auth::ldap::set_user(
+        &client,
+        "ldap",
+        "jane",
+        vec!["engineering".to_string(), "admins".to_string()],
+        vec!["personal".to_string()],
+    ))
+    .unwrap();
+    let user = tokio_test::block_on(auth::ldap::read_user(&client, "ldap", "jane")).unwrap();
+    assert_eq!(user.groups, "engineering,admins");
+    assert_eq!(user.policies, vec!["personal"]);
+
+    let requests = requests.lock().unwrap();
+    let body = |i: usize| -> serde_json::Value {
+        serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+    };
+    assert!(requests[0].starts_with("POST /v1/auth/ldap/groups/engineering "));
+    assert_eq!(body(0), serde_json::json!({"policies": ["dev", "ops"]}));
+    assert!(requests[1].starts_with("GET /v1/auth/ldap/groups/engineering "));
+    assert!(requests[2].starts_with("POST /v1/auth/ldap/users/jane "));
+    assert_eq!(
+        body(2),
+        serde_json::json!({"groups": ["engineering", "admins"], "policies": ["personal"]})
+    );
+    assert!(requests[3].starts_with("GET /v1/auth/ldap/users/jane "));
+}
+
+#[test]
+fn test_list_empty() {
+    let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+    let (address, requests) = mock_server(vec![empty; 2]);
+    let client = build_retry_client(&address);
+
+    // Vault responds with a 404 when there is nothing to list
+    let res = tokio_test::block_on(auth::ldap::list_groups(&client, "ldap"));
+    assert!(res.unwrap().keys.is_empty());
+    let res = tokio_test::block_on(auth::ldap::list_users(&client, "ldap"));
+    assert!(res.unwrap().keys.is_empty());
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("LIST /v1/auth/ldap/groups HTTP/1.1"));
+    assert!(requests[1].starts_with("LIST /v1/auth/ldap/users HTTP/1.1"));
+}
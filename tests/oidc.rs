@@ -111,3 +111,22 @@ async fn setup(server: &VaultServer, client: &impl Client) -> Result<OIDCEndpoin
         role: role.to_string(),
     })
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::auth::oidc;
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(oidc::role::list(&client, "oidc"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/auth/oidc/role HTTP/1.1"));
+    }
+}
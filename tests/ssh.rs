@@ -36,6 +36,7 @@ fn test() {
         crate::ca::test_delete(&client, &endpoint).await;
         crate::ca::test_generate(&client, &endpoint).await;
         crate::ca::test_sign(&client, &endpoint).await;
+        crate::ca::test_sign_disallowed_key_type(&client, &endpoint).await;
 
         // Test generate
         test_generate_dyn(&client, &endpoint).await;
@@ -74,18 +75,50 @@ pub async fn test_generate_otp(client: &impl Client, endpoint: &SSHEndpoint) ->
     .await;
 
     assert!(res.is_ok());
-    res.unwrap().key
+
+    let res = res.unwrap();
+    assert_eq!(res.data.key_type, "otp");
+    assert_eq!(res.data.ip.as_deref(), Some("192.168.1.1"));
+    assert_eq!(res.data.username.as_deref(), Some("admin"));
+    assert_eq!(res.data.port, 22);
+    assert!(res.lease_id.starts_with(endpoint.path.as_str()));
+    assert!(res.lease_duration > 0);
+
+    // The IP must be within the role's CIDR list
+    let err = vaultrs::ssh::generate(
+        client,
+        endpoint.path.as_str(),
+        endpoint.otp_role.as_str(),
+        "10.0.0.1",
+        None,
+    )
+    .await;
+    assert!(matches!(err, Err(ClientError::APIError { code: 400, .. })));
+
+    res.data.key
 }
 
 pub async fn test_verify_otp(client: &impl Client, endpoint: &SSHEndpoint, otp: String) {
     let res = vaultrs::ssh::verify_otp(client, endpoint.path.as_str(), otp.as_str()).await;
     assert!(res.is_ok());
+
+    let res = res.unwrap();
+    assert_eq!(res.ip, "192.168.1.1");
+    assert_eq!(res.username, "admin");
+    assert_eq!(res.role_name.as_deref(), Some(endpoint.otp_role.as_str()));
+
+    // An OTP can only be used once
+    let res = vaultrs::ssh::verify_otp(client, endpoint.path.as_str(), otp.as_str()).await;
+    assert!(res.is_err());
 }
 
 pub mod ca {
-    use super::{Client, SSHEndpoint};
-    use std::fs;
-    use vaultrs::ssh::ca;
+    use super::{Client, ClientError, SSHEndpoint};
+    use std::{collections::HashMap, fs};
+    use vaultrs::{
+        api::ssh::requests::{SetRoleRequest, SignSSHKeyRequest},
+        ssh::{ca, role},
+    };
 
     pub async fn test_delete(client: &impl Client, endpoint: &SSHEndpoint) {
         let res = ca::delete(client, endpoint.path.as_str()).await;
@@ -113,6 +146,68 @@ pub mod ca {
         )
         .await;
         assert!(res.is_ok());
+
+        let mut extensions = HashMap::new();
+        extensions.insert("permit-pty".to_string(), "".to_string());
+
+        let public_key = fs::read_to_string("tests/files/id_ed25519.pub").unwrap();
+        let res = ca::sign(
+            client,
+            endpoint.path.as_str(),
+            endpoint.role.as_str(),
+            public_key.as_str(),
+            Some(
+                &mut SignSSHKeyRequest::builder()
+                    .cert_type("user")
+                    .extensions(extensions)
+                    .key_id("test-key")
+                    .ttl("1h")
+                    .valid_principals("admin"),
+            ),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert!(res
+            .signed_key
+            .starts_with("ssh-ed25519-cert-v01@openssh.com "));
+        assert!(!res.serial_number.is_empty());
+    }
+
+    pub async fn test_sign_disallowed_key_type(client: &impl Client, endpoint: &SSHEndpoint) {
+        let mut key_lengths = HashMap::new();
+        key_lengths.insert("rsa".to_string(), 2048);
+
+        let res = role::set(
+            client,
+            endpoint.path.as_str(),
+            "test_rsa",
+            Some(
+                &mut SetRoleRequest::builder()
+                    .key_type("ca")
+                    .allowed_users("*")
+                    .allow_user_certificates(true)
+                    .allowed_user_key_lengths(key_lengths),
+            ),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        // The role only allows RSA keys
+        let public_key = fs::read_to_string("tests/files/id_ed25519.pub").unwrap();
+        let res = ca::sign(
+            client,
+            endpoint.path.as_str(),
+            "test_rsa",
+            public_key.as_str(),
+            None,
+        )
+        .await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 400, .. })));
+
+        let res = role::delete(client, endpoint.path.as_str(), "test_rsa").await;
+        assert!(res.is_ok());
     }
 
     pub async fn test_submit(client: &impl Client, endpoint: &SSHEndpoint) {
@@ -180,7 +275,9 @@ mod role {
                 &mut SetRoleRequest::builder()
                     .key_type("ca")
                     .allowed_users("*")
-                    .allow_user_certificates(true),
+                    .allowed_extensions("permit-pty")
+                    .allow_user_certificates(true)
+                    .allow_user_key_ids(true),
             ),
         )
         .await;
@@ -268,3 +365,42 @@ async fn setup(server: &VaultServer, client: &impl Client) -> Result<SSHEndpoint
         otp_role: otp_role.to_string(),
     })
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::ssh;
+
+    #[test]
+    fn test_generate_lease() {
+        let body = r#"{"request_id":"1","lease_id":"ssh/creds/otp/abc","lease_duration":2764800,"renewable":false,"data":{"ip":"192.168.1.1","key":"2f7e25a2-24c9-4b7b-0d35-27d5e5203a5c","key_type":"otp","port":22,"username":"admin"},"auth":null,"warnings":null,"wrap_info":null}"#;
+        let (address, _) = mock_server(vec![response("200 OK", "", body)]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(ssh::generate(
+            &client,
+            "ssh",
+            "otp",
+            "192.168.1.1",
+            Some("admin".to_string()),
+        ))
+        .unwrap();
+        assert_eq!(res.lease_id, "ssh/creds/otp/abc");
+        assert_eq!(res.lease_duration, 2764800);
+        assert!(!res.renewable);
+        assert!(!format!("{:?}", res).contains("2f7e25a2"));
+    }
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(ssh::role::list(&client, "ssh"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/ssh/roles HTTP/1.1"));
+    }
+}
@@ -3,8 +3,12 @@ mod common;
 use common::{VaultServer, VaultServerHelper};
 use test_log::test;
 use vaultrs::{
-    api::{sys::requests::ListMountsRequest, ResponseWrapper},
+    api::{
+        sys::{requests::ListMountsRequest, MetricsFormat},
+        ResponseWrapper,
+    },
     client::Client,
+    error::ClientError,
     sys::{self},
 };
 
@@ -17,29 +21,60 @@ fn test() {
 
         // Test wrapping
         test_wrap(&client).await;
+        crate::wrapping::test_wrap_and_unwrap(&client).await;
+
+        // Test init
+        test_init(&client).await;
 
         // Test health
         test_health(&client).await;
 
         // Test status
         test_status(&client).await;
+        test_leader(&client).await;
+        test_metrics(&client).await;
 
         // Test mount
         crate::mount::test_create_mount(&client).await;
         crate::mount::test_list_mount(&client).await;
+        crate::mount::test_create_mount_with_options(&client).await;
+        crate::mount::test_tune_mount(&client).await;
+
+        // Test audit
+        crate::audit::test_enable_audit(&client).await;
+        crate::audit::test_list_audit(&client).await;
+        crate::audit::test_hash_audit(&client).await;
+        crate::audit::test_disable_audit(&client).await;
 
         // Test auth
         crate::auth::test_create_auth(&client).await;
         crate::auth::test_list_auth(&client).await;
+        crate::auth::test_accessor(&client).await;
+        crate::auth::test_tune_auth(&client).await;
+
+        // Test generating a root token and rekeying
+        crate::generate_root::test_start_and_cancel(&client).await;
+        crate::rekey::test_init_and_cancel(&client).await;
+
+        // Test plugins
+        crate::plugins::test_list(&client).await;
+        crate::plugins::test_read(&client).await;
 
         // Test policy
         crate::policy::test_set_policy(&client).await;
         crate::policy::test_read_policy(&client).await;
         crate::policy::test_list_policies(&client).await;
+
+        // Test capabilities
+        crate::capabilities::test_lookup(&client).await;
+        crate::capabilities::test_self(&client).await;
+
         crate::policy::test_delete_policy(&client).await;
 
         // Test sealing
+        test_seal_status(&client, false).await;
         test_seal(&client).await;
+        test_seal_status(&client, true).await;
     });
 }
 
@@ -64,35 +99,350 @@ async fn test_health(client: &impl Client) {
     assert!(resp.is_ok());
 }
 
+async fn test_init(client: &impl Client) {
+    let resp = sys::init_status(client).await;
+    assert!(resp.unwrap());
+
+    // The dev server is already initialized
+    let resp = sys::init(client, 1, 1, None).await;
+    assert!(matches!(resp, Err(ClientError::APIError { code: 400, .. })));
+}
+
+async fn test_leader(client: &impl Client) {
+    // The dev server uses in-memory storage which doesn't support HA
+    let resp = sys::leader(client).await.unwrap();
+    assert!(!resp.ha_enabled);
+    assert!(!resp.is_self);
+    assert!(resp.leader_address.is_empty());
+}
+
+async fn test_metrics(client: &impl Client) {
+    let resp = sys::metrics(client, MetricsFormat::Json).await;
+    assert!(resp.is_ok());
+
+    match resp.unwrap() {
+        sys::Metrics::Json(m) => assert!(!m.gauges.is_empty()),
+        m => panic!("Unexpected metrics: {:?}", m),
+    }
+}
+
 async fn test_seal(client: &impl Client) {
     let resp = sys::seal(client).await;
     assert!(resp.is_ok());
 }
 
+async fn test_seal_status(client: &impl Client, sealed: bool) {
+    let resp = sys::seal_status(client).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert!(resp.initialized);
+    assert_eq!(resp.sealed, sealed);
+}
+
 async fn test_status(client: &impl Client) {
     let resp = sys::status(client).await;
     assert!(resp.is_ok());
     assert!(matches!(resp.unwrap(), sys::ServerStatus::OK));
 }
 
+mod wrapping {
+    use super::Client;
+    use std::collections::HashMap;
+    use vaultrs::sys::wrapping;
+
+    pub async fn test_wrap_and_unwrap(client: &impl Client) {
+        let mut data = HashMap::new();
+        data.insert("key", "value");
+
+        let info = wrapping::wrap(client, &data, "5m").await;
+        assert!(info.is_ok());
+        let info = info.unwrap();
+        assert_eq!(info.ttl, 300);
+
+        let lookup = wrapping::lookup(client, info.token.as_str()).await;
+        assert!(lookup.is_ok());
+
+        // Rewrapping invalidates the original token
+        let rewrapped = wrapping::rewrap(client, info.token.as_str()).await;
+        assert!(rewrapped.is_ok());
+        let rewrapped = rewrapped.unwrap();
+        assert_ne!(rewrapped.token, info.token);
+        assert!(wrapping::lookup(client, info.token.as_str()).await.is_err());
+
+        let unwrapped: Result<HashMap<String, String>, _> =
+            wrapping::unwrap(client, Some(rewrapped.token.as_str())).await;
+        assert!(unwrapped.is_ok());
+        assert_eq!(unwrapped.unwrap()["key"], "value");
+    }
+}
+
+mod capabilities {
+    use super::Client;
+    use vaultrs::{api::token::requests::CreateTokenRequest, sys::capabilities, token};
+
+    pub async fn test_lookup(client: &impl Client) {
+        let auth = token::new(
+            client,
+            Some(CreateTokenRequest::builder().policies(vec!["test".to_string()])),
+        )
+        .await
+        .unwrap();
+        let paths = vec!["secret/data/app".to_string(), "sys/mounts".to_string()];
+
+        let resp = capabilities::lookup(client, auth.client_token.as_str(), paths).await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.len(), 2);
+        assert_eq!(resp["secret/data/app"], vec!["read"]);
+        assert_eq!(resp["sys/mounts"], vec!["deny"]);
+    }
+
+    pub async fn test_self(client: &impl Client) {
+        let paths = vec!["secret/data/app".to_string()];
+
+        let resp = capabilities::self_(client, paths).await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.len(), 1);
+        assert_eq!(resp["secret/data/app"], vec!["root"]);
+    }
+}
+
 mod mount {
     use super::Client;
-    use vaultrs::sys::mount;
+    use std::collections::HashMap;
+    use vaultrs::{
+        api::sys::requests::{EnableEngineRequest, TuneMountRequest},
+        kv2,
+        sys::mount,
+    };
 
     pub async fn test_create_mount(client: &impl Client) {
         let resp = mount::enable(client, "pki_temp", "pki", None).await;
         assert!(resp.is_ok());
     }
 
+    pub async fn test_create_mount_with_options(client: &impl Client) {
+        let resp = mount::enable(
+            client,
+            "kv2_temp",
+            "kv",
+            Some(
+                EnableEngineRequest::builder()
+                    .description("KV v2")
+                    .options(HashMap::from([("version".to_string(), "2".to_string())])),
+            ),
+        )
+        .await;
+        assert!(resp.is_ok());
+
+        let resp = mount::list(client).await.unwrap();
+        let mount = &resp["kv2_temp/"];
+        assert_eq!(mount.mount_type, "kv");
+        assert_eq!(mount.description, "KV v2");
+        assert_eq!(
+            mount
+                .options
+                .as_ref()
+                .unwrap()
+                .get("version")
+                .map(String::as_str),
+            Some("2")
+        );
+
+        // The mount can be used as a KV v2 engine
+        let secret = HashMap::from([("key", "value")]);
+        let resp = kv2::set(client, "kv2_temp", "app", &secret).await;
+        assert!(resp.is_ok());
+    }
+
     pub async fn test_list_mount(client: &impl Client) {
         let resp = mount::list(client).await;
         assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        let mount = &resp["pki_temp/"];
+        assert_eq!(mount.mount_type, "pki");
+        assert!(!mount.accessor.is_empty());
+    }
+
+    pub async fn test_tune_mount(client: &impl Client) {
+        let resp = mount::enable(client, "kv_temp", "kv", None).await;
+        assert!(resp.is_ok());
+
+        let resp = mount::tune(
+            client,
+            "kv_temp",
+            Some(
+                TuneMountRequest::builder()
+                    .default_lease_ttl("1h")
+                    .max_lease_ttl("7200")
+                    .listing_visibility("unauth"),
+            ),
+        )
+        .await;
+        assert!(resp.is_ok());
+
+        let resp = mount::read_tune(client, "kv_temp").await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.default_lease_ttl, 3600);
+        assert_eq!(resp.max_lease_ttl, 7200);
+        assert_eq!(resp.listing_visibility.as_deref(), Some("unauth"));
     }
 }
 
-mod auth {
+mod audit {
     use super::Client;
-    use vaultrs::sys::auth;
+    use std::collections::HashMap;
+    use vaultrs::{api::sys::requests::EnableAuditRequest, sys::audit};
+
+    pub async fn test_disable_audit(client: &impl Client) {
+        let resp = audit::disable(client, "file_temp").await;
+        assert!(resp.is_ok());
+
+        let resp = audit::list(client).await;
+        assert!(!resp.unwrap().contains_key("file_temp/"));
+    }
+
+    pub async fn test_enable_audit(client: &impl Client) {
+        let mut options = HashMap::new();
+        options.insert("file_path".to_string(), "/tmp/vault_audit.log".to_string());
+
+        let resp = audit::enable(
+            client,
+            "file_temp",
+            "file",
+            Some(
+                EnableAuditRequest::builder()
+                    .description("Test audit device")
+                    .options(options),
+            ),
+        )
+        .await;
+        assert!(resp.is_ok());
+    }
+
+    pub async fn test_hash_audit(client: &impl Client) {
+        let resp = audit::hash(client, "file_temp", "secret").await;
+        assert!(resp.is_ok());
+        assert!(resp.unwrap().starts_with("hmac-sha256:"));
+    }
+
+    pub async fn test_list_audit(client: &impl Client) {
+        let resp = audit::list(client).await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        let device = &resp["file_temp/"];
+        assert_eq!(device.device_type, "file");
+        assert_eq!(device.description, "Test audit device");
+        assert_eq!(
+            device.options.as_ref().unwrap()["file_path"],
+            "/tmp/vault_audit.log"
+        );
+    }
+}
+
+mod generate_root {
+    use super::Client;
+    use vaultrs::sys::generate_root;
+
+    pub async fn test_start_and_cancel(client: &impl Client) {
+        let resp = generate_root::start(client, None).await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert!(resp.started);
+        assert!(!resp.nonce.is_empty());
+        assert_eq!(resp.otp.len(), resp.otp_length as usize);
+
+        let resp = generate_root::read(client).await;
+        assert!(resp.unwrap().started);
+
+        let resp = generate_root::cancel(client).await;
+        assert!(resp.is_ok());
+
+        let resp = generate_root::read(client).await;
+        assert!(!resp.unwrap().started);
+    }
+}
+
+mod rekey {
+    use super::Client;
+    use vaultrs::sys::rekey;
+
+    pub async fn test_init_and_cancel(client: &impl Client) {
+        let resp = rekey::init(client, 3, 2, None).await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert!(resp.started);
+        assert_eq!(resp.threshold, 2);
+        assert_eq!(resp.n_shares, 3);
+
+        let resp = rekey::read(client).await;
+        assert!(resp.unwrap().started);
+
+        let resp = rekey::cancel(client).await;
+        assert!(resp.is_ok());
+
+        let resp = rekey::read(client).await;
+        assert!(!resp.unwrap().started);
+    }
+}
+
+mod plugins {
+    use super::Client;
+    use vaultrs::{api::sys::PluginType, sys::plugins};
+
+    pub async fn test_list(client: &impl Client) {
+        let resp = plugins::list(client).await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert!(resp.secret.contains(&"kv".to_string()));
+        assert!(resp.auth.contains(&"userpass".to_string()));
+    }
+
+    pub async fn test_read(client: &impl Client) {
+        let resp = plugins::read(client, PluginType::Secret, "kv").await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.name, "kv");
+        assert!(resp.builtin);
+    }
+}
+
+mod auth {
+    use super::{Client, ClientError};
+    use vaultrs::{
+        api::sys::requests::{EnableAuthDataConfigBuilder, EnableAuthRequest, TuneAuthRequest},
+        sys::auth,
+    };
+
+    pub async fn test_accessor(client: &impl Client) {
+        let resp = auth::accessor(client, "oidc_temp").await;
+        let accessor = resp.unwrap();
+        assert!(accessor.starts_with("auth_oidc_"));
+
+        let resp = auth::list(client).await.unwrap();
+        assert_eq!(resp["oidc_temp/"].accessor, accessor);
+
+        // A trailing slash is accepted as well
+        let resp = auth::accessor(client, "oidc_temp/").await;
+        assert_eq!(resp.unwrap(), accessor);
+
+        let resp = auth::accessor(client, "missing").await;
+        assert!(matches!(
+            resp,
+            Err(ClientError::MountNotFoundError { path }) if path == "missing"
+        ));
+    }
 
     pub async fn test_create_auth(client: &impl Client) {
         let resp = auth::enable(client, "oidc_temp", "oidc", None).await;
@@ -103,34 +453,853 @@ mod auth {
         let resp = auth::list(client).await;
         assert!(resp.is_ok());
     }
+
+    pub async fn test_tune_auth(client: &impl Client) {
+        let resp = auth::enable(
+            client,
+            "approle_temp",
+            "approle",
+            Some(
+                EnableAuthRequest::builder()
+                    .description("temporary")
+                    .config(
+                        EnableAuthDataConfigBuilder::default()
+                            .default_lease_ttl("30m")
+                            .max_lease_ttl("2h")
+                            .token_type("batch")
+                            .build()
+                            .unwrap(),
+                    ),
+            ),
+        )
+        .await;
+        assert!(resp.is_ok());
+
+        let resp = auth::list(client).await.unwrap();
+        let mount = &resp["approle_temp/"];
+        assert_eq!(mount.mount_type, "approle");
+        assert_eq!(mount.config.default_lease_ttl, 1800);
+        assert_eq!(mount.config.token_type, "batch");
+
+        let resp = auth::tune(
+            client,
+            "approle_temp",
+            Some(TuneAuthRequest::builder().default_lease_ttl("1h")),
+        )
+        .await;
+        assert!(resp.is_ok());
+
+        let resp = auth::read_tune(client, "approle_temp").await;
+        assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.default_lease_ttl, 3600);
+        assert_eq!(resp.max_lease_ttl, 7200);
+        assert_eq!(resp.description, "temporary");
+        assert_eq!(resp.token_type.as_deref(), Some("batch"));
+
+        let resp = auth::disable(client, "approle_temp").await;
+        assert!(resp.is_ok());
+
+        let resp = auth::list(client).await.unwrap();
+        assert!(!resp.contains_key("approle_temp/"));
+    }
 }
 
 mod policy {
-    use super::Client;
+    use super::{Client, ClientError};
     use vaultrs::sys::policy;
 
     pub async fn test_delete_policy(client: &impl Client) {
         let resp = policy::delete(client, "test").await;
         assert!(resp.is_ok());
+
+        let resp = policy::read(client, "test").await;
+        assert!(matches!(resp, Err(ClientError::APIError { code: 404, .. })));
     }
 
     pub async fn test_list_policies(client: &impl Client) {
         let resp = policy::list(client).await;
         assert!(resp.is_ok());
+        assert!(resp.unwrap().policies.contains(&"test".to_string()));
     }
 
     pub async fn test_read_policy(client: &impl Client) {
         let resp = policy::read(client, "test").await;
         assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.name, "test");
+        assert!(resp.rules.contains(r#"path "secret/data/app""#));
     }
 
     pub async fn test_set_policy(client: &impl Client) {
         let policy = r#"
-            path "sys" {
-                capabilities = ["list"]
+            path "secret/data/app" {
+                capabilities = ["read"]
             }"#;
 
         let resp = policy::set(client, "test", policy).await;
         assert!(resp.is_ok());
     }
 }
+
+mod mock {
+    use super::Client;
+    use crate::common::{
+        build_enterprise_client, build_retry_client, build_timeout_client, mock_server, response,
+    };
+    use std::net::TcpListener;
+    use vaultrs::api::sys::requests::{
+        RegisterPluginRequest, SetRaftAutopilotConfigurationRequest, StartRekeyRequest,
+    };
+    use vaultrs::api::sys::{EnforcementLevel, MetricsFormat, PluginReloadTarget, PluginType};
+    use vaultrs::error::ClientError;
+    use vaultrs::sys;
+
+    #[test]
+    fn test_health_status_codes() {
+        let cases = [
+            (
+                "200 OK",
+                r#""initialized":true,"sealed":false,"standby":false"#,
+                "OK",
+                "Active",
+            ),
+            (
+                "429 Too Many Requests",
+                r#""initialized":true,"sealed":false,"standby":true"#,
+                "STANDBY",
+                "Standby",
+            ),
+            (
+                "472 Data Recovery",
+                r#""initialized":true,"sealed":false,"standby":false,"replication_dr_mode":"secondary""#,
+                "RECOVERY",
+                "Recovery",
+            ),
+            (
+                "473 Performance Standby",
+                r#""initialized":true,"sealed":false,"standby":true,"performance_standby":true"#,
+                "PERFSTANDBY",
+                "PerformanceStandby",
+            ),
+            (
+                "501 Not Implemented",
+                r#""initialized":false,"sealed":true,"standby":true"#,
+                "UNINITIALIZED",
+                "Uninitialized",
+            ),
+            (
+                "503 Service Unavailable",
+                r#""initialized":true,"sealed":true,"standby":true"#,
+                "SEALED",
+                "Sealed",
+            ),
+        ];
+
+        for (status, fields, expected, expected_health) in cases {
+            let body = format!(
+                r#"{{{},"server_time_utc":1652000000,"version":"1.10.3"}}"#,
+                fields
+            );
+            let (address, requests) = mock_server(vec![
+                response(status, "", &body),
+                response(status, "", &body),
+                response(status, "", &body),
+            ]);
+            let client = build_retry_client(&address);
+
+            let health = tokio_test::block_on(sys::health(&client)).unwrap();
+            assert_eq!(health.version, "1.10.3");
+            let res = tokio_test::block_on(sys::status(&client)).unwrap();
+            assert_eq!(format!("{:?}", res), expected);
+            let res = tokio_test::block_on(client.health_status());
+            assert_eq!(format!("{:?}", res), expected_health);
+
+            // Health responses are never retried
+            assert_eq!(requests.lock().unwrap().len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_health_status_unreachable() {
+        // Nothing is listening on the address once the listener is dropped
+        let address = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let client = build_timeout_client(&address, 0);
+
+        let res = tokio_test::block_on(client.health_status());
+        assert!(matches!(res, sys::VaultHealth::Unreachable(_)));
+
+        // Unexpected responses are reported the same way
+        let (address, _) = mock_server(vec![response(
+            "500 Internal Server Error",
+            "",
+            r#"{"errors":["internal error"]}"#,
+        )]);
+        let client = build_timeout_client(&address, 0);
+        let res = tokio_test::block_on(sys::health_status(&client));
+        assert!(matches!(
+            res,
+            sys::VaultHealth::Unreachable(ClientError::APIError { code: 500, .. })
+        ));
+    }
+
+    #[test]
+    fn test_seal_status_and_unseal() {
+        let (address, requests) = mock_server(vec![
+            response(
+                "200 OK",
+                "",
+                r#"{"type":"shamir","initialized":true,"sealed":true,"t":3,"n":5,"progress":1,"nonce":"abc","version":"1.10.3","migration":false,"recovery_seal":false,"storage_type":"inmem"}"#,
+            ),
+            response(
+                "200 OK",
+                "",
+                r#"{"type":"shamir","initialized":true,"sealed":true,"t":3,"n":5,"progress":2,"nonce":"abc","version":"1.10.3","migration":false,"recovery_seal":false}"#,
+            ),
+        ]);
+        let client = build_retry_client(&address);
+
+        let status = tokio_test::block_on(sys::seal_status(&client)).unwrap();
+        assert!(status.sealed);
+        assert_eq!(status.seal_type, "shamir");
+        assert_eq!(status.threshold, 3);
+        assert_eq!(status.n_shares, 5);
+        assert_eq!(status.progress, 1);
+        assert_eq!(status.storage_type.as_deref(), Some("inmem"));
+
+        let res = tokio_test::block_on(sys::unseal(
+            &client,
+            Some("key-share".to_string()),
+            Some(true),
+            None,
+        ))
+        .unwrap();
+        assert_eq!(res.progress, 2);
+        assert_eq!(res.nonce, "abc");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /v1/sys/seal-status "));
+        assert!(!requests[0].to_lowercase().contains("x-vault-token"));
+        assert!(requests[1].starts_with("PUT /v1/sys/unseal "));
+        let body: serde_json::Value =
+            serde_json::from_str(requests[1].split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(body["key"], "key-share");
+        assert_eq!(body["reset"], true);
+    }
+
+    #[test]
+    fn test_leader() {
+        let (address, requests) = mock_server(vec![
+            response(
+                "200 OK",
+                "",
+                r#"{"ha_enabled":true,"is_self":false,"active_time":"2022-05-01T10:00:00Z","leader_address":"https://10.0.0.1:8200","leader_cluster_address":"https://10.0.0.1:8201","performance_standby":true,"performance_standby_last_remote_wal":42}"#,
+            ),
+            response(
+                "200 OK",
+                "",
+                r#"{"ha_enabled":false,"is_self":false,"leader_address":"","leader_cluster_address":""}"#,
+            ),
+        ]);
+        let client = build_retry_client(&address);
+
+        let leader = tokio_test::block_on(sys::leader(&client)).unwrap();
+        assert!(leader.ha_enabled);
+        assert!(!leader.is_self);
+        assert_eq!(leader.leader_address, "https://10.0.0.1:8200");
+        assert_eq!(leader.leader_cluster_address, "https://10.0.0.1:8201");
+        assert!(leader.performance_standby);
+        assert_eq!(leader.performance_standby_last_remote_wal, Some(42));
+
+        // Most fields are empty or missing if HA is disabled
+        let leader = tokio_test::block_on(sys::leader(&client)).unwrap();
+        assert!(!leader.ha_enabled);
+        assert!(leader.leader_address.is_empty());
+        assert!(!leader.performance_standby);
+        assert_eq!(leader.active_time, None);
+
+        // The request is sent without the token
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /v1/sys/leader "));
+        assert!(!requests[0].to_lowercase().contains("x-vault-token"));
+    }
+
+    #[test]
+    fn test_raft() {
+        let envelope = |data: &str| {
+            format!(
+                r#"{{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{},"auth":null,"warnings":null,"wrap_info":null}}"#,
+                data
+            )
+        };
+        let snapshot = "snapshot-archive";
+        let (address, requests) = mock_server(vec![
+            response(
+                "200 OK",
+                "",
+                &envelope(
+                    r#"{"config":{"index":0,"servers":[{"address":"10.0.0.1:8201","leader":true,"node_id":"raft1","protocol_version":"3","voter":true},{"address":"10.0.0.2:8201","leader":false,"node_id":"raft2","protocol_version":"3","voter":false}]}}"#,
+                ),
+            ),
+            response("204 No Content", "", ""),
+            response(
+                "200 OK",
+                "",
+                &envelope(
+                    r#"{"healthy":true,"failure_tolerance":1,"leader":"raft1","voters":["raft1","raft2","raft3"],"servers":{"raft1":{"id":"raft1","name":"raft1","address":"10.0.0.1:8201","node_status":"alive","last_contact":"0s","last_term":3,"last_index":61,"healthy":true,"stable_since":"2022-05-01T10:00:00Z","status":"leader","meta":null}}}"#,
+                ),
+            ),
+            response(
+                "200 OK",
+                "",
+                &envelope(
+                    r#"{"cleanup_dead_servers":false,"last_contact_threshold":"10s","dead_server_last_contact_threshold":"24h0m0s","max_trailing_logs":1000,"min_quorum":0,"server_stabilization_time":"10s","disable_upgrade_migration":false}"#,
+                ),
+            ),
+            response("204 No Content", "", ""),
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/gzip\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                snapshot.len(),
+                snapshot
+            ),
+            response("204 No Content", "", ""),
+            response("204 No Content", "", ""),
+        ]);
+        let client = build_retry_client(&address);
+
+        let config = tokio_test::block_on(sys::storage::raft::configuration(&client)).unwrap();
+        assert_eq!(config.config.servers.len(), 2);
+        assert_eq!(config.config.servers[0].node_id, "raft1");
+        assert!(config.config.servers[0].leader);
+        assert!(!config.config.servers[1].voter);
+
+        tokio_test::block_on(sys::storage::raft::remove_peer(&client, "raft2")).unwrap();
+
+        let state = tokio_test::block_on(sys::storage::raft::autopilot_state(&client)).unwrap();
+        assert!(state.healthy);
+        assert_eq!(state.failure_tolerance, 1);
+        assert_eq!(state.voters.len(), 3);
+        assert_eq!(state.servers["raft1"].status, "leader");
+        assert_eq!(state.servers["raft1"].last_index, 61);
+
+        let autopilot =
+            tokio_test::block_on(sys::storage::raft::autopilot_configuration(&client)).unwrap();
+        assert!(!autopilot.cleanup_dead_servers);
+        assert_eq!(autopilot.max_trailing_logs, 1000);
+
+        tokio_test::block_on(sys::storage::raft::set_autopilot_configuration(
+            &client,
+            Some(
+                &mut SetRaftAutopilotConfigurationRequest::builder()
+                    .cleanup_dead_servers(true)
+                    .min_quorum(3u64),
+            ),
+        ))
+        .unwrap();
+
+        // Snapshots are returned and sent as raw bytes
+        let res = tokio_test::block_on(sys::storage::raft::snapshot::save(&client)).unwrap();
+        assert_eq!(res, snapshot.as_bytes());
+        tokio_test::block_on(sys::storage::raft::snapshot::restore(&client, &res, false)).unwrap();
+        tokio_test::block_on(sys::storage::raft::snapshot::restore(&client, &res, true)).unwrap();
+
+        let requests = requests.lock().unwrap();
+        let body = |i: usize| -> serde_json::Value {
+            serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        };
+        assert!(requests[0].starts_with("GET /v1/sys/storage/raft/configuration "));
+        assert!(requests[1].starts_with("POST /v1/sys/storage/raft/remove-peer "));
+        assert_eq!(body(1)["server_id"], "raft2");
+        assert!(requests[2].starts_with("GET /v1/sys/storage/raft/autopilot/state "));
+        assert!(requests[3].starts_with("GET /v1/sys/storage/raft/autopilot/configuration "));
+        assert!(requests[4].starts_with("POST /v1/sys/storage/raft/autopilot/configuration "));
+        assert_eq!(body(4)["cleanup_dead_servers"], true);
+        assert_eq!(body(4)["min_quorum"], 3);
+        assert!(body(4).get("max_trailing_logs").is_none());
+        assert!(requests[5].starts_with("GET /v1/sys/storage/raft/snapshot "));
+        assert!(requests[6].starts_with("POST /v1/sys/storage/raft/snapshot "));
+        assert!(requests[6].ends_with(&format!("\r\n\r\n{}", snapshot)));
+        assert!(requests[7].starts_with("POST /v1/sys/storage/raft/snapshot-force "));
+        assert!(requests[7].ends_with(&format!("\r\n\r\n{}", snapshot)));
+    }
+
+    #[test]
+    fn test_plugins() {
+        let envelope = |data: &str| {
+            format!(
+                r#"{{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{},"auth":null,"warnings":null,"wrap_info":null}}"#,
+                data
+            )
+        };
+        let (address, requests) = mock_server(vec![
+            response("204 No Content", "", ""),
+            response(
+                "400 Bad Request",
+                "",
+                r#"{"errors":["error running plugin: checksums did not match"]}"#,
+            ),
+            response(
+                "200 OK",
+                "",
+                &envelope(
+                    r#"{"auth":["approle","my-plugin"],"database":["postgresql-database-plugin"],"secret":["kv"],"detailed":[{"name":"my-plugin","type":"auth","builtin":false,"version":""},{"name":"kv","type":"secret","builtin":true,"version":"v0.13.0+builtin","deprecation_status":"supported"}]}"#,
+                ),
+            ),
+            response(
+                "200 OK",
+                "",
+                &envelope(
+                    r#"{"name":"my-plugin","args":["--debug"],"builtin":false,"command":"my-plugin","sha256":"abc123","version":""}"#,
+                ),
+            ),
+            response("204 No Content", "", ""),
+            response("200 OK", "", &envelope(r#"{"reload_id":"1"}"#)),
+            response("204 No Content", "", ""),
+        ]);
+        let client = build_retry_client(&address);
+
+        tokio_test::block_on(sys::plugins::register(
+            &client,
+            PluginType::Auth,
+            "my-plugin",
+            "abc123",
+            "my-plugin",
+            Some(
+                RegisterPluginRequest::builder()
+                    .args(vec!["--debug".to_string()])
+                    .env(vec!["LOG_LEVEL=debug".to_string()]),
+            ),
+        ))
+        .unwrap();
+
+        // Vault verifies the checksum of the binary
+        let res = tokio_test::block_on(sys::plugins::register(
+            &client,
+            PluginType::Auth,
+            "my-plugin",
+            "wrong",
+            "my-plugin",
+            None,
+        ));
+        assert!(matches!(
+            res,
+            Err(ClientError::PluginChecksumMismatch { name }) if name == "my-plugin"
+        ));
+
+        let plugins = tokio_test::block_on(sys::plugins::list(&client)).unwrap();
+        assert_eq!(plugins.auth, vec!["approle", "my-plugin"]);
+        assert_eq!(plugins.secret, vec!["kv"]);
+        assert_eq!(plugins.detailed[0].plugin_type, PluginType::Auth);
+        assert!(!plugins.detailed[0].builtin);
+        assert!(plugins.detailed[1].builtin);
+
+        let plugin =
+            tokio_test::block_on(sys::plugins::read(&client, PluginType::Auth, "my-plugin"))
+                .unwrap();
+        assert_eq!(plugin.sha256, "abc123");
+        assert_eq!(plugin.args, vec!["--debug"]);
+
+        tokio_test::block_on(sys::plugins::deregister(
+            &client,
+            PluginType::Auth,
+            "my-plugin",
+        ))
+        .unwrap();
+        tokio_test::block_on(sys::plugins::reload(
+            &client,
+            PluginReloadTarget::Plugin("my-plugin".to_string()),
+        ))
+        .unwrap();
+        tokio_test::block_on(sys::plugins::reload(
+            &client,
+            PluginReloadTarget::Mounts(vec!["auth/my-plugin".to_string()]),
+        ))
+        .unwrap();
+
+        let requests = requests.lock().unwrap();
+        let body = |i: usize| -> serde_json::Value {
+            serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        };
+        assert!(requests[0].starts_with("POST /v1/sys/plugins/catalog/auth/my-plugin "));
+        assert_eq!(
+            body(0),
+            serde_json::json!({
+                "sha256": "abc123",
+                "command": "my-plugin",
+                "args": ["--debug"],
+                "env": ["LOG_LEVEL=debug"]
+            })
+        );
+        assert_eq!(
+            body(1),
+            serde_json::json!({"sha256": "wrong", "command": "my-plugin"})
+        );
+        assert!(requests[2].starts_with("GET /v1/sys/plugins/catalog "));
+        assert!(requests[3].starts_with("GET /v1/sys/plugins/catalog/auth/my-plugin "));
+        assert!(requests[4].starts_with("DELETE /v1/sys/plugins/catalog/auth/my-plugin "));
+        assert!(requests[5].starts_with("POST /v1/sys/plugins/reload/backend "));
+        assert_eq!(body(5), serde_json::json!({"plugin": "my-plugin"}));
+        assert_eq!(body(6), serde_json::json!({"mounts": ["auth/my-plugin"]}));
+    }
+
+    #[test]
+    fn test_generate_root() {
+        let status = |progress: u8, rest: &str| {
+            format!(
+                r#"{{"started":true,"nonce":"nonce-1","progress":{},"required":2,"pgp_fingerprint":"",{}}}"#,
+                progress, rest
+            )
+        };
+        let (address, requests) = mock_server(vec![
+            response(
+                "200 OK",
+                "",
+                &status(
+                    0,
+                    r#""complete":false,"encoded_token":"","otp":"aBcDeFgHiJkLmNoPqRsTuVwXyZ01","otp_length":28"#,
+                ),
+            ),
+            response(
+                "200 OK",
+                "",
+                &status(1, r#""complete":false,"encoded_token":"","otp_length":28"#),
+            ),
+            response(
+                "200 OK",
+                "",
+                &status(
+                    2,
+                    r#""complete":true,"encoded_token":"CTQQalV3VXtdf117VXcOMhI2FjISPh4yEjZdXw","otp_length":28"#,
+                ),
+            ),
+            response(
+                "200 OK",
+                "",
+                r#"{"started":false,"nonce":"","progress":0,"required":2,"complete":false,"encoded_token":"","otp_length":28}"#,
+            ),
+            response("204 No Content", "", ""),
+        ]);
+        let client = build_retry_client(&address);
+
+        let attempt = tokio_test::block_on(sys::generate_root::start(&client, None)).unwrap();
+        assert!(attempt.started);
+        assert!(!attempt.complete);
+        assert_eq!(attempt.required, 2);
+        assert_eq!(attempt.otp.len(), attempt.otp_length as usize);
+
+        // Each key share progresses the attempt until the encoded token is returned
+        let res = tokio_test::block_on(sys::generate_root::update(
+            &client,
+            "share-1",
+            &attempt.nonce,
+        ))
+        .unwrap();
+        assert_eq!(res.progress, 1);
+        assert!(!res.complete);
+        assert!(res.encoded_token.is_empty());
+
+        let res = tokio_test::block_on(sys::generate_root::update(
+            &client,
+            "share-2",
+            &attempt.nonce,
+        ))
+        .unwrap();
+        assert_eq!(res.progress, 2);
+        assert!(res.complete);
+        assert!(!format!("{:?}", res).contains(&res.encoded_token));
+        assert!(!format!("{:?}", attempt).contains(&attempt.otp));
+
+        let token = sys::generate_root::decode_token(&res.encoded_token, &attempt.otp).unwrap();
+        assert_eq!(token, "hvs.0123456789abcdefghijklmn");
+        assert!(matches!(
+            sys::generate_root::decode_token(&res.encoded_token, "short"),
+            Err(ClientError::DecodeError { .. })
+        ));
+
+        let res = tokio_test::block_on(sys::generate_root::read(&client)).unwrap();
+        assert!(!res.started);
+        tokio_test::block_on(sys::generate_root::cancel(&client)).unwrap();
+
+        let requests = requests.lock().unwrap();
+        let body = |i: usize| -> serde_json::Value {
+            serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        };
+        assert!(requests[0].starts_with("PUT /v1/sys/generate-root/attempt "));
+        assert!(requests[1].starts_with("PUT /v1/sys/generate-root/update "));
+        assert_eq!(
+            body(1),
+            serde_json::json!({"key": "share-1", "nonce": "nonce-1"})
+        );
+        assert_eq!(body(2)["key"], "share-2");
+        assert!(requests[3].starts_with("GET /v1/sys/generate-root/attempt "));
+        assert!(requests[4].starts_with("DELETE /v1/sys/generate-root/attempt "));
+    }
+
+    #[test]
+    fn test_rekey() {
+        let (address, requests) = mock_server(vec![
+            response(
+                "200 OK",
+                "",
+                r#"{"started":true,"nonce":"nonce-1","t":2,"n":3,"progress":0,"required":2,"pgp_fingerprints":null,"backup":false,"verification_required":true}"#,
+            ),
+            response(
+                "200 OK",
+                "",
+                r#"{"started":true,"nonce":"nonce-1","t":2,"n":3,"progress":1,"required":2,"pgp_fingerprints":null,"backup":false,"verification_required":true}"#,
+            ),
+            response(
+                "200 OK",
+                "",
+                r#"{"complete":true,"nonce":"nonce-1","keys":["new-1","new-2","new-3"],"keys_base64":["bmV3LTE=","bmV3LTI=","bmV3LTM="],"pgp_fingerprints":null,"backup":false,"verification_required":true,"verification_nonce":"nonce-2"}"#,
+            ),
+            response(
+                "200 OK",
+                "",
+                r#"{"nonce":"nonce-2","started":true,"t":2,"n":3,"progress":1}"#,
+            ),
+            response("200 OK", "", r#"{"nonce":"nonce-2","complete":true}"#),
+            response("204 No Content", "", ""),
+        ]);
+        let client = build_retry_client(&address);
+
+        let rekey = tokio_test::block_on(sys::rekey::init(
+            &client,
+            3,
+            2,
+            Some(StartRekeyRequest::builder().require_verification(true)),
+        ))
+        .unwrap();
+        assert!(rekey.started);
+        assert_eq!(rekey.threshold, 2);
+        assert_eq!(rekey.n_shares, 3);
+        assert!(rekey.verification_required);
+
+        // The new shares are only returned once enough current shares are given
+        let res = tokio_test::block_on(sys::rekey::update(&client, "old-1", &rekey.nonce)).unwrap();
+        assert!(!res.complete);
+        assert_eq!(res.progress, 1);
+        assert!(res.keys.is_empty());
+
+        let res = tokio_test::block_on(sys::rekey::update(&client, "old-2", &rekey.nonce)).unwrap();
+        assert!(res.complete);
+        assert_eq!(res.keys, vec!["new-1", "new-2", "new-3"]);
+        assert!(!format!("{:?}", res).contains("new-1"));
+
+        // The new shares take effect once verified
+        let nonce = res.verification_nonce.unwrap();
+        let res = tokio_test::block_on(sys::rekey::verify(&client, "new-1", &nonce)).unwrap();
+        assert!(!res.complete);
+        assert_eq!(res.progress, 1);
+        let res = tokio_test::block_on(sys::rekey::verify(&client, "new-2", &nonce)).unwrap();
+        assert!(res.complete);
+
+        tokio_test::block_on(sys::rekey::cancel(&client)).unwrap();
+
+        let requests = requests.lock().unwrap();
+        let body = |i: usize| -> serde_json::Value {
+            serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        };
+        assert!(requests[0].starts_with("PUT /v1/sys/rekey/init "));
+        assert_eq!(
+            body(0),
+            serde_json::json!({"secret_shares": 3, "secret_threshold": 2, "require_verification": true})
+        );
+        assert!(requests[1].starts_with("PUT /v1/sys/rekey/update "));
+        assert_eq!(
+            body(2),
+            serde_json::json!({"key": "old-2", "nonce": "nonce-1"})
+        );
+        assert!(requests[3].starts_with("PUT /v1/sys/rekey/verify "));
+        assert_eq!(
+            body(4),
+            serde_json::json!({"key": "new-2", "nonce": "nonce-2"})
+        );
+        assert!(requests[5].starts_with("DELETE /v1/sys/rekey/init "));
+    }
+
+    #[test]
+    fn test_metrics() {
+        let prometheus = "# HELP vault_core_unsealed vault_core_unsealed\n# TYPE vault_core_unsealed gauge\nvault_core_unsealed{cluster=\"vault\"} 1\n";
+        let (address, requests) = mock_server(vec![
+            response(
+                "200 OK",
+                "",
+                r#"{"Timestamp":"2022-05-01 10:00:00 +0000 UTC","Gauges":[{"Name":"vault.core.unsealed","Value":1,"Labels":{"cluster":"vault"}}],"Points":[],"Counters":[{"Name":"vault.core.handle_request","Count":2,"Rate":0.2,"Sum":2,"Min":1,"Max":1,"Mean":1,"Stddev":0,"Labels":{}}],"Samples":[{"Name":"vault.barrier.get","Count":4,"Rate":0.01,"Sum":0.1,"Min":0.02,"Max":0.03,"Mean":0.025,"Stddev":0.005,"Labels":{}}]}"#,
+            ),
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                prometheus.len(),
+                prometheus
+            ),
+        ]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(sys::metrics(&client, MetricsFormat::Json)).unwrap();
+        let metrics = match res {
+            sys::Metrics::Json(m) => m,
+            m => panic!("Unexpected metrics: {:?}", m),
+        };
+        assert_eq!(metrics.gauges[0].name, "vault.core.unsealed");
+        assert_eq!(metrics.gauges[0].value, 1.0);
+        assert_eq!(metrics.gauges[0].labels["cluster"], "vault");
+        assert_eq!(metrics.counters[0].count, 2);
+        assert_eq!(metrics.samples[0].mean, 0.025);
+
+        // The Prometheus exposition is returned untouched
+        let res = tokio_test::block_on(sys::metrics(&client, MetricsFormat::Prometheus)).unwrap();
+        assert!(matches!(res, sys::Metrics::Prometheus(m) if m == prometheus));
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /v1/sys/metrics?format=json "));
+        assert!(requests[1].starts_with("GET /v1/sys/metrics?format=prometheus "));
+    }
+
+    #[test]
+    fn test_init() {
+        let errors = r#"{"errors":["Vault is already initialized"]}"#;
+        let (address, requests) = mock_server(vec![
+            response("200 OK", "", r#"{"initialized":false}"#),
+            response(
+                "200 OK",
+                "",
+                r#"{"keys":["unseal-key"],"keys_base64":["unseal-key-base64"],"root_token":"s.root-token"}"#,
+            ),
+            response("400 Bad Request", "", errors),
+        ]);
+        let client = build_retry_client(&address);
+
+        assert!(!tokio_test::block_on(sys::init_status(&client)).unwrap());
+
+        let res = tokio_test::block_on(sys::init(&client, 1, 1, None)).unwrap();
+        assert_eq!(res.keys, vec!["unseal-key"]);
+        assert_eq!(res.root_token, "s.root-token");
+        let debug = format!("{:?}", res);
+        assert!(!debug.contains("unseal-key"));
+        assert!(!debug.contains("s.root-token"));
+
+        let res = tokio_test::block_on(sys::init(&client, 1, 1, None));
+        assert!(matches!(res, Err(ClientError::APIError { code: 400, .. })));
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[1].starts_with("PUT /v1/sys/init "));
+        let body: serde_json::Value =
+            serde_json::from_str(requests[1].split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(body["secret_shares"], 1);
+        assert_eq!(body["secret_threshold"], 1);
+    }
+
+    #[test]
+    fn test_sentinel_policies() {
+        let unsupported = r#"{"errors":["1 error occurred:\n\t* unsupported path\n\n"]}"#;
+        let (address, requests) = mock_server(vec![
+            response("204 No Content", "", ""),
+            response("204 No Content", "", ""),
+            response("404 Not Found", "", r#"{"errors":[]}"#),
+            response("404 Not Found", "", r#"{"errors":[]}"#),
+            response("404 Not Found", "", unsupported),
+        ]);
+        let client = build_enterprise_client(&address);
+
+        let res = tokio_test::block_on(sys::policies::rgp::set(
+            &client,
+            "business-hours",
+            "main = rule { true }",
+            EnforcementLevel::SoftMandatory,
+        ));
+        assert!(res.is_ok());
+
+        let res = tokio_test::block_on(sys::policies::egp::set(
+            &client,
+            "cidr-check",
+            "main = rule { false }",
+            EnforcementLevel::HardMandatory,
+            vec!["secret/*".to_string()],
+        ));
+        assert!(res.is_ok());
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(sys::policies::rgp::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+        let res = tokio_test::block_on(sys::policies::egp::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+
+        // Vault OSS doesn't support Sentinel policies
+        let res = tokio_test::block_on(sys::policies::rgp::list(&client));
+        assert!(matches!(res, Err(ClientError::EnterpriseOnlyError)));
+
+        let requests = requests.lock().unwrap();
+        let body = |i: usize| -> serde_json::Value {
+            serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        };
+        assert!(requests[0].starts_with("PUT /v1/sys/policies/rgp/business-hours "));
+        assert_eq!(
+            body(0),
+            serde_json::json!({
+                "policy": "main = rule { true }",
+                "enforcement_level": "soft-mandatory",
+            })
+        );
+        assert!(requests[1].starts_with("PUT /v1/sys/policies/egp/cidr-check "));
+        assert_eq!(
+            body(1),
+            serde_json::json!({
+                "policy": "main = rule { false }",
+                "enforcement_level": "hard-mandatory",
+                "paths": ["secret/*"],
+            })
+        );
+        assert!(requests[2].starts_with("LIST /v1/sys/policies/rgp "));
+        assert!(requests[3].starts_with("LIST /v1/sys/policies/egp "));
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let body = r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"capabilities":["read"],"secret/data/app":["read"]},"auth":null,"warnings":null,"wrap_info":null}"#;
+        let (address, requests) = mock_server(vec![response("200 OK", "", body)]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(sys::capabilities::lookup(
+            &client,
+            "other",
+            vec!["secret/data/app".to_string()],
+        ));
+        assert!(res.is_ok());
+
+        // The `capabilities` key isn't a requested path
+        let res = res.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res["secret/data/app"], vec!["read"]);
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /v1/sys/capabilities "));
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({"paths": ["secret/data/app"], "token": "other"})
+        );
+    }
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty; 2]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(sys::policy::list(&client));
+        assert!(res.unwrap().policies.is_empty());
+        let res = tokio_test::block_on(sys::leases::list(&client, "database/creds/app/"));
+        assert!(res.unwrap().is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/sys/policies/acl HTTP/1.1"));
+        assert!(requests[1].starts_with("LIST /v1/sys/leases/lookup/database/creds/app/ HTTP/1.1"));
+    }
+}
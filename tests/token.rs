@@ -4,8 +4,11 @@ extern crate tracing;
 mod common;
 
 use common::{VaultServer, VaultServerHelper};
+use std::sync::Arc;
+use std::time::Duration;
 use test_log::test;
-use vaultrs::client::Client;
+use tokio::sync::RwLock;
+use vaultrs::client::{renew::AutoRenewer, Client};
 use vaultrs::{api::token::requests::CreateTokenRequest, error::ClientError, token};
 
 #[test]
@@ -20,6 +23,7 @@ fn test() {
         crate::role::test_set(&client, "test").await;
         crate::role::test_list(&client).await;
         crate::role::test_read(&client, "test").await;
+        crate::role::test_new_role(&client, "test").await;
         crate::role::test_delete(&client, "test").await;
 
         // Test tidy
@@ -29,6 +33,9 @@ fn test() {
         test_new(&client).await;
         test_new_orphan(&client).await;
 
+        // Test listing accessors
+        test_list_accessors(&client, token.accessor.as_str()).await;
+
         // Test looking up tokens
         test_lookup(&client, token.token.as_str()).await;
         test_lookup_self(&client).await;
@@ -38,11 +45,15 @@ fn test() {
         test_renew(&client, token.token.as_str()).await;
         test_renew_self(&client).await;
         test_renew_accessor(&client, token.accessor.as_str()).await;
+        test_auto_renew(&server).await;
+
+        // Test the lifecycle of a child token
+        test_child_lifecycle(&server).await;
 
         // Test revoking tokens
         test_revoke(&client, token.token.as_str()).await;
         token = setup(&client).await.unwrap();
-        test_revoke_accessor(&client, token.accessor.as_str()).await;
+        test_revoke_accessor(&client, &token).await;
         token = setup(&client).await.unwrap();
         test_revoke_orphan(&client, token.token.as_str()).await;
 
@@ -50,6 +61,19 @@ fn test() {
     });
 }
 
+pub async fn test_list_accessors(client: &impl Client, accessor: &str) {
+    let resp = token::list_accessors(client).await;
+    assert!(resp.is_ok());
+    let before = resp.unwrap();
+    assert!(before.iter().any(|a| a == accessor));
+
+    // New tokens are listed
+    setup(client).await.unwrap();
+    setup(client).await.unwrap();
+    let after = token::list_accessors(client).await.unwrap();
+    assert_eq!(after.len(), before.len() + 2);
+}
+
 pub async fn test_lookup(client: &impl Client, token: &str) {
     let resp = token::lookup(client, token).await;
     assert!(resp.is_ok());
@@ -58,6 +82,11 @@ pub async fn test_lookup(client: &impl Client, token: &str) {
 pub async fn test_lookup_accessor(client: &impl Client, accessor: &str) {
     let resp = token::lookup_accessor(client, accessor).await;
     assert!(resp.is_ok());
+
+    // The token itself isn't revealed through its accessor
+    let resp = resp.unwrap();
+    assert_eq!(resp.accessor, accessor);
+    assert!(resp.id.is_empty());
 }
 
 pub async fn test_lookup_self(client: &impl Client) {
@@ -93,14 +122,87 @@ pub async fn test_renew_self(client: &impl Client) {
     }
 }
 
+pub async fn test_auto_renew(server: &VaultServer) {
+    let root = server.client();
+    let resp = token::new(
+        &root,
+        Some(CreateTokenRequest::builder().ttl("3s").renewable(true)),
+    )
+    .await
+    .unwrap();
+
+    let mut client = server.client();
+    client.set_token(resp.client_token.as_str());
+    let client = Arc::new(RwLock::new(client));
+
+    // The token outlives its original TTL while being renewed
+    let mut handle = AutoRenewer::new(client.clone())
+        .fraction(0.5)
+        .increment("3s")
+        .start();
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    assert!(client.read().await.lookup().await.is_ok());
+
+    // Revoking the token makes the renewal fail permanently
+    token::revoke(&root, resp.client_token.as_str())
+        .await
+        .unwrap();
+    let err = handle.error().await;
+    assert!(matches!(err, Some(ClientError::APIError { .. })));
+    handle.stop();
+}
+
+pub async fn test_child_lifecycle(server: &VaultServer) {
+    let root = server.client();
+    let resp = token::new(
+        &root,
+        Some(
+            CreateTokenRequest::builder()
+                .policies(vec!["default".to_string()])
+                .ttl("1m")
+                .renewable(true)
+                .num_uses(0u64)
+                .display_name("child"),
+        ),
+    )
+    .await;
+    assert!(resp.is_ok());
+    let child = resp.unwrap().client_token;
+
+    let mut client = server.client();
+    client.set_token(child.as_str());
+
+    // The child token is tied to its parent
+    let lookup = token::lookup_self(&client).await.unwrap();
+    assert!(!lookup.orphan);
+    assert!(lookup.renewable);
+    assert!(lookup.ttl <= 60);
+    assert_eq!(lookup.display_name, "token-child");
+
+    let renewed = token::renew_self(&client, Some("5m")).await;
+    assert!(renewed.is_ok());
+    assert!(renewed.unwrap().lease_duration > 60);
+
+    let resp = token::revoke_self(&client).await;
+    assert!(resp.is_ok());
+
+    let resp = token::lookup(&root, child.as_str()).await;
+    assert!(resp.is_err());
+}
+
 pub async fn test_revoke(client: &impl Client, token: &str) {
     let resp = token::revoke(client, token).await;
     assert!(resp.is_ok());
 }
 
-pub async fn test_revoke_accessor(client: &impl Client, accessor: &str) {
-    let resp = token::revoke_accessor(client, accessor).await;
+async fn test_revoke_accessor(client: &impl Client, token: &Token) {
+    let resp = token::revoke_accessor(client, token.accessor.as_str()).await;
     assert!(resp.is_ok());
+
+    let resp = token::lookup_accessor(client, token.accessor.as_str()).await;
+    assert!(resp.is_err());
+    let resp = token::lookup(client, token.token.as_str()).await;
+    assert!(resp.is_err());
 }
 
 pub async fn test_revoke_orphan(client: &impl Client, token: &str) {
@@ -119,10 +221,10 @@ pub async fn test_tidy(client: &impl Client) {
 }
 
 mod role {
-    use vaultrs::api::token::requests::SetTokenRoleRequest;
+    use vaultrs::api::token::requests::{CreateRoleTokenRequest, SetTokenRoleRequest};
 
-    use super::Client;
-    use crate::token::role;
+    use super::{Client, ClientError};
+    use crate::token::{self, role};
 
     pub async fn test_delete(client: &impl Client, role_name: &str) {
         let resp = role::delete(client, role_name).await;
@@ -132,11 +234,51 @@ mod role {
     pub async fn test_list(client: &impl Client) {
         let resp = role::list(client).await;
         assert!(resp.is_ok());
+        assert!(resp.unwrap().keys.contains(&"test".to_string()));
+    }
+
+    pub async fn test_new_role(client: &impl Client, role_name: &str) {
+        let resp = token::new_role(
+            client,
+            role_name,
+            Some(CreateRoleTokenRequest::builder().policies(vec!["app".to_string()])),
+        )
+        .await;
+        assert!(resp.is_ok());
+
+        // The token gets the role's properties
+        let resp = resp.unwrap();
+        assert!(resp.policies.contains(&"app".to_string()));
+        let lookup = token::lookup(client, resp.client_token.as_str())
+            .await
+            .unwrap();
+        assert!(lookup.orphan);
+        assert!(lookup.renewable);
+        assert_eq!(lookup.num_uses, 5);
+
+        // Policies which aren't allowed by the role are rejected
+        let resp = token::new_role(
+            client,
+            role_name,
+            Some(CreateRoleTokenRequest::builder().policies(vec!["root".to_string()])),
+        )
+        .await;
+        assert!(matches!(resp, Err(ClientError::APIError { code: 400, .. })));
     }
 
     pub async fn test_read(client: &impl Client, role_name: &str) {
         let resp = role::read(client, role_name).await;
         assert!(resp.is_ok());
+
+        let resp = resp.unwrap();
+        assert_eq!(resp.name, role_name);
+        assert_eq!(resp.allowed_policies, vec!["app"]);
+        assert_eq!(resp.disallowed_policies, vec!["root"]);
+        assert!(resp.orphan);
+        assert!(resp.renewable);
+        assert_eq!(resp.token_explicit_max_ttl, 3600);
+        assert_eq!(resp.token_num_uses, 5);
+        assert_eq!(resp.token_period, 0);
     }
 
     pub async fn test_set(client: &impl Client, role_name: &str) {
@@ -145,8 +287,12 @@ mod role {
             role_name,
             Some(
                 SetTokenRoleRequest::builder()
+                    .allowed_policies(vec!["app".to_string()])
+                    .disallowed_policies(vec!["root".to_string()])
+                    .orphan(true)
                     .renewable(true)
-                    .token_explicit_max_ttl("1h"),
+                    .token_explicit_max_ttl("1h")
+                    .token_num_uses(5u64),
             ),
         )
         .await;
@@ -154,8 +300,6 @@ mod role {
     }
 }
 
-// TODO: Add test for create token with role
-
 struct Token {
     pub accessor: String,
     pub token: String,
@@ -180,3 +324,36 @@ async fn setup(client: &impl Client) -> Result<Token, ClientError> {
         token: resp.client_token,
     })
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::token;
+
+    #[test]
+    fn test_list_accessors() {
+        let (address, requests) = mock_server(vec![response(
+            "200 OK",
+            "",
+            r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"keys":["accessor1","accessor2"]},"auth":null,"warnings":null,"wrap_info":null}"#,
+        )]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(token::list_accessors(&client)).unwrap();
+        assert_eq!(res, vec!["accessor1", "accessor2"]);
+        assert!(requests.lock().unwrap()[0].starts_with("LIST /v1/auth/token/accessors "));
+    }
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(token::role::list(&client));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/auth/token/roles HTTP/1.1"));
+    }
+}
@@ -0,0 +1,202 @@
+#[macro_use]
+extern crate tracing;
+
+mod common;
+
+use common::{VaultServer, VaultServerHelper};
+use test_log::test;
+use vaultrs::{client::VaultClient, error::ClientError};
+
+#[test]
+fn test() {
+    let test = common::new_test();
+    test.run(|instance| async move {
+        let server: VaultServer = instance.server();
+        let endpoint = TOTPEndpoint::setup(&server).await.unwrap();
+
+        // Test keys
+        key::test_generate(&endpoint).await;
+        key::test_create(&endpoint).await;
+        key::test_read(&endpoint).await;
+        key::test_list(&endpoint).await;
+
+        // Test codes
+        code::test_generate_and_validate(&endpoint).await;
+
+        key::test_delete(&endpoint).await;
+    });
+}
+
+mod key {
+    use super::{ClientError, TOTPEndpoint};
+    use vaultrs::api::totp::{requests::CreateKeyRequest, TotpAlgorithm};
+    use vaultrs::totp::key;
+
+    pub async fn test_create(endpoint: &TOTPEndpoint) {
+        let res = key::create(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.imported,
+            Some(
+                CreateKeyRequest::builder()
+                    .key("JBSWY3DPEHPK3PXP")
+                    .issuer("vaultrs")
+                    .account_name("imported@example.com")
+                    .algorithm(TotpAlgorithm::Sha256)
+                    .digits(8u64),
+            ),
+        )
+        .await;
+        assert!(res.is_ok());
+    }
+
+    pub async fn test_delete(endpoint: &TOTPEndpoint) {
+        for name in [&endpoint.keys.generated, &endpoint.keys.imported] {
+            let res = key::delete(&endpoint.client, &endpoint.path, name).await;
+            assert!(res.is_ok());
+
+            let res = key::read(&endpoint.client, &endpoint.path, name).await;
+            assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+        }
+    }
+
+    pub async fn test_generate(endpoint: &TOTPEndpoint) {
+        let res = key::generate(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.generated,
+            "vaultrs",
+            "user@example.com",
+            None,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert!(res.url.unwrap().starts_with("otpauth://totp/vaultrs:"));
+        assert!(!base64::decode(res.barcode.unwrap()).unwrap().is_empty());
+    }
+
+    pub async fn test_list(endpoint: &TOTPEndpoint) {
+        let res = key::list(&endpoint.client, &endpoint.path).await;
+        assert!(res.is_ok());
+
+        let keys = res.unwrap().keys;
+        assert!(keys.contains(&endpoint.keys.generated));
+        assert!(keys.contains(&endpoint.keys.imported));
+    }
+
+    pub async fn test_read(endpoint: &TOTPEndpoint) {
+        let res = key::read(&endpoint.client, &endpoint.path, &endpoint.keys.generated).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.issuer, "vaultrs");
+        assert_eq!(res.account_name, "user@example.com");
+        assert_eq!(res.algorithm, TotpAlgorithm::Sha1);
+        assert_eq!(res.digits, 6);
+        assert_eq!(res.period, 30);
+
+        let res = key::read(&endpoint.client, &endpoint.path, &endpoint.keys.imported).await;
+        assert!(res.is_ok());
+
+        let res = res.unwrap();
+        assert_eq!(res.algorithm, TotpAlgorithm::Sha256);
+        assert_eq!(res.digits, 8);
+    }
+}
+
+mod code {
+    use super::{ClientError, TOTPEndpoint};
+    use vaultrs::totp::code;
+
+    pub async fn test_generate_and_validate(endpoint: &TOTPEndpoint) {
+        let res = code::generate(&endpoint.client, &endpoint.path, &endpoint.keys.generated).await;
+        assert!(res.is_ok());
+
+        let generated = res.unwrap().code;
+        assert_eq!(generated.len(), 6);
+
+        let res = code::validate(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.generated,
+            &generated,
+        )
+        .await;
+        assert!(res.unwrap());
+
+        // Codes can't be reused
+        let res = code::validate(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.generated,
+            &generated,
+        )
+        .await;
+        assert!(matches!(res, Err(ClientError::APIError { code: 400, .. })));
+
+        let res = code::generate(&endpoint.client, &endpoint.path, &endpoint.keys.imported).await;
+        assert_eq!(res.unwrap().code.len(), 8);
+
+        let res = code::validate(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.imported,
+            "not-a-code",
+        )
+        .await;
+        assert!(!res.unwrap());
+    }
+}
+
+pub struct TestKeys {
+    pub generated: String,
+    pub imported: String,
+}
+
+pub struct TOTPEndpoint {
+    pub client: VaultClient,
+    pub path: String,
+    pub keys: TestKeys,
+}
+
+impl TOTPEndpoint {
+    async fn setup(server: &VaultServer) -> Result<Self, ClientError> {
+        debug!("setting up TOTP secrets engine");
+
+        let endpoint = TOTPEndpoint {
+            client: server.client(),
+            path: "totp-test".into(),
+            keys: TestKeys {
+                generated: "generated-key".into(),
+                imported: "imported-key".into(),
+            },
+        };
+
+        server
+            .mount_secret(&endpoint.client, &endpoint.path, "totp")
+            .await?;
+
+        Ok(endpoint)
+    }
+}
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::totp;
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(totp::key::list(&client, "totp"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/totp/keys HTTP/1.1"));
+    }
+}
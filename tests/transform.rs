@@ -0,0 +1,129 @@
+mod common;
+
+// The Transform engine is only available in Vault Enterprise, so it's tested
+// against a mock server instead of the Vault container.
+
+use common::{build_enterprise_client, mock_server, response};
+use vaultrs::api::transform::requests::TransformBatchItemBuilder;
+use vaultrs::error::ClientError;
+use vaultrs::transform;
+
+#[test]
+fn test_transform() {
+    let no_handler = r#"{"errors":["no handler for route \"transform/encode/payments\". route entry not found."]}"#;
+    let (address, requests) = mock_server(vec![
+        response(
+            "200 OK",
+            "",
+            r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"encoded_value":"958-91-9454"},"auth":null,"warnings":null,"wrap_info":null}"#,
+        ),
+        response(
+            "200 OK",
+            "",
+            r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"decoded_value":"123-45-6789"},"auth":null,"warnings":null,"wrap_info":null}"#,
+        ),
+        response(
+            "200 OK",
+            "",
+            r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"batch_results":[{"encoded_value":"958-91-9454"},{"encoded_value":"301-20-7812"}]},"auth":null,"warnings":null,"wrap_info":null}"#,
+        ),
+        response("404 Not Found", "", no_handler),
+    ]);
+    let client = build_enterprise_client(&address);
+
+    let res = tokio_test::block_on(transform::encode(
+        &client,
+        "transform",
+        "payments",
+        "123-45-6789",
+        Some("ssn"),
+        Some("dGVzdHR3ZWFr"),
+    ));
+    let encoded = res.unwrap().encoded_value;
+    assert_ne!(encoded, "123-45-6789");
+    assert_eq!(encoded.len(), "123-45-6789".len());
+
+    let res = tokio_test::block_on(transform::decode(
+        &client,
+        "transform",
+        "payments",
+        encoded.as_str(),
+        Some("ssn"),
+        Some("dGVzdHR3ZWFr"),
+    ));
+    assert_eq!(res.unwrap().decoded_value, "123-45-6789");
+
+    let res = tokio_test::block_on(transform::encode_batch(
+        &client,
+        "transform",
+        "payments",
+        vec![
+            TransformBatchItemBuilder::default()
+                .value("123-45-6789")
+                .transformation("ssn")
+                .build()
+                .unwrap(),
+            TransformBatchItemBuilder::default()
+                .value("987-65-4321")
+                .transformation("ssn")
+                .build()
+                .unwrap(),
+        ],
+    ));
+    let res = res.unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[1].encoded_value, "301-20-7812");
+
+    // A mount which isn't enabled isn't mistaken for Vault OSS
+    let res = tokio_test::block_on(transform::encode(
+        &client,
+        "transform",
+        "payments",
+        "123-45-6789",
+        None,
+        None,
+    ));
+    assert!(matches!(res, Err(ClientError::APIError { code: 404, .. })));
+
+    let requests = requests.lock().unwrap();
+    let body = |i: usize| -> serde_json::Value {
+        serde_json::from_str(requests[i].split("\r\n\r\n").nth(1).unwrap()).unwrap()
+    };
+    assert!(requests[0].starts_with("POST /v1/transform/encode/payments "));
+    assert_eq!(
+        body(0),
+        serde_json::json!({
+            "value": "123-45-6789",
+            "transformation": "ssn",
+            "tweak": "dGVzdHR3ZWFr",
+        })
+    );
+    assert!(requests[1].starts_with("POST /v1/transform/decode/payments "));
+    assert_eq!(body(1)["value"], "958-91-9454");
+    assert_eq!(
+        body(2),
+        serde_json::json!({
+            "batch_input": [
+                {"value": "123-45-6789", "transformation": "ssn"},
+                {"value": "987-65-4321", "transformation": "ssn"},
+            ],
+        })
+    );
+    assert_eq!(body(3), serde_json::json!({"value": "123-45-6789"}));
+}
+
+#[test]
+fn test_list_empty() {
+    let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+    let (address, requests) = mock_server(vec![empty; 2]);
+    let client = build_enterprise_client(&address);
+
+    // Vault responds with a 404 when there is nothing to list
+    let res = tokio_test::block_on(transform::role::list(&client, "transform"));
+    assert!(res.unwrap().keys.is_empty());
+    let res = tokio_test::block_on(transform::transformation::list(&client, "transform"));
+    assert!(res.unwrap().keys.is_empty());
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("LIST /v1/transform/role HTTP/1.1"));
+}
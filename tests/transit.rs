@@ -22,12 +22,16 @@ fn test() {
         key::test_rotate(&endpoint).await;
         key::test_update(&endpoint).await;
         key::test_delete(&endpoint).await;
+        key::test_min_decryption_version(&endpoint).await;
         key::test_export(&endpoint).await;
         key::test_backup_and_restore(&endpoint).await;
         key::test_trim(&endpoint).await;
 
         data::test_encrypt_and_rewrap_and_decrypt(&endpoint).await;
+        data::test_encrypt_and_decrypt_batch(&endpoint).await;
+        data::test_rewrap_batch(&endpoint).await;
         data::test_sign_and_verify(&endpoint).await;
+        data::test_sign_and_verify_batch(&endpoint).await;
 
         generate::test_data_key(&endpoint).await;
         generate::test_random_bytes(&endpoint).await;
@@ -39,13 +43,13 @@ fn test() {
 }
 
 mod key {
-    use super::TransitEndpoint;
+    use super::{ClientError, TransitEndpoint};
     use vaultrs::api::transit::requests::{
         CreateKeyRequest, ExportKeyType, ExportVersion, RestoreKeyRequest,
         UpdateKeyConfigurationRequest,
     };
     use vaultrs::api::transit::KeyType;
-    use vaultrs::transit::key;
+    use vaultrs::transit::{data, key};
 
     pub async fn test_create(endpoint: &TransitEndpoint) {
         let resp = key::create(&endpoint.client, &endpoint.path, &endpoint.keys.basic, None).await;
@@ -150,12 +154,94 @@ mod key {
 
     pub async fn test_delete(endpoint: &TransitEndpoint) {
         let resp = key::delete(&endpoint.client, &endpoint.path, &endpoint.keys.basic).await;
-        assert!(resp.is_err());
+        assert!(matches!(resp, Err(ClientError::KeyDeletionNotAllowed)));
 
         let resp = key::delete(&endpoint.client, &endpoint.path, &endpoint.keys.delete).await;
         assert!(resp.is_ok());
     }
 
+    pub async fn test_min_decryption_version(endpoint: &TransitEndpoint) {
+        let name = "rotation-key";
+        let resp = key::create(&endpoint.client, &endpoint.path, name, None).await;
+        assert!(resp.is_ok());
+
+        let old = data::encrypt(
+            &endpoint.client,
+            &endpoint.path,
+            name,
+            &endpoint.data.secret,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(old.ciphertext.starts_with("vault:v1:"));
+
+        // key versions 2 and 3
+        for _ in 0..2 {
+            let resp = key::rotate(&endpoint.client, &endpoint.path, name).await;
+            assert!(resp.is_ok());
+        }
+
+        let resp = key::update(
+            &endpoint.client,
+            &endpoint.path,
+            name,
+            Some(UpdateKeyConfigurationRequest::builder().min_decryption_version(3u64)),
+        )
+        .await;
+        assert!(resp.is_ok());
+
+        let resp = key::read(&endpoint.client, &endpoint.path, name).await;
+        let resp = resp.unwrap();
+        assert_eq!(resp.min_decryption_version, 3);
+
+        // Ciphertexts of older versions can no longer be decrypted
+        let resp = data::decrypt(
+            &endpoint.client,
+            &endpoint.path,
+            name,
+            &old.ciphertext,
+            None,
+        )
+        .await;
+        assert!(resp.is_err());
+
+        let new = data::encrypt(
+            &endpoint.client,
+            &endpoint.path,
+            name,
+            &endpoint.data.secret,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(new.ciphertext.starts_with("vault:v3:"));
+        let resp = data::decrypt(
+            &endpoint.client,
+            &endpoint.path,
+            name,
+            &new.ciphertext,
+            None,
+        )
+        .await;
+        assert_eq!(resp.unwrap().plaintext, endpoint.data.secret);
+
+        // Deleting the key must be allowed first
+        let resp = key::delete(&endpoint.client, &endpoint.path, name).await;
+        assert!(matches!(resp, Err(ClientError::KeyDeletionNotAllowed)));
+
+        let resp = key::update(
+            &endpoint.client,
+            &endpoint.path,
+            name,
+            Some(UpdateKeyConfigurationRequest::builder().deletion_allowed(true)),
+        )
+        .await;
+        assert!(resp.is_ok());
+        let resp = key::delete(&endpoint.client, &endpoint.path, name).await;
+        assert!(resp.is_ok());
+    }
+
     pub async fn test_export(endpoint: &TransitEndpoint) {
         let resp = key::export(
             &endpoint.client,
@@ -165,7 +251,7 @@ mod key {
             ExportVersion::All,
         )
         .await;
-        assert!(resp.is_err());
+        assert!(matches!(resp, Err(ClientError::KeyNotExportable)));
 
         let latest = key::export(
             &endpoint.client,
@@ -190,6 +276,11 @@ mod key {
         assert_eq!(resp.keys.len(), 1);
         assert_eq!(&resp.keys, &latest.keys);
 
+        // The key material is never printed
+        let debug = format!("{:?}", resp);
+        assert!(debug.contains("[redacted]"));
+        assert!(resp.keys.values().all(|k| !debug.contains(k.as_str())));
+
         let resp = key::export(
             &endpoint.client,
             &endpoint.path,
@@ -241,8 +332,9 @@ mod key {
 mod data {
     use super::TransitEndpoint;
     use vaultrs::api::transit::requests::{
-        DecryptDataRequest, EncryptDataRequest, RewrapDataRequest, SignDataRequest,
-        VerifySignedDataRequest,
+        DecryptBatchItemBuilder, DecryptDataRequest, EncryptBatchItemBuilder, EncryptDataRequest,
+        RewrapBatchItemBuilder, RewrapDataRequest, SignBatchItemBuilder, SignDataRequest,
+        VerifyBatchItemBuilder, VerifySignedDataRequest,
     };
     use vaultrs::api::transit::SignatureAlgorithm;
     use vaultrs::transit::{data, key};
@@ -296,6 +388,106 @@ mod data {
         assert_eq!(&decrypted.plaintext, &endpoint.data.secret);
     }
 
+    pub async fn test_encrypt_and_decrypt_batch(endpoint: &TransitEndpoint) {
+        let item = EncryptBatchItemBuilder::default()
+            .plaintext(&endpoint.data.secret)
+            .context(&endpoint.data.context)
+            .build()
+            .unwrap();
+        let bad_item = EncryptBatchItemBuilder::default()
+            .plaintext(&endpoint.data.secret)
+            .context("not base64!")
+            .build()
+            .unwrap();
+
+        let encrypted = data::encrypt_batch(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.export,
+            vec![item.clone(), bad_item, item],
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(encrypted.len(), 3);
+        assert!(encrypted[0].is_ok());
+        assert!(encrypted[1].is_err());
+        assert!(encrypted[2].is_ok());
+
+        let ciphertext = encrypted[0].as_ref().unwrap().ciphertext.clone().unwrap();
+        let item = DecryptBatchItemBuilder::default()
+            .ciphertext(&ciphertext)
+            .context(&endpoint.data.context)
+            .build()
+            .unwrap();
+        let bad_item = DecryptBatchItemBuilder::default()
+            .ciphertext(&ciphertext)
+            .context(base64::encode("other-context"))
+            .build()
+            .unwrap();
+
+        let decrypted = data::decrypt_batch(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.export,
+            vec![item.clone(), bad_item, item],
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(decrypted.len(), 3);
+        assert_eq!(
+            decrypted[0].as_ref().unwrap().plaintext.as_ref(),
+            Some(&endpoint.data.secret)
+        );
+        assert!(decrypted[1].is_err());
+        assert_eq!(
+            decrypted[2].as_ref().unwrap().plaintext.as_ref(),
+            Some(&endpoint.data.secret)
+        );
+    }
+
+    pub async fn test_rewrap_batch(endpoint: &TransitEndpoint) {
+        let encrypted = data::encrypt(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.export,
+            &endpoint.data.secret,
+            Some(EncryptDataRequest::builder().context(&endpoint.data.context)),
+        )
+        .await
+        .unwrap();
+
+        let resp = key::rotate(&endpoint.client, &endpoint.path, &endpoint.keys.export).await;
+        assert!(resp.is_ok());
+
+        let item = RewrapBatchItemBuilder::default()
+            .ciphertext(&encrypted.ciphertext)
+            .context(&endpoint.data.context)
+            .build()
+            .unwrap();
+        let bad_item = RewrapBatchItemBuilder::default()
+            .ciphertext(&encrypted.ciphertext)
+            .context(base64::encode("other-context"))
+            .build()
+            .unwrap();
+
+        let rewrapped = data::rewrap_batch(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.export,
+            vec![item, bad_item],
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(rewrapped.len(), 2);
+        let result = rewrapped[0].as_ref().unwrap();
+        assert!(result.ciphertext.as_ref() != Some(&encrypted.ciphertext));
+        assert!(result.plaintext.is_none());
+        assert!(rewrapped[1].is_err());
+    }
+
     pub async fn test_sign_and_verify(endpoint: &TransitEndpoint) {
         let signed = data::sign(
             &endpoint.client,
@@ -326,6 +518,74 @@ mod data {
         .await
         .unwrap();
         assert!(verified.valid);
+
+        // A signature doesn't verify against tampered data
+        let verified = data::verify(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.signing,
+            &base64::encode("tampered data"),
+            Some(
+                VerifySignedDataRequest::builder()
+                    .context(&endpoint.data.context)
+                    .signature(&signed.signature),
+            ),
+        )
+        .await
+        .unwrap();
+        assert!(!verified.valid);
+    }
+
+    pub async fn test_sign_and_verify_batch(endpoint: &TransitEndpoint) {
+        let item = SignBatchItemBuilder::default()
+            .input(&endpoint.data.secret)
+            .context(&endpoint.data.context)
+            .build()
+            .unwrap();
+        let bad_item = SignBatchItemBuilder::default()
+            .input(&endpoint.data.secret)
+            .context("not base64!")
+            .build()
+            .unwrap();
+
+        let signed = data::sign_batch(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.signing,
+            vec![item, bad_item],
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(signed.len(), 2);
+        assert!(signed[1].is_err());
+
+        let signature = signed[0].as_ref().unwrap().signature.clone().unwrap();
+        let item = VerifyBatchItemBuilder::default()
+            .input(&endpoint.data.secret)
+            .signature(&signature)
+            .context(&endpoint.data.context)
+            .build()
+            .unwrap();
+        let tampered_item = VerifyBatchItemBuilder::default()
+            .input(base64::encode("tampered data"))
+            .signature(&signature)
+            .context(&endpoint.data.context)
+            .build()
+            .unwrap();
+
+        let verified = data::verify_batch(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.signing,
+            vec![item, tampered_item],
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(verified.len(), 2);
+        assert_eq!(verified[0].as_ref().unwrap().valid, Some(true));
+        assert_eq!(verified[1].as_ref().unwrap().valid, Some(false));
     }
 }
 
@@ -333,10 +593,10 @@ mod generate {
     use super::TransitEndpoint;
     use vaultrs::api::transit::requests::{
         DataKeyType, GenerateDataKeyRequest, GenerateRandomBytesRequest, HashDataRequest,
-        RandomBytesSource,
+        RandomBytesSource, VerifySignedDataRequest,
     };
     use vaultrs::api::transit::{HashAlgorithm, OutputFormat};
-    use vaultrs::transit::generate;
+    use vaultrs::transit::{data, generate};
 
     pub async fn test_data_key(endpoint: &TransitEndpoint) {
         let resp = generate::data_key(
@@ -348,7 +608,34 @@ mod generate {
         )
         .await
         .unwrap();
-        assert!(&resp.plaintext.is_some())
+        assert!(&resp.plaintext.is_some());
+
+        // The plaintext key is base64 encoded and wrapped by the named key
+        let plaintext = resp.plaintext.unwrap();
+        assert_eq!(base64::decode(&plaintext).unwrap().len(), 64);
+        let decrypted = data::decrypt(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.basic,
+            &resp.ciphertext,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(decrypted.plaintext, plaintext);
+
+        // Wrapped keys only return the ciphertext
+        let resp = generate::data_key(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.basic,
+            DataKeyType::Wrapped,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(resp.plaintext.is_none());
+        assert!(resp.ciphertext.starts_with("vault:v"));
     }
 
     pub async fn test_random_bytes(endpoint: &TransitEndpoint) {
@@ -361,7 +648,18 @@ mod generate {
         )
         .await
         .unwrap();
-        assert_eq!(resp.random_bytes.len(), 20)
+        assert_eq!(resp.random_bytes.len(), 20);
+
+        let resp = generate::random_bytes(
+            &endpoint.client,
+            &endpoint.path,
+            OutputFormat::Base64,
+            RandomBytesSource::All,
+            Some(GenerateRandomBytesRequest::builder().bytes(32u32)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(base64::decode(resp.random_bytes).unwrap().len(), 32);
     }
 
     pub async fn test_hash(endpoint: &TransitEndpoint) {
@@ -378,6 +676,20 @@ mod generate {
         .await
         .unwrap();
         assert_eq!(resp.sum, endpoint.data.context_shasum_hex);
+
+        let resp = generate::hash(
+            &endpoint.client,
+            &endpoint.path,
+            &base64::encode("hello"),
+            Some(
+                HashDataRequest::builder()
+                    .algorithm(HashAlgorithm::Sha2_256)
+                    .format(OutputFormat::Base64),
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(resp.sum, "LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ=");
     }
 
     pub async fn test_hmac(endpoint: &TransitEndpoint) {
@@ -390,6 +702,18 @@ mod generate {
         )
         .await;
         assert!(resp.is_ok());
+
+        // The digest verifies against the same key
+        let verified = data::verify(
+            &endpoint.client,
+            &endpoint.path,
+            &endpoint.keys.basic,
+            &endpoint.data.context,
+            Some(VerifySignedDataRequest::builder().hmac(resp.unwrap().hmac)),
+        )
+        .await
+        .unwrap();
+        assert!(verified.valid);
     }
 }
 
@@ -468,3 +792,80 @@ impl TransitEndpoint {
         Ok(endpoint)
     }
 }
+
+mod mock {
+    use crate::common::{build_retry_client, mock_server, response};
+    use vaultrs::api::transit::requests::ImportKeyRequest;
+    use vaultrs::api::transit::{ImportHashFunction, KeyType};
+    use vaultrs::transit;
+
+    #[test]
+    fn test_import() {
+        let (address, requests) = mock_server(vec![
+            response(
+                "200 OK",
+                "",
+                r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":{"public_key":"-----BEGIN PUBLIC KEY-----\nMIIC\n-----END PUBLIC KEY-----\n"},"auth":null,"warnings":null,"wrap_info":null}"#,
+            ),
+            response("204 No Content", "", ""),
+            response("204 No Content", "", ""),
+        ]);
+        let client = build_retry_client(&address);
+
+        let res = tokio_test::block_on(transit::wrapping_key(&client, "transit")).unwrap();
+        assert!(res.public_key.starts_with("-----BEGIN PUBLIC KEY-----"));
+
+        let ciphertext = transit::key::import_ciphertext(&[1, 2], &[3, 4]);
+        assert_eq!(ciphertext, "AQIDBA==");
+        tokio_test::block_on(transit::key::import(
+            &client,
+            "transit",
+            "imported",
+            &ciphertext,
+            KeyType::Aes256Gcm96,
+            Some(
+                ImportKeyRequest::builder()
+                    .hash_function(ImportHashFunction::Sha512)
+                    .allow_rotation(true),
+            ),
+        ))
+        .unwrap();
+        tokio_test::block_on(transit::key::import_version(
+            &client,
+            "transit",
+            "imported",
+            &ciphertext,
+            None,
+        ))
+        .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /v1/transit/wrapping_key "));
+        assert!(requests[1].starts_with("POST /v1/transit/keys/imported/import "));
+        let body: serde_json::Value =
+            serde_json::from_str(requests[1].split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(body["ciphertext"], "AQIDBA==");
+        assert_eq!(body["type"], "aes256-gcm96");
+        assert_eq!(body["hash_function"], "SHA512");
+        assert_eq!(body["allow_rotation"], true);
+        assert!(body.get("exportable").is_none());
+        assert!(requests[2].starts_with("POST /v1/transit/keys/imported/import_version "));
+        let body: serde_json::Value =
+            serde_json::from_str(requests[2].split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({ "ciphertext": "AQIDBA==" }));
+    }
+
+    #[test]
+    fn test_list_empty() {
+        let empty = response("404 Not Found", "", r#"{"errors":[]}"#);
+        let (address, requests) = mock_server(vec![empty]);
+        let client = build_retry_client(&address);
+
+        // Vault responds with a 404 when there is nothing to list
+        let res = tokio_test::block_on(transit::key::list(&client, "transit"));
+        assert!(res.unwrap().keys.is_empty());
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("LIST /v1/transit/keys HTTP/1.1"));
+    }
+}
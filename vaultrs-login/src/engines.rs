@@ -1,6 +1,12 @@
 pub mod approle;
 #[cfg(feature = "aws")]
 pub mod aws;
+pub mod azure;
+pub mod cert;
+pub mod gcp;
+pub mod jwt;
+pub mod kubernetes;
+pub mod ldap;
 #[cfg(feature = "oidc")]
 pub mod oidc;
 pub mod userpass;
@@ -1,15 +1,28 @@
+use std::fmt;
+
 use async_trait::async_trait;
 use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
 
 use crate::LoginMethod;
 
 /// A login method which uses AppRole credentials for obtaining a new token.
-#[derive(Debug)]
+///
+/// Invalid credentials are rejected by Vault and returned as a
+/// [ClientError::APIError] containing the error messages from the server.
 pub struct AppRoleLogin {
     pub role_id: String,
     pub secret_id: String,
 }
 
+impl fmt::Debug for AppRoleLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppRoleLogin")
+            .field("role_id", &self.role_id)
+            .field("secret_id", &"[redacted]")
+            .finish()
+    }
+}
+
 impl AppRoleLogin {
     pub fn new(role_id: &str, secret_id: &str) -> Self {
         AppRoleLogin {
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use async_trait::async_trait;
 use std::time::SystemTime;
@@ -8,7 +8,21 @@ use crate::LoginMethod;
 use aws_sigv4::http_request::{sign, SignableRequest, SigningParams, SigningSettings};
 
 /// A login method which uses AWS credentials for obtaining a new token.
-#[derive(Debug)]
+///
+/// The login signs an STS `GetCallerIdentity` request with the credentials
+/// using AWS Signature Version 4, without sending it to AWS. Vault sends the
+/// signed request to STS itself to verify the identity of the caller, so:
+///
+/// * The credentials must be valid, for example those of the IAM role of an
+///   EC2 instance, ECS task or Lambda function. Temporary credentials require
+///   their `session_token`.
+/// * The request is signed for the global `https://sts.amazonaws.com`
+///   endpoint, which is the default STS endpoint of Vault and expects the
+///   `region` to be `us-east-1`.
+/// * If the auth engine is configured with an `iam_server_id_header_value`,
+///   the same value must be given as `header_value`. It is signed along with
+///   the request, which prevents replaying it against other Vault servers.
+/// * The `role` defaults to the name of the IAM principal if not given.
 pub struct AwsIamLogin {
     pub access_key: String,
     pub secret_key: String,
@@ -18,14 +32,44 @@ pub struct AwsIamLogin {
     pub header_value: Option<String>,
 }
 
+impl fmt::Debug for AwsIamLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AwsIamLogin")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"[redacted]")
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "[redacted]"),
+            )
+            .field("region", &self.region)
+            .field("role", &self.role)
+            .field("header_value", &self.header_value)
+            .finish()
+    }
+}
+
 /// A login method which uses EC2 instance pkcs7 signature for obtaining a new token.
-#[derive(Debug)]
+///
+/// The signature is read from the instance metadata service at
+/// `http://169.254.169.254/latest/dynamic/instance-identity/pkcs7`. If no
+/// `nonce` is given, Vault returns one in the metadata of the first login,
+/// which must be passed when logging in again from the same instance.
 pub struct AwsEc2Login {
     pub pkcs7: String,
     pub nonce: Option<String>,
     pub role: Option<String>,
 }
 
+impl fmt::Debug for AwsEc2Login {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AwsEc2Login")
+            .field("pkcs7", &"[redacted]")
+            .field("nonce", &self.nonce)
+            .field("role", &self.role)
+            .finish()
+    }
+}
+
 #[async_trait]
 impl LoginMethod for AwsIamLogin {
     async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
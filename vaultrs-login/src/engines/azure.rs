@@ -0,0 +1,78 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use vaultrs::{
+    api::{auth::azure::requests::LoginWithAzureRequest, AuthInfo},
+    client::Client,
+    error::ClientError,
+};
+
+use crate::LoginMethod;
+
+/// A login method which uses the JWT of an Azure managed identity for
+/// obtaining a new token.
+///
+/// The JWT is usually fetched from the instance metadata service of the
+/// virtual machine. The metadata of the machine is optional but required by
+/// roles which bind to a subscription, resource group or machine name.
+pub struct AzureLogin {
+    pub role: String,
+    pub jwt: String,
+    pub subscription_id: Option<String>,
+    pub resource_group_name: Option<String>,
+    pub vm_name: Option<String>,
+    pub vmss_name: Option<String>,
+}
+
+impl AzureLogin {
+    pub fn new(role: &str, jwt: &str) -> Self {
+        AzureLogin {
+            role: role.to_string(),
+            jwt: jwt.to_string(),
+            subscription_id: None,
+            resource_group_name: None,
+            vm_name: None,
+            vmss_name: None,
+        }
+    }
+}
+
+impl fmt::Debug for AzureLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AzureLogin")
+            .field("role", &self.role)
+            .field("jwt", &"[redacted]")
+            .field("subscription_id", &self.subscription_id)
+            .field("resource_group_name", &self.resource_group_name)
+            .field("vm_name", &self.vm_name)
+            .field("vmss_name", &self.vmss_name)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl LoginMethod for AzureLogin {
+    async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
+        let mut opts = LoginWithAzureRequest::builder();
+        if let Some(subscription_id) = &self.subscription_id {
+            opts.subscription_id(subscription_id);
+        }
+        if let Some(resource_group_name) = &self.resource_group_name {
+            opts.resource_group_name(resource_group_name);
+        }
+        if let Some(vm_name) = &self.vm_name {
+            opts.vm_name(vm_name);
+        }
+        if let Some(vmss_name) = &self.vmss_name {
+            opts.vmss_name(vmss_name);
+        }
+        vaultrs::auth::azure::login(
+            client,
+            mount,
+            self.role.as_str(),
+            self.jwt.as_str(),
+            Some(&mut opts),
+        )
+        .await
+    }
+}
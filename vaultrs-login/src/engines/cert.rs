@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
+
+use crate::LoginMethod;
+
+/// A login method which uses the client certificate presented over TLS for
+/// obtaining a new token.
+///
+/// The client must be configured with a certificate through
+/// [VaultClientSettings::identity][vaultrs::client::VaultClientSettings::identity].
+/// If no `name` is given, Vault matches the certificate against all
+/// configured certificate roles.
+#[derive(Debug, Default)]
+pub struct CertLogin {
+    pub name: Option<String>,
+}
+
+impl CertLogin {
+    pub fn new(name: Option<&str>) -> Self {
+        CertLogin {
+            name: name.map(|n| n.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl LoginMethod for CertLogin {
+    async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
+        vaultrs::auth::cert::login(client, mount, self.name.as_deref()).await
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
+
+use crate::LoginMethod;
+
+/// A login method which uses a signed JWT of a GCP service account or GCE
+/// instance for obtaining a new token.
+///
+/// Service accounts sign the JWT through the IAM credentials API, while GCE
+/// instances fetch an identity token from their metadata server. Either way
+/// the audience of the JWT must be `vault/{role}`.
+pub struct GCPLogin {
+    pub role: String,
+    pub jwt: String,
+}
+
+impl GCPLogin {
+    pub fn new(role: &str, jwt: &str) -> Self {
+        GCPLogin {
+            role: role.to_string(),
+            jwt: jwt.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for GCPLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GCPLogin")
+            .field("role", &self.role)
+            .field("jwt", &"[redacted]")
+            .finish()
+    }
+}
+
+#[async_trait]
+impl LoginMethod for GCPLogin {
+    async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
+        vaultrs::auth::gcp::login(client, mount, self.role.as_str(), self.jwt.as_str()).await
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
+
+use crate::LoginMethod;
+
+/// A login method which uses a pre-obtained JWT for obtaining a new token.
+///
+/// This is meant for contexts like CI pipelines which are handed a JWT by
+/// their platform. The JWT is sent as is, so no OIDC browser flow is started.
+/// The mount is usually either `jwt` or `oidc` depending on how the backend
+/// was enabled.
+pub struct JWTLogin {
+    pub role: String,
+    pub jwt: String,
+}
+
+impl JWTLogin {
+    pub fn new(role: &str, jwt: &str) -> Self {
+        JWTLogin {
+            role: role.to_string(),
+            jwt: jwt.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for JWTLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JWTLogin")
+            .field("role", &self.role)
+            .field("jwt", &"[redacted]")
+            .finish()
+    }
+}
+
+#[async_trait]
+impl LoginMethod for JWTLogin {
+    async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
+        vaultrs::auth::jwt::login(client, mount, self.role.as_str(), self.jwt.as_str()).await
+    }
+}
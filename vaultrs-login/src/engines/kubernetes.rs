@@ -0,0 +1,82 @@
+use std::{fmt, io::ErrorKind, path::PathBuf};
+
+use async_trait::async_trait;
+use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
+
+use crate::LoginMethod;
+
+/// The path where Kubernetes mounts the service account token inside a pod.
+pub const DEFAULT_JWT_PATH: &str = "/var/run/secrets/kubernetes.io/serviceaccount/token";
+
+/// A login method which uses a Kubernetes service account token for obtaining
+/// a new token.
+///
+/// The token is read from `jwt_path` (or [DEFAULT_JWT_PATH] if not set) each
+/// time a login is performed, unless a JWT is supplied directly with
+/// [KubernetesLogin::with_jwt].
+pub struct KubernetesLogin {
+    pub role: String,
+    pub jwt_path: Option<PathBuf>,
+    pub jwt: Option<String>,
+}
+
+impl KubernetesLogin {
+    pub fn new(role: &str) -> Self {
+        KubernetesLogin {
+            role: role.to_string(),
+            jwt_path: None,
+            jwt: None,
+        }
+    }
+
+    /// Creates a login which uses the given JWT instead of reading it from
+    /// the filesystem.
+    pub fn with_jwt(role: &str, jwt: &str) -> Self {
+        KubernetesLogin {
+            role: role.to_string(),
+            jwt_path: None,
+            jwt: Some(jwt.to_string()),
+        }
+    }
+
+    /// Returns the JWT used for logging in, reading it from the filesystem if
+    /// it wasn't supplied directly.
+    fn read_jwt(&self) -> Result<String, ClientError> {
+        if let Some(jwt) = &self.jwt {
+            return Ok(jwt.clone());
+        }
+
+        let path = self
+            .jwt_path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_JWT_PATH));
+        match std::fs::read_to_string(&path) {
+            Ok(jwt) => Ok(jwt.trim().to_string()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(ClientError::FileNotFoundError {
+                path: path.display().to_string(),
+            }),
+            Err(e) => Err(ClientError::FileReadError {
+                source: e,
+                path: path.display().to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for KubernetesLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KubernetesLogin")
+            .field("role", &self.role)
+            .field("jwt_path", &self.jwt_path)
+            .field("jwt", &self.jwt.as_ref().map(|_| "[redacted]"))
+            .finish()
+    }
+}
+
+#[async_trait]
+impl LoginMethod for KubernetesLogin {
+    async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
+        let jwt = self.read_jwt()?;
+        vaultrs::auth::kubernetes::login(client, mount, self.role.as_str(), jwt.as_str()).await
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
+
+use crate::LoginMethod;
+
+/// A login method which uses LDAP credentials for obtaining a new token.
+///
+/// The password is redacted when the login is formatted with [fmt::Debug].
+pub struct LdapLogin {
+    pub username: String,
+    pub password: String,
+}
+
+impl LdapLogin {
+    pub fn new(username: &str, password: &str) -> Self {
+        LdapLogin {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for LdapLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LdapLogin")
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
+
+#[async_trait]
+impl LoginMethod for LdapLogin {
+    async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
+        vaultrs::auth::ldap::login(
+            client,
+            mount,
+            self.username.as_str(),
+            self.password.as_str(),
+        )
+        .await
+    }
+}
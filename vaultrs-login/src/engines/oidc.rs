@@ -1,33 +1,97 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt,
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+};
 
 use async_trait::async_trait;
-use tiny_http::{Response, Server};
+use tiny_http::{Header, Response, Server};
 use tokio::task::JoinHandle;
 use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
 
 use crate::{MultiLoginCallback, MultiLoginMethod};
 
 /// A login method which uses OIDC credentials for obtaining a new token.
-#[derive(Debug)]
+///
+/// The redirect URL advertised to Vault is built from `redirect_host` and
+/// `port` (i.e. `http://localhost:8250/oidc/callback`) and must be present in
+/// the `allowed_redirect_uris` of the Vault role being used. An IPv6 address
+/// used as `redirect_host` must be enclosed in brackets. The callback server
+/// itself listens on `bind_addr`, which allows it to be reachable from outside
+/// a container or through a forwarded port.
+#[derive(Debug, Default)]
 pub struct OIDCLogin {
-    pub port: Option<u16>,    // Defaults to 8250
-    pub role: Option<String>, // Defaults to what's configured in the backend
+    pub port: Option<u16>,               // Defaults to 8250
+    pub role: Option<String>,            // Defaults to what's configured in the backend
+    pub timeout: Option<Duration>,       // Defaults to waiting indefinitely
+    pub bind_addr: Option<IpAddr>,       // Defaults to 127.0.0.1
+    pub redirect_host: Option<String>,   // Defaults to localhost
+    pub on_success: OIDCSuccessResponse, // Defaults to a plain text response
+}
+
+/// The response returned to the end-user's browser after a successful OIDC
+/// redirect has been received.
+#[derive(Clone, Debug)]
+pub enum OIDCSuccessResponse {
+    /// Responds with the given plain text
+    Text(String),
+    /// Responds with the given HTML document
+    Html(String),
+    /// Redirects the browser to the given URL
+    Redirect(url::Url),
+}
+
+impl Default for OIDCSuccessResponse {
+    fn default() -> Self {
+        OIDCSuccessResponse::Text("Success!".to_string())
+    }
+}
+
+impl OIDCSuccessResponse {
+    fn response(&self) -> Response<std::io::Cursor<Vec<u8>>> {
+        match self {
+            OIDCSuccessResponse::Text(text) => Response::from_string(text.as_str()),
+            OIDCSuccessResponse::Html(html) => Response::from_string(html.as_str()).with_header(
+                Header::from_bytes(&b"Content-Type"[..], &b"text/html; charset=utf-8"[..]).unwrap(),
+            ),
+            OIDCSuccessResponse::Redirect(url) => Response::from_string("")
+                .with_status_code(302)
+                .with_header(Header::from_bytes(&b"Location"[..], url.as_str()).unwrap()),
+        }
+    }
 }
 
 /// The callback for the OIDC login method.
 #[derive(Debug)]
 pub struct OIDCCallback {
-    pub handle: JoinHandle<OIDCCallbackParams>,
+    pub handle: JoinHandle<Result<OIDCCallbackParams, ClientError>>,
     pub url: String,
 }
 
 // The parameters returned by the OAuth authorization server after successful
-// authentication.
-#[derive(Debug, Default)]
+// authentication. If the redirect is missing the `code` or `state` parameters
+// the `error` field is set instead, along with any `error_description` the
+// authorization server provided.
+#[derive(Default)]
 pub struct OIDCCallbackParams {
     pub code: String,
     pub nonce: String,
     pub state: String,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+impl fmt::Debug for OIDCCallbackParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OIDCCallbackParams")
+            .field("code", &"[redacted]")
+            .field("nonce", &"[redacted]")
+            .field("state", &"[redacted]")
+            .field("error", &self.error)
+            .field("error_description", &self.error_description)
+            .finish()
+    }
 }
 
 #[async_trait]
@@ -47,7 +111,8 @@ impl MultiLoginMethod for OIDCLogin {
     /// URL generated by Vault which an end-user must visit to complete the
     /// authorization flow. It also returns a handle to the task running the
     /// HTTP server. The `callback` method can be awaited on and will only
-    /// return once the redirect has been received.
+    /// return once the redirect has been received or, if a `timeout` was
+    /// configured, once it elapses with a [ClientError::OIDCServerTimeout].
     async fn login(
         &self,
         client: &impl Client,
@@ -56,43 +121,76 @@ impl MultiLoginMethod for OIDCLogin {
         // The Vault CLI uses http://localhost:8250/oidc/callback by default, so
         // we match that here to try and remain consistent
         let port = self.port.unwrap_or(8250);
-        let ip = "127.0.0.1";
-        let hostname = "localhost";
+        let ip = self
+            .bind_addr
+            .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::LOCALHOST));
+        let hostname = self.redirect_host.as_deref().unwrap_or("localhost");
+
+        let invalid_host = |source| ClientError::OIDCRedirectHostError {
+            source,
+            host: hostname.to_string(),
+        };
+        let host = url::Host::parse(hostname).map_err(invalid_host)?;
+        let base =
+            url::Url::parse(format!("http://{}:{}", host, port).as_str()).map_err(invalid_host)?;
+        let redirect = base
+            .join("oidc/callback")
+            .map_err(invalid_host)?
+            .to_string();
 
-        let base = url::Url::parse(format!("http://{}:{}", hostname, port).as_str()).unwrap();
-        let redirect = base.join("oidc/callback").unwrap().to_string();
+        // The server is started first, so that Vault isn't asked for an
+        // authorization URL which can't be redirected back to
+        let server = Server::http((ip, port)).map_err(|e| ClientError::OIDCServerError {
+            source: std::io::Error::other(e),
+        })?;
         let response =
             vaultrs::auth::oidc::auth(client, mount, redirect.as_str(), self.role.clone()).await?;
-        let server = Server::http(format!("{}:{}", ip, port)).unwrap();
 
+        let timeout = self.timeout;
+        let on_success = self.on_success.clone();
         let handle = tokio::task::spawn_blocking(move || {
-            let mut result = OIDCCallbackParams::default();
-            for request in server.incoming_requests() {
-                let url = base.join(request.url()).unwrap();
-                let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
-
-                result.code = query
-                    .get("code")
-                    .cloned()
-                    .or_else(|| Some("".to_string()))
-                    .unwrap();
-                result.nonce = query
-                    .get("nonce")
-                    .cloned()
-                    .or_else(|| Some("".to_string()))
-                    .unwrap();
-                result.state = query
-                    .get("state")
-                    .cloned()
-                    .or_else(|| Some("".to_string()))
-                    .unwrap();
-
-                request
-                    .respond(Response::from_string("Success!"))
-                    .expect("Error responding!");
-                server.unblock();
-            }
-            result
+            let request = match timeout {
+                Some(t) => server
+                    .recv_timeout(t)
+                    .map_err(|e| ClientError::OIDCServerError { source: e })?
+                    .ok_or(ClientError::OIDCServerTimeout)?,
+                None => server
+                    .recv()
+                    .map_err(|e| ClientError::OIDCServerError { source: e })?,
+            };
+
+            let url = base.join(request.url()).unwrap();
+            let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+
+            let result = match (query.get("code"), query.get("state")) {
+                (Some(code), Some(state)) => OIDCCallbackParams {
+                    code: code.clone(),
+                    nonce: query.get("nonce").cloned().unwrap_or_default(),
+                    state: state.clone(),
+                    ..Default::default()
+                },
+                _ => OIDCCallbackParams {
+                    error: Some(
+                        query
+                            .get("error")
+                            .cloned()
+                            .unwrap_or_else(|| "missing_code_or_state".to_string()),
+                    ),
+                    error_description: query.get("error_description").cloned(),
+                    ..Default::default()
+                },
+            };
+
+            let response = match &result.error {
+                Some(e) => Response::from_string(format!("Login failed: {}", e)),
+                None => on_success.response(),
+            };
+            request.respond(response).expect("Error responding!");
+
+            // The server only ever handles a single redirect, so it's dropped
+            // here to release the port for any subsequent logins
+            drop(server);
+            Ok(result)
         });
 
         Ok(OIDCCallback {
@@ -109,8 +207,18 @@ impl MultiLoginCallback for OIDCCallback {
     /// This method will block until the underlying HTTP server receives a
     /// request from the OAuth authorization server at the redirect URL. It uses
     /// the resulting state, code, and nonce to retrieve a token from Vault.
+    ///
+    /// If the authorization server redirected with an error (i.e. the user
+    /// denied consent) a [ClientError::OIDCAuthError] is returned without
+    /// contacting Vault.
     async fn callback(self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
-        let result = self.handle.await.unwrap();
+        let result = self.handle.await.unwrap()?;
+        if let Some(error) = result.error {
+            return Err(ClientError::OIDCAuthError {
+                error,
+                description: result.error_description,
+            });
+        }
         vaultrs::auth::oidc::callback(
             client,
             mount,
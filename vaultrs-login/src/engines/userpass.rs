@@ -1,10 +1,13 @@
+use std::fmt;
+
 use async_trait::async_trait;
 use vaultrs::{api::AuthInfo, client::Client, error::ClientError};
 
 use crate::LoginMethod;
 
 /// A login method which uses user/pass credentials for obtaining a new token.
-#[derive(Debug)]
+///
+/// The password is redacted when the login is formatted with [fmt::Debug].
 pub struct UserpassLogin {
     pub password: String,
     pub username: String,
@@ -19,6 +22,15 @@ impl UserpassLogin {
     }
 }
 
+impl fmt::Debug for UserpassLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserpassLogin")
+            .field("password", &"[redacted]")
+            .field("username", &self.username)
+            .finish()
+    }
+}
+
 #[async_trait]
 impl LoginMethod for UserpassLogin {
     async fn login(&self, client: &impl Client, mount: &str) -> Result<AuthInfo, ClientError> {
@@ -90,9 +90,22 @@ pub trait LoginClient: Client + Sized {
         mount: &str,
         method: &M,
     ) -> Result<(), ClientError> {
+        self.login_and_set(mount, method).await.map(|_| ())
+    }
+
+    /// Performs a login using the given method and sets the resulting token to
+    /// this client, returning the authentication info for inspection.
+    ///
+    /// The token is used by all subsequent requests of this client.
+    #[instrument(skip(self, method), err)]
+    async fn login_and_set<M: 'static + LoginMethod>(
+        &mut self,
+        mount: &str,
+        method: &M,
+    ) -> Result<AuthInfo, ClientError> {
         let info = method.login(self, mount).await?;
         self.set_token(info.client_token.as_str());
-        Ok(())
+        Ok(info)
     }
 
     /// Performs the first step of a multi-step login, returning the resulting
@@ -4,8 +4,18 @@ use serde::Deserialize;
 use vaultrs::{client::Client, error::ClientError};
 
 /// Contains the login methods currently supported by this crate
-pub const SUPPORTED_METHODS: [Method; 4] =
-    [Method::APPROLE, Method::OIDC, Method::USERPASS, Method::AWS];
+pub const SUPPORTED_METHODS: [Method; 10] = [
+    Method::APPROLE,
+    Method::OIDC,
+    Method::USERPASS,
+    Method::AWS,
+    Method::KUBERNETES,
+    Method::JWT,
+    Method::CERT,
+    Method::AZURE,
+    Method::GCP,
+    Method::LDAP,
+];
 
 /// Represents all login methods.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
@@ -19,6 +29,7 @@ pub enum Method {
     CF,
     GCP,
     GITHUB,
+    JWT,
     KERBEROS,
     KUBERNETES,
     LDAP,
@@ -41,6 +52,7 @@ impl std::fmt::Display for Method {
             Method::CF => write!(f, "Cloud Foundry"),
             Method::GCP => write!(f, "GCP"),
             Method::GITHUB => write!(f, "Github"),
+            Method::JWT => write!(f, "JWT"),
             Method::KERBEROS => write!(f, "Kerberos"),
             Method::KUBERNETES => write!(f, "Kubernetes"),
             Method::LDAP => write!(f, "LDAP"),
@@ -67,6 +79,7 @@ impl FromStr for Method {
             "cf" => Method::CF,
             "gcp" => Method::GCP,
             "github" => Method::GITHUB,
+            "jwt" => Method::JWT,
             "kerberos" => Method::KERBEROS,
             "kubernetes" => Method::KUBERNETES,
             "ldap" => Method::LDAP,
@@ -109,6 +122,7 @@ impl<'a> Into<&'a str> for Method {
             Method::CF => "cf",
             Method::GCP => "gcp",
             Method::GITHUB => "github",
+            Method::JWT => "jwt",
             Method::KERBEROS => "kerberos",
             Method::KUBERNETES => "kubernetes",
             Method::LDAP => "ldap",
@@ -0,0 +1,32 @@
+mod mock;
+
+use mock::{build_client, mock_server};
+use vaultrs_login::engines::azure::AzureLogin;
+use vaultrs_login::LoginClient;
+
+#[test]
+fn test_login() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    let mut login = AzureLogin::new("vm", "fake.jwt.token");
+    login.subscription_id = Some("subscription".to_string());
+    login.resource_group_name = Some("group".to_string());
+    login.vm_name = Some("machine".to_string());
+    tokio_test::block_on(client.login("azure", &login)).unwrap();
+    assert_eq!(client.settings.token, "new-token");
+
+    let (head, body) = requests.recv().unwrap();
+    assert!(head.starts_with("POST /v1/auth/azure/login "));
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["role"], "vm");
+    assert_eq!(body["jwt"], "fake.jwt.token");
+    assert_eq!(body["subscription_id"], "subscription");
+    assert_eq!(body["resource_group_name"], "group");
+    assert_eq!(body["vm_name"], "machine");
+    // Unset metadata is left out instead of being sent as null
+    assert!(body.get("vmss_name").is_none());
+
+    // The JWT is never printed
+    assert!(!format!("{:?}", login).contains("fake.jwt.token"));
+}
@@ -0,0 +1,33 @@
+mod mock;
+
+use mock::{build_client, mock_server};
+use vaultrs_login::engines::cert::CertLogin;
+use vaultrs_login::LoginClient;
+
+#[test]
+fn test_login() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    let login = CertLogin::new(Some("web"));
+    tokio_test::block_on(client.login("cert", &login)).unwrap();
+    assert_eq!(client.settings.token, "new-token");
+
+    let (head, body) = requests.recv().unwrap();
+    assert!(head.starts_with("POST /v1/auth/cert/login "));
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["name"], "web");
+}
+
+#[test]
+fn test_login_without_name() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    tokio_test::block_on(client.login("cert", &CertLogin::default())).unwrap();
+
+    // Vault matches against all roles when no name is sent
+    let (head, body) = requests.recv().unwrap();
+    assert!(head.starts_with("POST /v1/auth/cert/login "));
+    assert!(body.is_empty());
+}
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
+#[allow(unused_imports)]
 pub use dockertest_server::servers::auth::{OIDCServer, OIDCServerConfig};
+#[allow(unused_imports)]
 pub use dockertest_server::servers::cloud::{LocalStackServer, LocalStackServerConfig};
 pub use dockertest_server::servers::hashi::{VaultServer, VaultServerConfig};
 use dockertest_server::Test;
@@ -20,6 +22,7 @@ pub const VAULT_VERSION: &str = "1.8.2";
 pub const LOCALSTACK_PORT: u32 = 8765;
 pub const LOCALSTACK_VERSION: &str = "0.13.1";
 
+#[allow(dead_code)]
 #[async_trait]
 pub trait VaultServerHelper {
     /// Mounts a new instance of the requested secret engine at the given path.
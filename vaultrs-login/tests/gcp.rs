@@ -0,0 +1,25 @@
+mod mock;
+
+use mock::{build_client, mock_server};
+use vaultrs_login::engines::gcp::GCPLogin;
+use vaultrs_login::LoginClient;
+
+#[test]
+fn test_login() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    let login = GCPLogin::new("service", "fake.jwt.token");
+    tokio_test::block_on(client.login("gcp", &login)).unwrap();
+    assert_eq!(client.settings.token, "new-token");
+
+    let (head, body) = requests.recv().unwrap();
+    assert!(head.starts_with("POST /v1/auth/gcp/login "));
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body.as_object().unwrap().len(), 2);
+    assert_eq!(body["role"], "service");
+    assert_eq!(body["jwt"], "fake.jwt.token");
+
+    // The JWT is never printed
+    assert!(!format!("{:?}", login).contains("fake.jwt.token"));
+}
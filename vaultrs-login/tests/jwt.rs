@@ -0,0 +1,24 @@
+mod mock;
+
+use mock::{build_client, mock_server};
+use vaultrs_login::engines::jwt::JWTLogin;
+use vaultrs_login::LoginClient;
+
+#[test]
+fn test_login() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    let login = JWTLogin::new("ci", "fake.jwt.token");
+    tokio_test::block_on(client.login("jwt", &login)).unwrap();
+    assert_eq!(client.settings.token, "new-token");
+
+    let (head, body) = requests.recv().unwrap();
+    assert!(head.starts_with("POST /v1/auth/jwt/login "));
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["role"], "ci");
+    assert_eq!(body["jwt"], "fake.jwt.token");
+
+    // The JWT is never printed
+    assert!(!format!("{:?}", login).contains("fake.jwt.token"));
+}
@@ -0,0 +1,60 @@
+mod mock;
+
+use mock::{build_client, mock_server};
+use vaultrs::error::ClientError;
+use vaultrs_login::engines::kubernetes::KubernetesLogin;
+use vaultrs_login::LoginClient;
+
+#[test]
+fn test_login_with_jwt() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    let login = KubernetesLogin::with_jwt("test", "fake.jwt.token");
+    tokio_test::block_on(client.login("kubernetes", &login)).unwrap();
+    assert_eq!(client.settings.token, "new-token");
+
+    let (head, body) = requests.recv().unwrap();
+    assert!(head.starts_with("POST /v1/auth/kubernetes/login "));
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["role"], "test");
+    assert_eq!(body["jwt"], "fake.jwt.token");
+
+    // The JWT is never printed
+    assert!(!format!("{:?}", login).contains("fake.jwt.token"));
+}
+
+#[test]
+fn test_login_with_jwt_path() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    let path = std::env::temp_dir().join("vaultrs-login-kubernetes-token");
+    std::fs::write(&path, "file.jwt.token\n").unwrap();
+    let login = KubernetesLogin {
+        jwt_path: Some(path.clone()),
+        ..KubernetesLogin::new("test")
+    };
+    let res = tokio_test::block_on(client.login("kubernetes", &login));
+    std::fs::remove_file(&path).unwrap();
+    assert!(res.is_ok());
+
+    let (_, body) = requests.recv().unwrap();
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["jwt"], "file.jwt.token");
+}
+
+#[test]
+fn test_login_with_missing_jwt_path() {
+    let mut client = build_client("http://127.0.0.1:8200");
+
+    let login = KubernetesLogin {
+        jwt_path: Some("/does/not/exist".into()),
+        ..KubernetesLogin::new("test")
+    };
+    let res = tokio_test::block_on(client.login("kubernetes", &login));
+    assert!(matches!(
+        res,
+        Err(ClientError::FileNotFoundError { path }) if path == "/does/not/exist"
+    ));
+}
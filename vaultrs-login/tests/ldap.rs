@@ -0,0 +1,24 @@
+mod mock;
+
+use mock::{build_client, mock_server};
+use vaultrs_login::engines::ldap::LdapLogin;
+use vaultrs_login::LoginClient;
+
+#[test]
+fn test_login() {
+    let (address, requests) = mock_server();
+    let mut client = build_client(&address);
+
+    let login = LdapLogin::new("jane", "hunter2");
+    tokio_test::block_on(client.login("ldap", &login)).unwrap();
+    assert_eq!(client.settings.token, "new-token");
+
+    // The username is part of the path and only the password is sent
+    let (head, body) = requests.recv().unwrap();
+    assert!(head.starts_with("POST /v1/auth/ldap/login/jane "));
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body, serde_json::json!({"password": "hunter2"}));
+
+    // The password is never printed
+    assert!(!format!("{:?}", login).contains("hunter2"));
+}
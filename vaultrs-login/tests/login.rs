@@ -6,12 +6,21 @@ extern crate tracing_test;
 mod common;
 
 use std::collections::HashMap;
+#[cfg(feature = "oidc")]
+use std::time::Duration;
 
-use common::{LocalStackServer, OIDCServer, VaultServer, VaultServerHelper};
+#[cfg(feature = "aws")]
+use common::LocalStackServer;
+#[cfg(feature = "oidc")]
+use common::OIDCServer;
+use common::{VaultServer, VaultServerHelper};
 use vaultrs::api::auth::approle::requests::SetAppRoleRequest;
 use vaultrs::api::auth::userpass::requests::CreateUserRequest;
-use vaultrs::auth::{approle, aws, userpass};
-use vaultrs::client::VaultClient;
+#[cfg(feature = "aws")]
+use vaultrs::auth::aws;
+use vaultrs::auth::{approle, userpass};
+use vaultrs::client::{Client, VaultClient};
+use vaultrs::error::ClientError;
 use vaultrs_login::engines::{approle::AppRoleLogin, userpass::UserpassLogin};
 use vaultrs_login::method::{self, Method};
 use vaultrs_login::LoginClient;
@@ -21,8 +30,10 @@ use vaultrs_login::LoginClient;
 fn test() {
     let test = common::new_test();
     test.run(|instance| async move {
+        #[cfg(feature = "oidc")]
         let oidc_server: OIDCServer = instance.server();
         let vault_server: VaultServer = instance.server();
+        #[cfg(feature = "aws")]
         let localstack_server: LocalStackServer = instance.server();
         let client = vault_server.client();
 
@@ -115,6 +126,12 @@ async fn test_approle(client: &mut VaultClient) {
     assert!(res.is_ok());
     let secret_id = res.unwrap().secret_id;
 
+    // Test login with an invalid secret ID
+    let res = client
+        .login("approle_test", &AppRoleLogin::new(&role_id, "invalid"))
+        .await;
+    assert!(matches!(res, Err(ClientError::APIError { .. })));
+
     // Test login
     let res = client
         .login("approle_test", &AppRoleLogin { role_id, secret_id })
@@ -128,7 +145,9 @@ async fn test_approle(client: &mut VaultClient) {
 async fn test_oidc(oidc_server: &OIDCServer, vault_server: &VaultServer, client: &mut VaultClient) {
     debug!("running test...");
 
+    use std::net::{IpAddr, Ipv4Addr};
     use vaultrs::api::auth::oidc::requests::{SetConfigurationRequest, SetRoleRequest};
+    use vaultrs_login::engines::oidc::OIDCSuccessResponse;
 
     let mount = "oidc_test";
     let role = "test";
@@ -173,6 +192,10 @@ async fn test_oidc(oidc_server: &OIDCServer, vault_server: &VaultServer, client:
     let login = vaultrs_login::engines::oidc::OIDCLogin {
         port: Some(port),
         role: Some(role.to_string()),
+        timeout: Some(Duration::from_secs(30)),
+        bind_addr: Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+        redirect_host: Some("localhost".to_string()),
+        on_success: OIDCSuccessResponse::Html("<h1>Logged in</h1>".to_string()),
     };
     let callback = client.login_multi(mount, login).await.unwrap();
 
@@ -188,10 +211,41 @@ async fn test_oidc(oidc_server: &OIDCServer, vault_server: &VaultServer, client:
     );
     let rclient = reqwest::Client::default();
     let params = [("username", "default"), ("acr", "default")];
-    rclient.post(url).form(&params).send().await.unwrap();
+    let resp = rclient.post(url).form(&params).send().await.unwrap();
+    assert_eq!(resp.headers()["content-type"], "text/html; charset=utf-8");
+    assert_eq!(resp.text().await.unwrap(), "<h1>Logged in</h1>");
 
     // The callback should be successful now
     client.login_multi_callback(mount, callback).await.unwrap();
+
+    // A login that never receives a redirect should time out and release the
+    // port for the next attempt
+    let login = vaultrs_login::engines::oidc::OIDCLogin {
+        port: Some(port),
+        role: Some(role.to_string()),
+        timeout: Some(Duration::from_secs(1)),
+        ..Default::default()
+    };
+    let callback = client.login_multi(mount, login).await.unwrap();
+    let res = client.login_multi_callback(mount, callback).await;
+    assert!(matches!(res, Err(ClientError::OIDCServerTimeout)));
+
+    // An error redirect from the authorization server should be surfaced
+    // without attempting the token exchange
+    let login = vaultrs_login::engines::oidc::OIDCLogin {
+        port: Some(port),
+        role: Some(role.to_string()),
+        timeout: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+    let callback = client.login_multi(mount, login).await.unwrap();
+    let denied = format!("{}?error=access_denied&error_description=denied", redirect);
+    rclient.get(denied).send().await.unwrap();
+    let res = client.login_multi_callback(mount, callback).await;
+    assert!(matches!(
+        res,
+        Err(ClientError::OIDCAuthError { error, description: Some(_) }) if error == "access_denied"
+    ));
     //assert!(vault_server.client.lookup().await.is_ok());
 }
 
@@ -222,6 +276,46 @@ async fn test_userpass(client: &mut VaultClient) {
         .await;
     assert!(res.is_ok());
     //assert!(server.client.lookup().await.is_ok());
+
+    // Change the password
+    let res = userpass::user::update_password(client, "userpass_test", "test", "changed").await;
+    assert!(res.is_ok());
+
+    let res = client
+        .login("userpass_test", &UserpassLogin::new("test", "test"))
+        .await;
+    assert!(matches!(res, Err(ClientError::APIError { .. })));
+
+    let res = client
+        .login("userpass_test", &UserpassLogin::new("test", "changed"))
+        .await;
+    assert!(res.is_ok());
+
+    // Change the policies
+    let res = userpass::user::update_policies(client, "userpass_test", "test", "default,dev").await;
+    assert!(res.is_ok());
+
+    // The new token is used right away and carries the new policies
+    let res = client
+        .login_and_set("userpass_test", &UserpassLogin::new("test", "changed"))
+        .await;
+    let info = res.unwrap();
+    assert!(info.policies.contains(&"dev".to_string()));
+    assert_eq!(client.settings().token, info.client_token);
+
+    let res = client.lookup().await;
+    let res = res.unwrap();
+    assert_eq!(res.id, info.client_token);
+    assert_eq!(res.display_name, "userpass-test");
+    assert!(res.policies.contains(&"dev".to_string()));
+}
+
+#[test]
+fn test_userpass_debug_redacts_password() {
+    let login = UserpassLogin::new("test", "hunter2");
+    let debug = format!("{:?}", login);
+    assert!(debug.contains("test"));
+    assert!(!debug.contains("hunter2"));
 }
 
 #[cfg(feature = "aws")]
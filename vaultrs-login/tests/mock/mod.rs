@@ -0,0 +1,55 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+
+use vaultrs::client::{VaultClient, VaultClientSettingsBuilder};
+
+const AUTH: &str = r#"{"request_id":"1","lease_id":"","lease_duration":0,"renewable":false,"data":null,"warnings":null,"wrap_info":null,"auth":{"client_token":"new-token","accessor":"accessor","policies":["default"],"token_policies":["default"],"metadata":{"role":"test"},"lease_duration":3600,"renewable":true,"entity_id":"","token_type":"service","orphan":true}}"#;
+
+pub fn build_client(address: &str) -> VaultClient {
+    VaultClient::new(
+        VaultClientSettingsBuilder::default()
+            .address(address)
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+/// Starts a server which answers a single login request and sends the
+/// request line and body it received back to the test
+pub fn mock_server() -> (String, mpsc::Receiver<(String, String)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut head = String::new();
+        reader.read_line(&mut head).unwrap();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" || line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        tx.send((head, String::from_utf8(body).unwrap())).unwrap();
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            AUTH.len(),
+            AUTH
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+    });
+    (address, rx)
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "oidc")]
+
+use std::net::TcpListener;
+
+use vaultrs::client::{VaultClient, VaultClientSettingsBuilder};
+use vaultrs::error::ClientError;
+use vaultrs_login::engines::oidc::OIDCLogin;
+use vaultrs_login::MultiLoginMethod;
+
+// Nothing listens at this address, so any request sent to Vault fails
+fn build_client() -> VaultClient {
+    VaultClient::new(
+        VaultClientSettingsBuilder::default()
+            .address("http://127.0.0.1:1")
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_login_port_in_use() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let login = OIDCLogin {
+        port: Some(listener.local_addr().unwrap().port()),
+        ..Default::default()
+    };
+
+    // The callback server fails to start before Vault is asked for a URL
+    let res = tokio_test::block_on(login.login(&build_client(), "oidc"));
+    assert!(matches!(res, Err(ClientError::OIDCServerError { .. })));
+}
+
+#[test]
+fn test_login_invalid_redirect_host() {
+    for host in ["::1", "localhost/oidc", "localhost:8250"] {
+        let login = OIDCLogin {
+            port: Some(0),
+            redirect_host: Some(host.to_string()),
+            ..Default::default()
+        };
+
+        // The redirect URL is built before the server or Vault is contacted
+        let res = tokio_test::block_on(login.login(&build_client(), "oidc"));
+        assert!(
+            matches!(res, Err(ClientError::OIDCRedirectHostError { host: ref h, .. }) if h == host),
+            "{}",
+            host
+        );
+    }
+}